@@ -17,10 +17,17 @@
  * @brief Actions on exceptions
  */
 /**@{*/
-#define EXCP_DEFER 0  /* Defer exception to kernel.  */
-#define EXCP_HANDLE 1 /* Handle exception.           */
+#define EXCP_DEFER 0     /* Defer exception to kernel.  */
+#define EXCP_HANDLE 1    /* Handle exception.           */
+#define EXCP_TERMINATE 2 /* Terminate faulting thread.  */
 /**@}*/
 
+/**
+ * @brief Bit that is set in the return value of a thread that is terminated
+ * because of an exception. The remaining bits hold the exception number.
+ */
+#define EXCP_EXIT_FAULTED (1 << 8)
+
 /*============================================================================*
  * Structures                                                                 *
  *============================================================================*/
@@ -28,7 +35,7 @@
 /**
  * @brief Size of exception information structure.
  */
-#define __SIZEOF_EXCPINFO 16
+#define __SIZEOF_EXCPINFO 20
 
 /**
  * @brief Exception information.
@@ -38,6 +45,7 @@ struct excpinfo {
     int num;      /** Exception number. */
     vaddr_t addr; /** Faulting address. */
     vaddr_t pc;   /** Program counter. */
+    tid_t tid;    /** Thread ID.        */
 };
 
 /*============================================================================*
@@ -60,6 +68,9 @@ extern int excp_init(void);
  *
  * @return Upon successful completion zero is returned. Upon failure a negative
  * error code is returned instead.
+ *
+ * @note When @p action is EXCP_TERMINATE, the thread that triggered the
+ * pending exception is terminated instead of being resumed.
  */
 extern int excp_control(int excpnum, int action);
 
@@ -64,6 +64,11 @@
  */
 #define SEMAPHORE_DELETE 2
 
+/**
+ * @brief Command Semaphore Get Owner
+ */
+#define SEMAPHORE_GETOWNER 3
+
 /*============================================================================*
  * Public Variables                                                           *
  *============================================================================*/
@@ -169,6 +174,17 @@ extern int semaphore_getid(int key);
  */
 extern int semaphore_getcount(int semid);
 
+/*
+ * @brief Return owner process of semaphore.
+ *
+ * @p semid Semaphore id.
+ *
+ * @return Upon successful completion, the ID of the process that created the
+ * semaphore is returned. Upon failure, a negative error code is returned
+ * instead.
+ */
+extern int semaphore_getowner(int semid);
+
 /**
  * @brief Performs a down operation in a semaphore.
  *
@@ -29,6 +29,7 @@ static struct {
     struct {
         pid_t owner;                 /** Owner process.                       */
         bool pending;                /** Pending exception?                   */
+        bool terminate;              /** Terminate faulting thread?           */
         const struct excpinfo *info; /** Exception information                */
         struct condvar ack;          /** Signaled when an exception is acked. */
     } lines[EXCEPTIONS_NUM];
@@ -46,6 +47,7 @@ static void excpline_init(void)
     for (int i = 0; i < EXCEPTIONS_NUM; i++) {
         table.lines[i].owner = -1;
         table.lines[i].pending = false;
+        table.lines[i].terminate = false;
         table.lines[i].info = NULL;
         cond_init(&table.lines[i].ack);
     }
@@ -196,16 +198,17 @@ static void excpline_wait_any(struct excpinfo *info)
 {
     const struct process *p = process_get_curr();
     while (true) {
-        cond_wait(&table.exception_triggered);
-
+        // Check for pending exceptions before sleeping, so that an exception
+        // that is triggered before the calling process starts waiting is not
+        // lost.
         for (int i = 0; i < EXCEPTIONS_NUM; i++) {
-            if (table.lines[i].owner == p->pid) {
+            if ((table.lines[i].owner == p->pid) && table.lines[i].pending) {
                 __memcpy(info, table.lines[i].info, sizeof(struct excpinfo));
                 return;
             }
         }
 
-        warn("spurious wake up");
+        cond_wait(&table.exception_triggered);
     }
 
     UNREACHABLE();
@@ -242,20 +245,22 @@ static void kernel_exception_handler(const struct exception *excp,
         .num = excpnum,
         .addr = get_page_fault_addr(),
         .pc = excp->instruction,
+        .tid = thread_get_curr(),
     };
 
     excpline_trigger(excpnum, &info);
 
-    // FIXME: Race condition with excp_wait().
-    // An exception may trigger just before the process calls excp_wait(). Thus
-    // causing the exception signal to be lost. This can be solved by Modeling
-    // this as a single consumer (the excp_wait() function) and single producer
-    // (the kernel_exception_handler() function) queue. If exception reentrancy
-    // is considered, this queue should support multiple produces (reentrant
-    // calls to the kernel_exception_handler() function).
+    // NOTE: the exception remains pending until it is acknowledged, thus
+    // excp_wait() picks it up even if it is called after this point.
     warn("exception %d was triggered", excpnum);
 
     excpline_wait_ack(excpnum);
+
+    // Terminate the faulting thread, if requested by the handler.
+    if (table.lines[excpnum].terminate) {
+        table.lines[excpnum].terminate = false;
+        thread_exit((void *)(EXCP_EXIT_FAULTED | excpnum));
+    }
 }
 
 /*============================================================================*
@@ -291,7 +296,7 @@ int excp_control(int excpnum, int action)
         // Defer exception to kernel.
         case EXCP_DEFER: {
             // Check if the current process is handling this exception.
-            if (excpline_owns(excpnum)) {
+            if (!excpline_owns(excpnum)) {
                 error("exception %d is not assigned to current process",
                       excpnum);
                 return (-EPERM);
@@ -304,6 +309,26 @@ int excp_control(int excpnum, int action)
             excpline_unassign(excpnum);
         } break;
 
+        // Terminate faulting thread.
+        case EXCP_TERMINATE: {
+            // Check if the current process is handling this exception.
+            if (!excpline_owns(excpnum)) {
+                error("exception %d is not assigned to current process",
+                      excpnum);
+                return (-EPERM);
+            }
+
+            // Check if there is a faulting thread to terminate.
+            if (!excpline_is_triggered(excpnum)) {
+                error("exception %d is not pending", excpnum);
+                return (-ENOENT);
+            }
+
+            // Wake up the faulting thread, which then terminates itself.
+            table.lines[excpnum].terminate = true;
+            excpline_ack(excpnum);
+        } break;
+
         // Invalid action.
         default: {
             error("invalid action %d", action);
@@ -326,7 +351,7 @@ int excp_resume(int excpnum)
     }
 
     // Check if the calling process is handling the exception.
-    if (excpline_owns(excpnum)) {
+    if (!excpline_owns(excpnum)) {
         error("exception %d is not assigned to current process", excpnum);
         return (-EPERM);
     }
@@ -354,8 +379,6 @@ int excp_wait(struct excpinfo *info)
         return (-EPERM);
     }
 
-    // TODO: fix race condition with kernel_exception_handler().
-
     excpline_wait_any(info);
 
     return (0);
@@ -27,6 +27,7 @@ static volatile struct {
     word_t arg2;     /** Third argument of kernel call.  */
     word_t arg3;     /** Fourth argument of kernel call. */
     word_t arg4;     /** Fifth argument of kernel call.  */
+    word_t arg5;     /** Sixth argument of kernel call.  */
     word_t ret;      /** Return value of kernel call.    */
 } scoreboard;
 
@@ -52,13 +53,14 @@ noreturn void handle_syscall(void)
  * @param arg2     Third kernel call argument.
  * @param arg3     Fourth kernel call argument.
  * @param arg4     Fifth kernel call argument.
+ * @param arg5     Sixth kernel call argument.
  * @param kcall_nr Kernel call number.
  *
  * @returns Upon successful completion, zero is returned. Upon
  * failure, a negative error code is returned instead.
  */
 int do_kcall(word_t arg0, word_t arg1, word_t arg2, word_t arg3, word_t arg4,
-             word_t kcall_nr)
+             word_t arg5, word_t kcall_nr)
 {
     int ret = -1;
 
@@ -81,14 +83,18 @@ int do_kcall(word_t arg0, word_t arg1, word_t arg2, word_t arg3, word_t arg4,
             ret = kcall_void4((int)arg0, (int)arg1, (int)arg2, (int)arg3);
             break;
         case NR_void5:
-            ret = kcall_void5(
-                (int)arg0, (int)arg1, (int)arg2, (int)arg3, (int)arg4);
+            ret = kcall_void5((int)arg0,
+                              (int)arg1,
+                              (int)arg2,
+                              (int)arg3,
+                              (int)arg4,
+                              (int)arg5);
             break;
         case NR_write:
             ret = kcall_write((int)arg0, (const char *)arg1, (size_t)arg2);
             break;
         case NR_shutdown:
-            kcall_shutdown();
+            kcall_shutdown((int)arg0);
             break;
         case NR_fralloc:
             ret = kcall_fralloc();
@@ -214,6 +220,7 @@ int do_kcall(word_t arg0, word_t arg1, word_t arg2, word_t arg3, word_t arg4,
             scoreboard.arg2 = arg2;
             scoreboard.arg3 = arg3;
             scoreboard.arg4 = arg4;
+            scoreboard.arg5 = arg5;
 
             semaphore_up(kernel_semaphore);
             semaphore_down(user_semaphore);
@@ -79,15 +79,20 @@ extern int kcall_void4(int arg0, int arg1, int arg2, int arg3);
  * @param arg2 Third argument.
  * @param arg3 Fourth argument.
  * @param arg4 Fifth argument.
+ * @param arg5 Optional sixth argument.
  *
- * @return Always returns @p arg0 + @p arg1 + @p arg2 + @p arg3 + @p arg4.
+ * @return Always returns @p arg0 + @p arg1 + @p arg2 + @p arg3 + @p arg4,
+ * plus @p arg5 if it equals 6.
  */
-extern int kcall_void5(int arg0, int arg1, int arg2, int arg3, int arg4);
+extern int kcall_void5(int arg0, int arg1, int arg2, int arg3, int arg4,
+                       int arg5);
 
 /**
  * @brief Shutdowns the system
+ *
+ * @param status Exit status. A nonzero value denotes an abnormal shutdown.
  */
-extern noreturn void kcall_shutdown(void);
+extern noreturn void kcall_shutdown(int status);
 
 /**
  * @brief Writes a buffer to a file descriptor.
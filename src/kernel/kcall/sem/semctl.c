@@ -27,6 +27,8 @@ int kcall_semctl(int id, int cmd, int val)
             return (semaphore_set(id, val));
         case SEMAPHORE_DELETE:
             return (semaphore_delete(id));
+        case SEMAPHORE_GETOWNER:
+            return (semaphore_getowner(id));
         default:
             return (-ENOENT);
     }
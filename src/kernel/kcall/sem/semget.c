@@ -27,7 +27,12 @@ int kcall_semget(unsigned key)
     if (ret < 0) {
         switch (ret) {
             case -EEXIST:
-                return (semaphore_getid(key));
+                // Register the caller as a user of the existing semaphore.
+                semid = semaphore_getid(key);
+                if (semid < 0) {
+                    return (semid);
+                }
+                return (semaphore_get(semid));
             case -ENOBUFS:
                 return (-ENOBUFS);
             default:
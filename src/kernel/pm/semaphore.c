@@ -280,6 +280,30 @@ int semaphore_getcount(int semid)
     return (semtable[semid].count);
 }
 
+/*
+ * @brief Return owner process of semaphore.
+ *
+ * @p semid Semaphore id.
+ *
+ * @return Upon successful completion, the ID of the process that created the
+ * semaphore is returned. Upon failure, a negative error code is returned
+ * instead.
+ */
+int semaphore_getowner(int semid)
+{
+    // Verify if semaphore is SEMAPHORE_ACTIVE.
+    if (!is_semaphore_SEMAPHORE_ACTIVE(semid)) {
+        return (-ENOENT);
+    }
+
+    // Verify if semaphore is get.
+    if (!is_semaphore_get(semid)) {
+        return (-EACCES);
+    }
+
+    return (semtable[semid].proc_owner);
+}
+
 /*
  * @brief Return semaphore id.
  *
@@ -0,0 +1,79 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: the kernel does not run on AArch64 yet, thus these functions have not
+// been exercised.
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::arch;
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Reads the cycle counter of the processor.
+///
+/// **Return**
+///
+/// This function returns the current value of the virtual counter, which is
+/// read from `cntvct_el0`.
+///
+/// **Notes**
+///
+/// - The virtual counter ticks at a fixed frequency, which is reported by
+///   `cntfrq_el0`, rather than once per core cycle.
+///
+#[inline]
+pub fn cycles() -> u64 {
+    let count: u64;
+    unsafe {
+        arch::asm!("isb",
+            "mrs {}, cntvct_el0",
+            out(reg) count,
+            options(nomem, nostack, preserves_flags)
+        );
+    }
+    count
+}
+
+///
+/// **Description**
+///
+/// Calls a function on another stack and switches back once it returns.
+///
+/// **Parameters**
+/// - `top` - Address of the top of the stack. It must be 16-byte aligned.
+/// - `entry` - Function to call, which takes a single pointer argument.
+/// - `arg` - Argument for the function.
+///
+/// **Notes**
+///
+/// - The stack pointer of the calling thread is saved on the new stack.
+///
+/// **Safety**
+///
+/// - The caller must ensure that `top` is the top of a mapped, writable stack
+///   that is large enough for `entry`, and that `entry` is a C function.
+///
+pub(crate) unsafe fn switch_stack(top: u32, entry: usize, arg: usize) {
+    arch::asm!("mov x11, sp",
+        "mov sp, x10",
+        "str x11, [sp, #-16]!",
+        "blr x9",
+        "ldr x11, [sp], #16",
+        "mov sp, x11",
+        in("x0") arg as u64,
+        in("x9") entry as u64,
+        in("x10") top as u64,
+        out("x11") _,
+        clobber_abi("C"),
+    );
+}
@@ -0,0 +1,42 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: this module holds the inline asm that is not tied to kernel calls, so
+// that every target implements the same set of functions. Kernel call stubs
+// live in `kcall::arch`.
+
+//==============================================================================
+// Modules
+//==============================================================================
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+#[cfg(target_arch = "riscv64")]
+mod riscv64;
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+)))]
+mod unsupported;
+#[cfg(target_arch = "x86")]
+mod x86;
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+#[cfg(target_arch = "aarch64")]
+pub use self::aarch64::*;
+#[cfg(target_arch = "riscv64")]
+pub use self::riscv64::*;
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+)))]
+pub use self::unsupported::*;
+#[cfg(target_arch = "x86")]
+pub use self::x86::*;
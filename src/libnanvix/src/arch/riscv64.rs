@@ -0,0 +1,79 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: the kernel does not run on RISC-V yet, thus these functions have not
+// been exercised.
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::arch;
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Reads the cycle counter of the processor.
+///
+/// **Return**
+///
+/// This function returns the current value of the cycle counter, which is
+/// read with `rdcycle`.
+///
+/// **Notes**
+///
+/// - Reading the cycle counter in user mode traps unless the kernel enables
+///   it in `scounteren`.
+///
+#[inline]
+pub fn cycles() -> u64 {
+    let count: u64;
+    unsafe {
+        arch::asm!("rdcycle {}",
+            out(reg) count,
+            options(nomem, nostack, preserves_flags)
+        );
+    }
+    count
+}
+
+///
+/// **Description**
+///
+/// Calls a function on another stack and switches back once it returns.
+///
+/// **Parameters**
+/// - `top` - Address of the top of the stack. It must be 16-byte aligned.
+/// - `entry` - Function to call, which takes a single pointer argument.
+/// - `arg` - Argument for the function.
+///
+/// **Notes**
+///
+/// - The stack pointer of the calling thread is saved on the new stack.
+///
+/// **Safety**
+///
+/// - The caller must ensure that `top` is the top of a mapped, writable stack
+///   that is large enough for `entry`, and that `entry` is a C function.
+///
+pub(crate) unsafe fn switch_stack(top: u32, entry: usize, arg: usize) {
+    arch::asm!("mv t0, sp",
+        "mv sp, t2",
+        "addi sp, sp, -16",
+        "sd t0, 0(sp)",
+        "jalr t1",
+        "ld t0, 0(sp)",
+        "mv sp, t0",
+        in("a0") arg as u64,
+        in("t1") entry as u64,
+        in("t2") top as u64,
+        out("t0") _,
+        clobber_abi("C"),
+    );
+}
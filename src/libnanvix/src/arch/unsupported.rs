@@ -0,0 +1,23 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Reads the cycle counter of the processor.
+///
+/// **Return**
+///
+/// This function always returns zero, because the target architecture has no
+/// supported cycle counter.
+///
+#[inline]
+pub fn cycles() -> u64 {
+    0
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::arch;
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Reads the cycle counter of the processor.
+///
+/// **Return**
+///
+/// This function returns the current value of the timestamp counter, which is
+/// read with `rdtsc`.
+///
+/// **Notes**
+///
+/// - On processors with frequency scaling, the timestamp counter may not match
+///   core cycles.
+///
+#[inline]
+pub fn cycles() -> u64 {
+    unsafe { arch::x86::_rdtsc() }
+}
+
+///
+/// **Description**
+///
+/// Calls a function on another stack and switches back once it returns.
+///
+/// **Parameters**
+/// - `top` - Address of the top of the stack. It must be 16-byte aligned.
+/// - `entry` - Function to call, which takes a single pointer argument.
+/// - `arg` - Argument for the function.
+///
+/// **Notes**
+///
+/// - The stack pointer of the calling thread is saved on the new stack.
+///
+/// **Safety**
+///
+/// - The caller must ensure that `top` is the top of a mapped, writable stack
+///   that is large enough for `entry`, and that `entry` is a C function.
+///
+pub(crate) unsafe fn switch_stack(top: u32, entry: usize, arg: usize) {
+    arch::asm!("mov edi, esp",
+        "mov esp, ecx",
+        "push edi",
+        "sub esp, 8",
+        "push eax",
+        "call edx",
+        "add esp, 12",
+        "pop esp",
+        in("eax") arg,
+        in("ecx") top,
+        in("edx") entry,
+        out("edi") _,
+        clobber_abi("C"),
+    );
+}
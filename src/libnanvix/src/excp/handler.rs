@@ -0,0 +1,175 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    excp::{
+        ExceptionInfo,
+        ExceptionKind,
+        ExcpAction,
+        ExcpInfo,
+    },
+    kcall::{
+        self,
+        KcallError,
+        KcallNumbers,
+    },
+};
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Controls whether the calling process handles an exception.
+///
+/// **Parameters**
+///
+/// - `kind` - Target exception.
+/// - `enable` - Handle the exception in the calling process?
+///
+/// **Return**
+///
+/// On successful completion, empty is returned. On error, an error is returned
+/// instead.
+///
+/// **Notes**
+///
+/// - An exception may be handled by a single process at a time.
+/// - When `enable` is `false`, handling of the exception is deferred back to
+/// the kernel.
+///
+pub fn control(kind: ExceptionKind, enable: bool) -> Result<(), KcallError> {
+    let action: ExcpAction = if enable {
+        ExcpAction::Handle
+    } else {
+        ExcpAction::Defer
+    };
+
+    unsafe {
+        kcall::kcall2_checked(
+            KcallNumbers::ExcpCtrl as u32,
+            kind.as_vector(),
+            action.into(),
+        )?;
+    }
+
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Waits for an exception that is handled by the calling process.
+///
+/// **Return**
+///
+/// On successful completion, information about the exception is returned. On
+/// error, an error is returned instead.
+///
+/// **Notes**
+///
+/// - The faulting thread stays blocked until [`resume`] is called.
+///
+pub fn wait() -> Result<ExceptionInfo, KcallError> {
+    let mut info: ExcpInfo = ExcpInfo::default();
+
+    unsafe {
+        kcall::kcall1_checked(
+            KcallNumbers::ExcpWait as u32,
+            &mut info as *mut ExcpInfo as u32,
+        )?;
+    }
+
+    Ok(ExceptionInfo::from(info))
+}
+
+///
+/// **Description**
+///
+/// Waits for an exception that is handled by the calling process, waiting
+/// again if the wait is interrupted.
+///
+/// **Return**
+///
+/// On successful completion, information about the exception is returned. On
+/// error, an error other than [`KcallError::Interrupted`] is returned instead.
+///
+/// **Notes**
+///
+/// - A wait that is interrupted reports `-EINTR` ([`KcallError::Interrupted`])
+/// and delivers no exception, thus it is safe to wait again.
+/// - The kernel does not interrupt waits yet, but callers should not depend on
+/// this.
+///
+pub fn wait_uninterrupted() -> Result<ExceptionInfo, KcallError> {
+    kcall::retry_interrupted(wait)
+}
+
+///
+/// **Description**
+///
+/// Resumes the execution of a faulting thread.
+///
+/// **Parameters**
+///
+/// - `info` - Information about the exception, as returned by [`wait`].
+///
+/// **Return**
+///
+/// On successful completion, empty is returned. On error, an error is returned
+/// instead.
+///
+/// **Notes**
+///
+/// - The faulting instruction is restarted, thus the cause of the exception
+/// should be fixed before resuming.
+///
+pub fn resume(info: &ExceptionInfo) -> Result<(), KcallError> {
+    unsafe {
+        kcall::kcall1_checked(
+            KcallNumbers::ExcpResume as u32,
+            info.kind.as_vector(),
+        )?;
+    }
+
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Terminates a faulting thread instead of resuming it.
+///
+/// **Parameters**
+///
+/// - `info` - Information about the exception, as returned by [`wait`].
+///
+/// **Return**
+///
+/// On successful completion, empty is returned. On error, an error is returned
+/// instead.
+///
+/// **Notes**
+///
+/// - The faulting thread exits with an exit status that decodes to
+/// [`crate::pm::ExitStatus::Faulted`] with the kind of the exception.
+///
+pub fn terminate(info: &ExceptionInfo) -> Result<(), KcallError> {
+    unsafe {
+        kcall::kcall2_checked(
+            KcallNumbers::ExcpCtrl as u32,
+            info.kind.as_vector(),
+            ExcpAction::Terminate.into(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -3,6 +3,22 @@
  * Licensed under the MIT License.
  */
 
+//==============================================================================
+// Modules
+//==============================================================================
+
+mod handler;
+mod types;
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+pub use self::{
+    handler::*,
+    types::*,
+};
+
 //==============================================================================
 // Imports
 //==============================================================================
@@ -90,6 +106,7 @@ impl Into<u32> for ExceptionNumber {
 pub enum ExcpAction {
     Handle = 0,
     Defer = 1,
+    Terminate = 2,
 }
 
 impl Into<u32> for ExcpAction {
@@ -97,6 +114,7 @@ impl Into<u32> for ExcpAction {
         match self {
             ExcpAction::Defer => 0,
             ExcpAction::Handle => 1,
+            ExcpAction::Terminate => 2,
         }
     }
 }
@@ -108,6 +126,7 @@ pub struct ExcpInfo {
     pub num: u32,
     pub addr: u32,
     pub pc: u32,
+    pub tid: u32,
 }
 
 impl core::fmt::Debug for ExcpInfo {
@@ -0,0 +1,290 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    excp::ExcpInfo,
+    memory::VirtualAddress,
+    pm::{
+        Pid,
+        Tid,
+    },
+};
+use core::fmt;
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Size of a register snapshot (in bytes).
+pub const REGISTERS_SIZE: usize = 28;
+
+//==============================================================================
+// Enumerations
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Kinds of exceptions that may be handled in user space.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExceptionKind {
+    /// Division-by-zero error.
+    DivideByZero,
+    /// Debug exception.
+    Debug,
+    /// Non-maskable interrupt.
+    NonMaskableInterrupt,
+    /// Breakpoint exception.
+    Breakpoint,
+    /// Overflow exception.
+    Overflow,
+    /// Bounds check exception.
+    BoundsCheck,
+    /// Illegal instruction.
+    IllegalInstruction,
+    /// Coprocessor not available.
+    CoprocessorNotAvailable,
+    /// Double fault.
+    DoubleFault,
+    /// Coprocessor segment overrun.
+    CoprocessorSegmentOverrun,
+    /// Invalid TSS.
+    InvalidTss,
+    /// Segment not present.
+    SegmentNotPresent,
+    /// Stack segment fault.
+    StackSegmentFault,
+    /// General protection fault.
+    GeneralProtectionFault,
+    /// Page fault.
+    PageFault,
+    /// Floating point exception.
+    FpuError,
+    /// Alignment check exception.
+    AlignmentCheck,
+    /// Machine check exception.
+    MachineCheck,
+    /// SIMD unit exception.
+    SimdError,
+    /// Virtualization exception.
+    VirtualException,
+    /// Exception that has no dedicated kind.
+    Other(u32),
+}
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Information about an exception that was delivered to a user-space handler.
+///
+/// **Notes**
+///
+/// - The kernel does not report the error code pushed by the hardware, thus it
+/// is not available here.
+/// - The kernel does not report the registers of the faulting thread yet, thus
+/// `registers` is always `None`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExceptionInfo {
+    /// ID of the faulting process.
+    pub pid: Pid,
+    /// ID of the faulting thread.
+    pub tid: Tid,
+    /// Kind of the exception.
+    pub kind: ExceptionKind,
+    /// Faulting address. Only meaningful for page faults.
+    pub address: VirtualAddress,
+    /// Address of the faulting instruction.
+    pub instruction_pointer: VirtualAddress,
+    /// General-purpose registers of the faulting thread, if reported.
+    pub registers: Option<Registers>,
+}
+
+///
+/// **Description**
+///
+/// Snapshot of the general-purpose registers of a thread.
+///
+/// **Notes**
+///
+/// - The layout of this structure should match the general-purpose registers
+/// in the `struct context` structure in the kernel space. See
+/// `include/nanvix/kernel/hal/arch/x86/ctx.h` for more information.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Registers {
+    /// Destination index register.
+    pub edi: u32,
+    /// Source index register.
+    pub esi: u32,
+    /// Base pointer register.
+    pub ebp: u32,
+    /// Data register.
+    pub edx: u32,
+    /// Counter register.
+    pub ecx: u32,
+    /// Base register.
+    pub ebx: u32,
+    /// Accumulator register.
+    pub eax: u32,
+}
+
+// Ensure that the layout of the register snapshot matches the kernel's.
+const _: () = assert!(core::mem::size_of::<Registers>() == REGISTERS_SIZE);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl ExceptionKind {
+    ///
+    /// **Description**
+    ///
+    /// Decodes an exception vector.
+    ///
+    /// **Parameters**
+    ///
+    /// - `vector` - Exception vector.
+    ///
+    /// **Return**
+    ///
+    /// The exception kind that corresponds to `vector`. Vectors that have no
+    /// dedicated kind are decoded as [`ExceptionKind::Other`].
+    ///
+    /// **Example**
+    ///
+    /// ```ignore
+    /// assert_eq!(ExceptionKind::from_vector(14), ExceptionKind::PageFault);
+    /// ```
+    ///
+    pub fn from_vector(vector: u32) -> Self {
+        match vector {
+            0 => ExceptionKind::DivideByZero,
+            1 => ExceptionKind::Debug,
+            2 => ExceptionKind::NonMaskableInterrupt,
+            3 => ExceptionKind::Breakpoint,
+            4 => ExceptionKind::Overflow,
+            5 => ExceptionKind::BoundsCheck,
+            6 => ExceptionKind::IllegalInstruction,
+            7 => ExceptionKind::CoprocessorNotAvailable,
+            8 => ExceptionKind::DoubleFault,
+            9 => ExceptionKind::CoprocessorSegmentOverrun,
+            10 => ExceptionKind::InvalidTss,
+            11 => ExceptionKind::SegmentNotPresent,
+            12 => ExceptionKind::StackSegmentFault,
+            13 => ExceptionKind::GeneralProtectionFault,
+            14 => ExceptionKind::PageFault,
+            16 => ExceptionKind::FpuError,
+            17 => ExceptionKind::AlignmentCheck,
+            18 => ExceptionKind::MachineCheck,
+            19 => ExceptionKind::SimdError,
+            20 => ExceptionKind::VirtualException,
+            n => ExceptionKind::Other(n),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Encodes an exception kind into an exception vector.
+    ///
+    /// **Return**
+    ///
+    /// The exception vector that corresponds to the target exception kind.
+    ///
+    /// **Notes**
+    ///
+    /// - [`ExceptionKind::Other`] is encoded as the vector that it holds, even
+    ///   if that vector has a dedicated kind.
+    ///
+    pub fn as_vector(&self) -> u32 {
+        match self {
+            ExceptionKind::DivideByZero => 0,
+            ExceptionKind::Debug => 1,
+            ExceptionKind::NonMaskableInterrupt => 2,
+            ExceptionKind::Breakpoint => 3,
+            ExceptionKind::Overflow => 4,
+            ExceptionKind::BoundsCheck => 5,
+            ExceptionKind::IllegalInstruction => 6,
+            ExceptionKind::CoprocessorNotAvailable => 7,
+            ExceptionKind::DoubleFault => 8,
+            ExceptionKind::CoprocessorSegmentOverrun => 9,
+            ExceptionKind::InvalidTss => 10,
+            ExceptionKind::SegmentNotPresent => 11,
+            ExceptionKind::StackSegmentFault => 12,
+            ExceptionKind::GeneralProtectionFault => 13,
+            ExceptionKind::PageFault => 14,
+            ExceptionKind::FpuError => 16,
+            ExceptionKind::AlignmentCheck => 17,
+            ExceptionKind::MachineCheck => 18,
+            ExceptionKind::SimdError => 19,
+            ExceptionKind::VirtualException => 20,
+            ExceptionKind::Other(n) => *n,
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl fmt::Display for ExceptionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name: &str = match self {
+            ExceptionKind::DivideByZero => "division by zero",
+            ExceptionKind::Debug => "debug exception",
+            ExceptionKind::NonMaskableInterrupt => "non-maskable interrupt",
+            ExceptionKind::Breakpoint => "breakpoint",
+            ExceptionKind::Overflow => "overflow",
+            ExceptionKind::BoundsCheck => "bounds check",
+            ExceptionKind::IllegalInstruction => "illegal instruction",
+            ExceptionKind::CoprocessorNotAvailable => {
+                "coprocessor not available"
+            },
+            ExceptionKind::DoubleFault => "double fault",
+            ExceptionKind::CoprocessorSegmentOverrun => {
+                "coprocessor segment overrun"
+            },
+            ExceptionKind::InvalidTss => "invalid TSS",
+            ExceptionKind::SegmentNotPresent => "segment not present",
+            ExceptionKind::StackSegmentFault => "stack segment fault",
+            ExceptionKind::GeneralProtectionFault => "general protection fault",
+            ExceptionKind::PageFault => "page fault",
+            ExceptionKind::FpuError => "floating point error",
+            ExceptionKind::AlignmentCheck => "alignment check",
+            ExceptionKind::MachineCheck => "machine check",
+            ExceptionKind::SimdError => "SIMD error",
+            ExceptionKind::VirtualException => "virtualization exception",
+            ExceptionKind::Other(vector) => {
+                return write!(f, "exception {}", vector)
+            },
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl From<ExcpInfo> for ExceptionInfo {
+    fn from(info: ExcpInfo) -> Self {
+        Self {
+            pid: Pid::new(info.pid as i32),
+            tid: info.tid as Tid,
+            kind: ExceptionKind::from_vector(info.num),
+            address: info.addr,
+            instruction_pointer: info.pc,
+            registers: None,
+        }
+    }
+}
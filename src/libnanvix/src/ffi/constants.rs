@@ -0,0 +1,20 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::misc::KMOD_CMDLINE_MAX;
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum length of a name, excluding the null terminator.
+///
+/// This matches the longest string that the kernel stores, which is the command
+/// line of a kernel module.
+pub const NAME_MAX: usize = KMOD_CMDLINE_MAX - 1;
@@ -0,0 +1,20 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Modules
+//==============================================================================
+
+mod constants;
+mod name;
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+pub use self::{
+    constants::*,
+    name::*,
+};
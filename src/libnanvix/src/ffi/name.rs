@@ -0,0 +1,135 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    ffi::NAME_MAX,
+    kcall::KcallError,
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A name that is handed over to the kernel as a null-terminated string.
+///
+/// **Notes**
+///
+/// - The name is copied into a fixed-size buffer, thus no allocation is
+///   needed.
+/// - Names that are longer than [`NAME_MAX`] bytes or that have interior null
+///   bytes are rejected.
+///
+pub struct Name<'a> {
+    /// Borrowed name.
+    name: &'a str,
+    /// Null-terminated copy of the name.
+    buf: [u8; NAME_MAX + 1],
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<'a> Name<'a> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a name.
+    ///
+    /// **Parameters**
+    /// - `name` - Name.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the name is returned. Upon failure, an
+    /// error is returned instead.
+    ///
+    pub fn new(name: &'a str) -> Result<Self, KcallError> {
+        let bytes: &[u8] = name.as_bytes();
+        if bytes.len() > NAME_MAX || bytes.contains(&0) {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let mut buf: [u8; NAME_MAX + 1] = [0; NAME_MAX + 1];
+        buf[..bytes.len()].copy_from_slice(bytes);
+
+        Ok(Self { name, buf })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets a pointer to the null-terminated copy of the target name.
+    ///
+    /// **Return**
+    ///
+    /// A pointer to the null-terminated copy of the target name, which is valid
+    /// while the target name is alive.
+    ///
+    pub fn as_ptr(&self) -> *const u8 {
+        self.buf.as_ptr()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the bytes of the target name, including the null terminator.
+    ///
+    /// **Return**
+    ///
+    /// The bytes of the target name, including the null terminator.
+    ///
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        &self.buf[..=self.name.len()]
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the target name as a string slice.
+    ///
+    /// **Return**
+    ///
+    /// The borrowed name, without the null terminator.
+    ///
+    pub fn as_str(&self) -> &'a str {
+        self.name
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the length of the target name.
+    ///
+    /// **Return**
+    ///
+    /// The length of the target name (in bytes), excluding the null
+    /// terminator.
+    ///
+    pub fn len(&self) -> usize {
+        self.name.len()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target name is empty.
+    ///
+    /// **Return**
+    ///
+    /// If the target name is empty, `true` is returned. Otherwise, `false` is
+    /// returned instead.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.name.is_empty()
+    }
+}
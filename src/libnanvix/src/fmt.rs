@@ -0,0 +1,89 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::{
+    fmt::{
+        self,
+        Write,
+    },
+    str,
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A formatter sink that writes into a byte buffer.
+///
+struct SliceWriter<'a> {
+    /// Underlying buffer.
+    buf: &'a mut [u8],
+    /// Number of bytes that were written.
+    len: usize,
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl<'a> fmt::Write for SliceWriter<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end: usize = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        let dst: &mut [u8] =
+            self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Formats text into a buffer.
+///
+/// **Parameters**
+/// - `buf` - Storage location for the text.
+/// - `args` - Formatting arguments, as built by [`format_args!`].
+///
+/// **Return**
+///
+/// Upon successful completion, the formatted text is returned, borrowed from
+/// `buf`. If the text does not fit in `buf`, or if a formatting trait fails,
+/// an error is returned instead.
+///
+/// **Notes**
+///
+/// - On failure, `buf` may hold part of the text.
+///
+/// **Example**
+///
+/// ```ignore
+/// let mut buf: [u8; CHANNEL_MESSAGE_MAX] = [0; CHANNEL_MESSAGE_MAX];
+/// let msg: &str = fmt::format_into(&mut buf, format_args!("pid={}", pid))?;
+/// tx.send(msg.as_bytes())?;
+/// ```
+///
+pub fn format_into<'a>(
+    buf: &'a mut [u8],
+    args: fmt::Arguments,
+) -> Result<&'a str, fmt::Error> {
+    let mut writer: SliceWriter = SliceWriter { buf, len: 0 };
+    writer.write_fmt(args)?;
+
+    let SliceWriter { buf, len } = writer;
+    str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
@@ -0,0 +1,345 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    iam::{
+        self,
+        types::{
+            GroupID,
+            UserID,
+        },
+    },
+    kcall::{
+        self,
+        KcallError,
+    },
+};
+use core::fmt;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// User ID.
+///
+/// **Notes**
+///
+/// - This structure is transparent, so that it has the same layout of the
+/// `uid_t` type in the kernel space.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Uid(UserID);
+
+///
+/// **Description**
+///
+/// Group ID.
+///
+/// **Notes**
+///
+/// - This structure is transparent, so that it has the same layout of the
+/// `gid_t` type in the kernel space.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Gid(GroupID);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Uid {
+    ///
+    /// **Description**
+    ///
+    /// Creates a user ID.
+    ///
+    /// **Parameters**
+    ///
+    /// - `uid` - Raw user ID.
+    ///
+    /// **Return**
+    ///
+    /// The user ID.
+    ///
+    pub const fn new(uid: UserID) -> Self {
+        Self(uid)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value of the user ID.
+    ///
+    /// **Return**
+    ///
+    /// The raw value of the user ID.
+    ///
+    pub const fn raw(&self) -> UserID {
+        self.0
+    }
+}
+
+impl Gid {
+    ///
+    /// **Description**
+    ///
+    /// Creates a group ID.
+    ///
+    /// **Parameters**
+    ///
+    /// - `gid` - Raw group ID.
+    ///
+    /// **Return**
+    ///
+    /// The group ID.
+    ///
+    pub const fn new(gid: GroupID) -> Self {
+        Self(gid)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value of the group ID.
+    ///
+    /// **Return**
+    ///
+    /// The raw value of the group ID.
+    ///
+    pub const fn raw(&self) -> GroupID {
+        self.0
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl fmt::Display for Uid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Gid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Gets the user ID of the calling process.
+///
+/// **Return**
+///
+/// The user ID of the calling process is returned.
+///
+pub fn get_uid() -> Uid {
+    Uid(iam::getuid())
+}
+
+///
+/// **Description**
+///
+/// Gets the effective user ID of the calling process.
+///
+/// **Return**
+///
+/// The effective user ID of the calling process is returned.
+///
+pub fn get_euid() -> Uid {
+    Uid(iam::geteuid())
+}
+
+///
+/// **Description**
+///
+/// Gets the group ID of the calling process.
+///
+/// **Return**
+///
+/// The group ID of the calling process is returned.
+///
+pub fn get_gid() -> Gid {
+    Gid(iam::getgid())
+}
+
+///
+/// **Description**
+///
+/// Gets the effective group ID of the calling process.
+///
+/// **Return**
+///
+/// The effective group ID of the calling process is returned.
+///
+pub fn get_egid() -> Gid {
+    Gid(iam::getegid())
+}
+
+///
+/// **Description**
+///
+/// Sets the user ID of the calling process.
+///
+/// **Parameters**
+///
+/// - `uid` - New user ID.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead. If the calling process is not allowed to change its user
+/// ID, [`KcallError::PermissionDenied`] is returned.
+///
+pub fn set_uid(uid: Uid) -> Result<(), KcallError> {
+    kcall::decode(iam::setuid(uid.0) as u32)?;
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Sets the effective user ID of the calling process.
+///
+/// **Parameters**
+///
+/// - `uid` - New effective user ID.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead. If the calling process is not allowed to change its
+/// effective user ID, [`KcallError::PermissionDenied`] is returned.
+///
+pub fn set_euid(uid: Uid) -> Result<(), KcallError> {
+    kcall::decode(iam::seteuid(uid.0) as u32)?;
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Sets the group ID of the calling process.
+///
+/// **Parameters**
+///
+/// - `gid` - New group ID.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead. If the calling process is not allowed to change its group
+/// ID, [`KcallError::PermissionDenied`] is returned.
+///
+pub fn set_gid(gid: Gid) -> Result<(), KcallError> {
+    kcall::decode(iam::setgid(gid.0) as u32)?;
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Sets the effective group ID of the calling process.
+///
+/// **Parameters**
+///
+/// - `gid` - New effective group ID.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead. If the calling process is not allowed to change its
+/// effective group ID, [`KcallError::PermissionDenied`] is returned.
+///
+pub fn set_egid(gid: Gid) -> Result<(), KcallError> {
+    kcall::decode(iam::setegid(gid.0) as u32)?;
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Drops the privileges of the calling process to those of a user and group.
+///
+/// **Parameters**
+///
+/// - `uid` - Target user ID.
+/// - `gid` - Target group ID.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead. If the calling process is not allowed to take on the
+/// target credentials, or if they did not take effect, then
+/// [`KcallError::PermissionDenied`] is returned.
+///
+/// **Notes**
+///
+/// - The group ID is set before the user ID, because a process that has given
+///   up the superuser may no longer change its group ID.
+/// - Changes are checked by reading back both the real and effective IDs. If
+///   the user ID may not be set, the group IDs are restored before the error
+///   is returned. Once the user ID is set, privileges may no longer be
+///   regained, thus nothing is restored.
+///
+pub fn drop_privileges(uid: Uid, gid: Gid) -> Result<(), KcallError> {
+    let (old_gid, old_egid): (Gid, Gid) = (get_gid(), get_egid());
+
+    set_gid(gid)?;
+    if get_gid() != gid || get_egid() != gid {
+        let _ = restore_gids(old_gid, old_egid);
+        return Err(KcallError::PermissionDenied);
+    }
+
+    if let Err(e) = set_uid(uid) {
+        let _ = restore_gids(old_gid, old_egid);
+        return Err(e);
+    }
+    if get_uid() != uid || get_euid() != uid {
+        return Err(KcallError::PermissionDenied);
+    }
+
+    Ok(())
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Restores the group IDs of the calling process.
+///
+/// **Parameters**
+///
+/// - `gid` - Group ID to restore.
+/// - `egid` - Effective group ID to restore.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+fn restore_gids(gid: Gid, egid: Gid) -> Result<(), KcallError> {
+    set_gid(gid)?;
+    set_egid(egid)
+}
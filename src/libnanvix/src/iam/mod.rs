@@ -3,6 +3,7 @@
  * Licensed under the MIT License.
  */
 
+pub mod cred;
 pub mod types;
 
 //==============================================================================
@@ -71,7 +72,9 @@ pub fn getgid() -> GroupID {
 /// The effective group ID of the calling process is returned.
 ///
 pub fn getegid() -> GroupID {
-    unsafe { kcall::kcall0(KcallNumbers::GetEffectiveUserID as u32) as GroupID }
+    unsafe {
+        kcall::kcall0(KcallNumbers::GetEffectiveUserGroupID as u32) as GroupID
+    }
 }
 
 ///
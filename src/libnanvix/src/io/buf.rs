@@ -0,0 +1,251 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    io::{
+        self,
+        Fd,
+    },
+    kcall::KcallError,
+};
+use core::fmt;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A writer that accumulates output in a fixed-size buffer before writing it
+/// to a file descriptor.
+///
+/// **Notes**
+///
+/// - The buffer lives in the writer, so no allocation is required. It is
+///   flushed whenever it fills up and when the writer is dropped. Errors that
+///   occur while flushing on drop are ignored.
+/// - A full buffer is written with a single kernel call, as long as `N` does
+///   not exceed [`io::WRITE_MAX`].
+/// - Formatting only reports an opaque [`fmt::Error`]. The error that caused
+///   a write to fail is kept in the writer until it is taken with
+///   [`BufWriter::take_error`].
+///
+/// **Example**
+///
+/// ```ignore
+/// let mut writer: BufWriter<64> = BufWriter::new(Fd::STDOUT);
+/// write!(&mut writer, "pid={}", pm::Pid::SELF)?;
+/// writer.flush()?;
+/// ```
+///
+pub struct BufWriter<const N: usize> {
+    /// Target file descriptor.
+    fd: Fd,
+    /// Pending bytes.
+    buf: [u8; N],
+    /// Number of pending bytes.
+    len: usize,
+    /// Number of bytes written to the file descriptor.
+    flushed: usize,
+    /// Last error that occurred while writing to the file descriptor.
+    error: Option<KcallError>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<const N: usize> BufWriter<N> {
+    /// Capacity of the buffer. Writers that have no room for pending bytes
+    /// are rejected at compile time.
+    const CAPACITY: usize = {
+        assert!(N > 0, "buffered writers need a buffer");
+        N
+    };
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a buffered writer.
+    ///
+    /// **Parameters**
+    ///
+    /// - `fd` - Target file descriptor.
+    ///
+    /// **Return**
+    ///
+    /// A buffered writer that has no pending bytes.
+    ///
+    pub const fn new(fd: Fd) -> Self {
+        Self {
+            fd,
+            buf: [0; N],
+            len: 0,
+            flushed: 0,
+            error: None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the target file descriptor.
+    ///
+    /// **Return**
+    ///
+    /// The file descriptor that the target writer writes to.
+    ///
+    pub fn fd(&self) -> Fd {
+        self.fd
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the bytes that were not written to the file descriptor yet.
+    ///
+    /// **Return**
+    ///
+    /// The pending bytes of the target writer.
+    ///
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of bytes written to the file descriptor.
+    ///
+    /// **Return**
+    ///
+    /// The number of bytes that the target writer has flushed so far.
+    ///
+    pub fn flushed(&self) -> usize {
+        self.flushed
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Takes the last error that occurred while writing to the file
+    /// descriptor.
+    ///
+    /// **Return**
+    ///
+    /// If a write failed since the error was last taken, the error that caused
+    /// it is returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn take_error(&mut self) -> Option<KcallError> {
+        self.error.take()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Writes all pending bytes to the file descriptor.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty result is returned. Upon failure, an
+    /// error is returned instead and pending bytes are discarded.
+    ///
+    /// **Notes**
+    ///
+    /// - Upon failure, the underlying error is kept for
+    ///   [`BufWriter::take_error`]. If the kernel accepts no bytes at all,
+    ///   [`KcallError::WriteZero`] is kept instead.
+    ///
+    pub fn flush(&mut self) -> fmt::Result {
+        let mut offset: usize = 0;
+        let result: fmt::Result = loop {
+            if offset == self.len {
+                break Ok(());
+            }
+            match io::write(self.fd, &self.buf[offset..self.len]) {
+                Ok(0) => {
+                    self.error = Some(KcallError::WriteZero);
+                    break Err(fmt::Error);
+                },
+                Err(e) => {
+                    self.error = Some(e);
+                    break Err(fmt::Error);
+                },
+                Ok(count) => offset += count,
+            }
+        };
+        self.flushed += offset;
+        self.len = 0;
+        result
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends bytes to the buffer, flushing it whenever it fills up.
+    ///
+    /// **Parameters**
+    /// - `bytes` - Bytes to append.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty result is returned. Upon failure, an
+    /// error is returned instead.
+    ///
+    fn push(&mut self, mut bytes: &[u8]) -> fmt::Result {
+        while !bytes.is_empty() {
+            // Flush buffer if it is full.
+            if self.len == Self::CAPACITY {
+                self.flush()?;
+            }
+
+            let count: usize = bytes.len().min(Self::CAPACITY - self.len);
+            self.buf[self.len..self.len + count]
+                .copy_from_slice(&bytes[..count]);
+            self.len += count;
+            bytes = &bytes[count..];
+        }
+
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl<const N: usize> fmt::Write for BufWriter<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push(s.as_bytes())
+    }
+}
+
+impl<const N: usize> io::Write for BufWriter<N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, KcallError> {
+        // The whole buffer is accepted, unless flushing fails.
+        match self.push(buf) {
+            Ok(()) => Ok(buf.len()),
+            Err(_) => Err(self.take_error().unwrap_or(KcallError::Again)),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), KcallError> {
+        match BufWriter::flush(self) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(self.take_error().unwrap_or(KcallError::Again)),
+        }
+    }
+}
+
+impl<const N: usize> Drop for BufWriter<N> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
@@ -0,0 +1,11 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum number of bytes that the kernel accepts in a single write.
+pub const WRITE_MAX: usize = 128;
@@ -0,0 +1,185 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Operations that a file descriptor may be used for.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FdAccess {
+    /// The file descriptor may only be read.
+    ReadOnly,
+    /// The file descriptor may only be written.
+    WriteOnly,
+    /// The file descriptor may be both read and written.
+    ReadWrite,
+}
+
+///
+/// **Description**
+///
+/// File descriptor
+///
+/// **Notes**
+///
+/// - A file descriptor carries the operations that it may be used for, which
+///   are checked before any kernel call is issued. Only the raw value is
+///   handed over to the kernel. See [`Fd::as_raw`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fd {
+    /// Raw file descriptor.
+    raw: i32,
+    /// Operations that the file descriptor may be used for.
+    access: FdAccess,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl FdAccess {
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target access allows reading.
+    ///
+    /// **Return**
+    ///
+    /// If reading is allowed, `true` is returned. Otherwise, `false` is
+    /// returned instead.
+    ///
+    pub const fn is_readable(&self) -> bool {
+        matches!(self, FdAccess::ReadOnly | FdAccess::ReadWrite)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target access allows writing.
+    ///
+    /// **Return**
+    ///
+    /// If writing is allowed, `true` is returned. Otherwise, `false` is
+    /// returned instead.
+    ///
+    pub const fn is_writable(&self) -> bool {
+        matches!(self, FdAccess::WriteOnly | FdAccess::ReadWrite)
+    }
+}
+
+impl Fd {
+    /// File descriptor of the standard error.
+    pub const STDERR: Fd = Fd::with_access(2, FdAccess::WriteOnly);
+    /// File descriptor of the standard output.
+    pub const STDOUT: Fd = Fd::with_access(1, FdAccess::WriteOnly);
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a file descriptor from its raw value.
+    ///
+    /// **Parameters**
+    ///
+    /// - `fd` - Raw file descriptor.
+    ///
+    /// **Return**
+    ///
+    /// The file descriptor, which may only be written.
+    ///
+    /// **Notes**
+    ///
+    /// - The file descriptor is not checked. Writing to an invalid file
+    ///   descriptor fails instead.
+    /// - The kernel only writes to file descriptors, thus they are write-only
+    ///   unless stated otherwise. See [`Fd::with_access`].
+    ///
+    pub const fn from_raw(fd: i32) -> Self {
+        Self::with_access(fd, FdAccess::WriteOnly)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a file descriptor from its raw value and the operations that it
+    /// may be used for.
+    ///
+    /// **Parameters**
+    ///
+    /// - `fd` - Raw file descriptor.
+    /// - `access` - Operations that the file descriptor may be used for.
+    ///
+    /// **Return**
+    ///
+    /// The file descriptor.
+    ///
+    /// **Notes**
+    ///
+    /// - Neither the file descriptor nor its access are checked against
+    ///   the kernel.
+    ///
+    pub const fn with_access(fd: i32, access: FdAccess) -> Self {
+        Self { raw: fd, access }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value of the file descriptor.
+    ///
+    /// **Return**
+    ///
+    /// The raw value of the file descriptor.
+    ///
+    pub const fn as_raw(&self) -> i32 {
+        self.raw
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the operations that the file descriptor may be used for.
+    ///
+    /// **Return**
+    ///
+    /// The operations that the file descriptor may be used for.
+    ///
+    pub const fn access(&self) -> FdAccess {
+        self.access
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the file descriptor may be read.
+    ///
+    /// **Return**
+    ///
+    /// If the file descriptor may be read, `true` is returned. Otherwise,
+    /// `false` is returned instead.
+    ///
+    pub const fn is_readable(&self) -> bool {
+        self.access.is_readable()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the file descriptor may be written.
+    ///
+    /// **Return**
+    ///
+    /// If the file descriptor may be written, `true` is returned. Otherwise,
+    /// `false` is returned instead.
+    ///
+    pub const fn is_writable(&self) -> bool {
+        self.access.is_writable()
+    }
+}
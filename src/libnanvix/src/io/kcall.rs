@@ -0,0 +1,295 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    io::{
+        Fd,
+        WRITE_MAX,
+    },
+    kcall::{
+        self,
+        KcallError,
+        KcallNumbers,
+    },
+};
+use core::sync::atomic::{
+    AtomicBool,
+    Ordering,
+};
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Asserts if writes to the standard output and error are discarded.
+static STDIO_DISCARDED: AtomicBool = AtomicBool::new(false);
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Writes a buffer to a file descriptor.
+///
+/// **Parameters**
+/// - `fd` - Target file descriptor.
+/// - `buf` - Buffer to write.
+///
+/// **Return**
+///
+/// Upon successful completion, the number of bytes written is returned. This
+/// may be less than the length of `buf`, in which case the caller should write
+/// the remaining bytes again. Upon failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - At most [`WRITE_MAX`] bytes are written in a single call.
+/// - Empty buffers are not handed to the kernel, and zero is returned.
+/// - If `fd` may not be written, [`KcallError::AccessDenied`] is returned.
+/// - Once the standard output and error are discarded, writes to them are not
+///   handed to the kernel, and they report the bytes as written. See
+///   [`is_stdio_discarded`].
+///
+pub fn write(fd: Fd, buf: &[u8]) -> Result<usize, KcallError> {
+    if !fd.is_writable() {
+        return Err(KcallError::AccessDenied);
+    }
+
+    // Check for buffers that cannot be described by kernel call arguments.
+    kcall::ptr_len(buf)?;
+
+    // Nothing to do.
+    if buf.is_empty() {
+        return Ok(0);
+    }
+
+    if is_stdio(fd) && is_stdio_discarded() {
+        return Ok(buf.len().min(WRITE_MAX));
+    }
+
+    let (ptr, len): (u32, u32) =
+        kcall::ptr_len(&buf[..buf.len().min(WRITE_MAX)])?;
+    let ret: u32 = unsafe {
+        kcall::kcall3_checked(
+            KcallNumbers::Write as u32,
+            fd.as_raw() as u32,
+            ptr,
+            len,
+        )?
+    };
+
+    // The kernel accounts for a trailing null character that it appends to
+    // the buffer, thus clamp the reported count to what was actually asked.
+    Ok(ret.min(len) as usize)
+}
+
+///
+/// **Description**
+///
+/// Reads bytes from a file descriptor.
+///
+/// **Parameters**
+/// - `fd` - Source file descriptor.
+/// - `buf` - Storage location for the bytes.
+///
+/// **Return**
+///
+/// Upon successful completion, the number of bytes read is returned. Upon
+/// failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - If `fd` may not be read, [`KcallError::AccessDenied`] is returned.
+/// - The kernel has no read kernel call yet, thus reading a readable file
+///   descriptor fails with [`KcallError::NotSupported`].
+///
+pub fn read(fd: Fd, buf: &mut [u8]) -> Result<usize, KcallError> {
+    if !fd.is_readable() {
+        return Err(KcallError::AccessDenied);
+    }
+
+    let _ = buf;
+    Err(KcallError::NotSupported)
+}
+
+///
+/// **Description**
+///
+/// Writes several buffers to a file descriptor.
+///
+/// **Parameters**
+/// - `fd` - Target file descriptor.
+/// - `bufs` - Buffers to write, in order.
+///
+/// **Return**
+///
+/// Upon successful completion, the total number of bytes written is returned.
+/// Upon failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - The kernel has no scatter-gather write, thus buffers are written one at a
+///   time with [`write`]. Writing stops at the first short write, in which case
+///   fewer bytes than requested are reported.
+/// - If writing fails after some bytes were written, the number of bytes
+///   written so far is returned instead of the error.
+///
+pub fn writev(fd: Fd, bufs: &[&[u8]]) -> Result<usize, KcallError> {
+    let mut total: usize = 0;
+
+    for buf in bufs.iter() {
+        let count: usize = match write(fd, buf) {
+            Ok(count) => count,
+            Err(_) if total > 0 => break,
+            Err(e) => return Err(e),
+        };
+        total += count;
+
+        // Stop on a short write.
+        if count < buf.len() {
+            break;
+        }
+    }
+
+    Ok(total)
+}
+
+///
+/// **Description**
+///
+/// Writes a whole buffer to a file descriptor.
+///
+/// **Parameters**
+/// - `fd` - Target file descriptor.
+/// - `buf` - Buffer to write.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+/// **Notes**
+///
+/// - Short writes are retried with the remaining bytes. See [`write_all_with`].
+///
+pub fn write_all(fd: Fd, buf: &[u8]) -> Result<(), KcallError> {
+    write_all_with(buf, |remaining| write(fd, remaining))
+}
+
+///
+/// **Description**
+///
+/// Writes a whole buffer with a function that may write fewer bytes than
+/// requested.
+///
+/// **Parameters**
+/// - `buf` - Buffer to write.
+/// - `write` - Function that writes a prefix of the bytes that it is handed
+///   and reports how many bytes it wrote.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead. If `write` writes no bytes, [`KcallError::WriteZero`] is
+/// returned.
+///
+/// **Notes**
+///
+/// - Writes that are interrupted are retried. Any other error stops writing,
+///   and bytes that were written so far are not reported.
+///
+/// **Example**
+///
+/// ```ignore
+/// io::write_all_with(msg, |bytes| io::write(fd, bytes))?;
+/// ```
+///
+pub fn write_all_with<F>(mut buf: &[u8], mut write: F) -> Result<(), KcallError>
+where
+    F: FnMut(&[u8]) -> Result<usize, KcallError>,
+{
+    while !buf.is_empty() {
+        match write(buf) {
+            Ok(0) => return Err(KcallError::WriteZero),
+            Ok(count) => buf = &buf[count.min(buf.len())..],
+            Err(KcallError::Interrupted) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Checks if writes to the standard output and error are discarded.
+///
+/// **Return**
+///
+/// If writes to [`Fd::STDOUT`] and [`Fd::STDERR`] are discarded, `true` is
+/// returned. Otherwise, `false` is returned instead.
+///
+/// **Notes**
+///
+/// - Writes are discarded once the calling process calls [`discard_stdio`],
+///   and they are never restored.
+///
+pub fn is_stdio_discarded() -> bool {
+    STDIO_DISCARDED.load(Ordering::Acquire)
+}
+
+///
+/// **Description**
+///
+/// Discards the writes to the standard output and error of the calling
+/// process from then on.
+///
+/// **Notes**
+///
+/// - This is what a daemon does to its standard output and error. The kernel
+///   does not track the parents of processes, thus processes already outlive
+///   the ones that spawned them, and there is nothing to detach from.
+///
+/// **Example**
+///
+/// ```ignore
+/// io::discard_stdio();
+/// let rx: Receiver = Receiver::create(SERVICE_TAG)?;
+/// loop {
+///     serve(&rx)?;
+/// }
+/// ```
+///
+pub fn discard_stdio() {
+    STDIO_DISCARDED.store(true, Ordering::Release);
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Checks if a file descriptor names the standard output or error.
+///
+/// **Parameters**
+/// - `fd` - Target file descriptor.
+///
+/// **Return**
+///
+/// If `fd` names the standard output or error, `true` is returned. Otherwise,
+/// `false` is returned instead.
+///
+fn is_stdio(fd: Fd) -> bool {
+    fd.as_raw() == Fd::STDOUT.as_raw() || fd.as_raw() == Fd::STDERR.as_raw()
+}
@@ -0,0 +1,28 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Modules
+//==============================================================================
+
+mod buf;
+mod constants;
+mod fd;
+mod kcall;
+mod stdout;
+mod traits;
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+pub use self::{
+    buf::*,
+    constants::*,
+    fd::*,
+    kcall::*,
+    stdout::*,
+    traits::*,
+};
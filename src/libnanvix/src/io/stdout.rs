@@ -0,0 +1,115 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    io::{
+        self,
+        BufWriter,
+        Fd,
+        WRITE_MAX,
+    },
+    kcall::KcallError,
+};
+use core::fmt;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A handle to the standard output.
+///
+/// **Notes**
+///
+/// - Output is accumulated in a fixed-size buffer that lives in the handle, so
+///   no allocation is required. The buffer is flushed whenever it fills up and
+///   when the handle is dropped.
+///
+pub struct Stdout {
+    /// Underlying writer.
+    writer: BufWriter<WRITE_MAX>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Stdout {
+    ///
+    /// **Description**
+    ///
+    /// Creates a handle to the standard output.
+    ///
+    /// **Return**
+    ///
+    /// A handle to the standard output.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            writer: BufWriter::new(Fd::STDOUT),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Writes all pending bytes to the standard output.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty result is returned. Upon failure, an
+    /// error is returned instead and pending bytes are discarded.
+    ///
+    pub fn flush(&mut self) -> fmt::Result {
+        self.writer.flush()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Takes the last error that occurred while writing to the standard
+    /// output.
+    ///
+    /// **Return**
+    ///
+    /// If a write failed since the error was last taken, the error that caused
+    /// it is returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn take_error(&mut self) -> Option<KcallError> {
+        self.writer.take_error()
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Default for Stdout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Write for Stdout {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_str(s)
+    }
+}
+
+impl io::Write for Stdout {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, KcallError> {
+        io::Write::write(&mut self.writer, buf)
+    }
+
+    fn flush(&mut self) -> Result<(), KcallError> {
+        io::Write::flush(&mut self.writer)
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    io::{
+        self,
+        Fd,
+    },
+    kcall::KcallError,
+};
+
+//==============================================================================
+// Traits
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A sink of bytes.
+///
+/// **Notes**
+///
+/// - The trait is object safe, so that writers of different kinds may be
+///   handed around as `&mut dyn Write`.
+///
+/// **Example**
+///
+/// ```ignore
+/// let mut stdout: Fd = Fd::STDOUT;
+/// let writer: &mut dyn io::Write = &mut stdout;
+/// writer.write_all(b"hello\n")?;
+/// ```
+///
+pub trait Write {
+    ///
+    /// **Description**
+    ///
+    /// Writes a buffer.
+    ///
+    /// **Parameters**
+    /// - `buf` - Buffer to write.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the number of bytes written is returned.
+    /// This may be less than the length of `buf`. Upon failure, an error is
+    /// returned instead.
+    ///
+    fn write(&mut self, buf: &[u8]) -> Result<usize, KcallError>;
+
+    ///
+    /// **Description**
+    ///
+    /// Writes a whole buffer.
+    ///
+    /// **Parameters**
+    /// - `buf` - Buffer to write.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Short writes are retried with the remaining bytes. See
+    ///   [`io::write_all_with`].
+    ///
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), KcallError> {
+        io::write_all_with(buf, |remaining| self.write(remaining))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Writes all bytes that the target writer holds back.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Writers that hold no bytes back do nothing, which is the default.
+    ///
+    fn flush(&mut self) -> Result<(), KcallError> {
+        Ok(())
+    }
+}
+
+///
+/// **Description**
+///
+/// A source of bytes.
+///
+/// **Notes**
+///
+/// - The trait is object safe, so that readers of different kinds may be
+///   handed around as `&mut dyn Read`.
+///
+pub trait Read {
+    ///
+    /// **Description**
+    ///
+    /// Reads bytes.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the bytes.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the number of bytes read is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, KcallError>;
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Write for Fd {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, KcallError> {
+        io::write(*self, buf)
+    }
+}
+
+impl Read for Fd {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, KcallError> {
+        io::read(*self, buf)
+    }
+}
+
+impl<W: Write + ?Sized> Write for &mut W {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, KcallError> {
+        (**self).write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), KcallError> {
+        (**self).write_all(buf)
+    }
+
+    fn flush(&mut self) -> Result<(), KcallError> {
+        (**self).flush()
+    }
+}
+
+impl<R: Read + ?Sized> Read for &mut R {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, KcallError> {
+        (**self).read(buf)
+    }
+}
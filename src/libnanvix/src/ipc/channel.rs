@@ -0,0 +1,585 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    io,
+    ipc::{
+        self,
+        CHANNEL_MESSAGE_MAX,
+        CHANNEL_TAG_MAX,
+        MAILBOX_MESSAGE_SIZE,
+    },
+    kcall::{
+        self,
+        KcallError,
+    },
+    pm::{
+        self,
+        Pid,
+        ProcessInfo,
+    },
+    sync::{
+        self,
+        Semaphore,
+    },
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Size of a frame that carries a message (in bytes).
+const FRAME_SIZE: usize = MAILBOX_MESSAGE_SIZE as usize;
+
+/// Base of the keys of the semaphores that count pending messages.
+const READY_KEY_BASE: u32 = 0x8000_0000;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Receiving half of a channel.
+///
+/// **Notes**
+///
+/// - The underlying mailbox is created on behalf of the calling process and
+///   unlinked when the receiver is dropped.
+/// - The kernel has neither a non-blocking read nor a poll operation on
+///   mailboxes. For this reason, the number of pending messages is tracked by
+///   a counting semaphore, which senders raise after every message that they
+///   write to the underlying mailbox. Messages that are written to the
+///   mailbox by other means are not accounted for.
+/// - Messages are always copied into the buffer of the caller. The kernel
+///   keeps messages in its own memory and has no means to map them into the
+///   address space of a process, thus a zero-copy receive is not available.
+///
+#[derive(Debug)]
+pub struct Receiver {
+    /// ID of the underlying open mailbox.
+    ombxid: u32,
+    /// Semaphore that counts pending messages.
+    ready: Semaphore,
+}
+
+///
+/// **Description**
+///
+/// Sending half of a channel.
+///
+/// **Notes**
+///
+/// - The kernel hands back the same open mailbox to all lookups for an owner
+///   and tag in a process. For this reason, a sender that refers to a mailbox
+///   owned by the calling process does not close it when dropped, and leaves
+///   it to the receiver.
+///
+#[derive(Debug)]
+pub struct Sender {
+    /// ID of the underlying open mailbox.
+    ombxid: u32,
+    /// ID of the semaphore that counts pending messages.
+    ready: u32,
+    /// Close the underlying mailbox on drop?
+    close: bool,
+}
+
+///
+/// **Description**
+///
+/// An iterator that receives the pending messages of a channel.
+///
+/// **Notes**
+///
+/// - The iterator ends once no message is pending. If a message may not be
+///   received, the error is yielded and the iterator ends.
+///
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct Drain<'a> {
+    /// Underlying receiving half.
+    rx: &'a mut Receiver,
+    /// Has the iterator ended?
+    done: bool,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Receiver {
+    ///
+    /// **Description**
+    ///
+    /// Creates the receiving half of a channel.
+    ///
+    /// **Parameters**
+    /// - `tag` - Name of the channel.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the receiving half of a channel is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Tags must not exceed [`CHANNEL_TAG_MAX`].
+    ///
+    pub fn create(tag: u32) -> Result<Self, KcallError> {
+        if tag > CHANNEL_TAG_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let owner: Pid = self_pid()?;
+        let ready: Semaphore = Semaphore::get(ready_key(owner, tag), 0)?;
+        // A sender may have created the semaphore first, so drop pending counts.
+        ready.set_value(0)?;
+        let ombxid: u32 =
+            kcall::decode(ipc::mailbox_create(owner.into(), tag) as u32)?;
+
+        Ok(Receiver { ombxid, ready })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attaches to the receiving half of a channel that another process may
+    /// have created on behalf of the calling process.
+    ///
+    /// **Parameters**
+    /// - `tag` - Name of the channel.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the receiving half of a channel is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Unlike [`Receiver::create`], messages that are already pending are
+    ///   kept. If the channel does not exist yet, it is created.
+    ///
+    pub fn attach(tag: u32) -> Result<Self, KcallError> {
+        if tag > CHANNEL_TAG_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let owner: Pid = self_pid()?;
+        let ready: Semaphore = Semaphore::attach(ready_key(owner, tag))?;
+        let ombxid: u32 =
+            kcall::decode(ipc::mailbox_create(owner.into(), tag) as u32)?;
+
+        Ok(Receiver { ombxid, ready })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Receives a message, blocking the calling thread until one arrives.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the message.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the size of the received message is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - If `buf` is too small to hold the message, the message is discarded
+    ///   and an error is returned. A buffer that is at least
+    ///   [`CHANNEL_MESSAGE_MAX`] bytes long never fails for this reason.
+    ///
+    pub fn recv(&self, buf: &mut [u8]) -> Result<usize, KcallError> {
+        self.ready.down()?;
+        self.read(buf)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attempts to receive a message without blocking the calling thread.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the message.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the size of the received message is
+    /// returned, or `None` if no message is pending. Upon failure, an error is
+    /// returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The same restrictions on the size of `buf` as in [`Receiver::recv`]
+    ///   apply.
+    ///
+    pub fn try_recv(
+        &self,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, KcallError> {
+        if !self.ready.try_down()? {
+            return Ok(None);
+        }
+
+        self.read(buf).map(Some)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if a message is pending, without receiving it.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, `true` is returned if a message is pending
+    /// and `false` otherwise. Upon failure, an error is returned instead.
+    ///
+    pub fn is_ready(&self) -> Result<bool, KcallError> {
+        if !self.ready.try_down()? {
+            return Ok(false);
+        }
+
+        // Put the message back.
+        self.ready.up()?;
+
+        Ok(true)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Receives the pending messages without blocking the calling thread.
+    ///
+    /// **Return**
+    ///
+    /// An iterator over the messages that are pending.
+    ///
+    /// **Example**
+    ///
+    /// ```ignore
+    /// for msg in rx.drain() {
+    ///     handle(&msg?);
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn drain(&mut self) -> Drain<'_> {
+        Drain {
+            rx: self,
+            done: false,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Reads a message that is known to be pending from the underlying
+    /// mailbox.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the message.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the size of the received message is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    fn read(&self, buf: &mut [u8]) -> Result<usize, KcallError> {
+        let mut frame: [u8; FRAME_SIZE] = [0; FRAME_SIZE];
+
+        ipc::mailbox_read_buf(self.ombxid, &mut frame)?;
+
+        // Parse frame.
+        let len: usize = frame[0] as usize;
+        if len > CHANNEL_MESSAGE_MAX || len > buf.len() {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        buf[..len].copy_from_slice(&frame[1..=len]);
+
+        Ok(len)
+    }
+}
+
+impl Sender {
+    ///
+    /// **Description**
+    ///
+    /// Opens the sending half of a channel.
+    ///
+    /// **Parameters**
+    /// - `owner` - Process that owns the channel.
+    /// - `tag` - Name of the channel.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the sending half of a channel is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn open(owner: Pid, tag: u32) -> Result<Self, KcallError> {
+        if tag > CHANNEL_TAG_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let close: bool = self_pid()? != owner;
+        let ombxid: u32 =
+            kcall::decode(ipc::mailbox_open(owner.into(), tag) as u32)?;
+
+        // Get the semaphore that counts pending messages, without resetting it.
+        let ready: u32 =
+            match kcall::decode(pm::semget(ready_key(owner, tag)) as u32) {
+                Ok(ready) => ready,
+                Err(e) => {
+                    if close {
+                        let _ = ipc::mailbox_close(ombxid);
+                    }
+                    return Err(e);
+                },
+            };
+
+        Ok(Sender {
+            ombxid,
+            ready,
+            close,
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a channel on behalf of another process and opens its sending
+    /// half.
+    ///
+    /// **Parameters**
+    /// - `owner` - Process that owns the channel.
+    /// - `tag` - Name of the channel.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the sending half of a channel is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The owner takes the receiving half with [`Receiver::attach`], which
+    ///   keeps messages that were sent before it attached.
+    ///
+    pub(crate) fn create_for(owner: Pid, tag: u32) -> Result<Self, KcallError> {
+        if tag > CHANNEL_TAG_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let close: bool = self_pid()? != owner;
+        let ready: u32 =
+            kcall::decode(pm::semget(ready_key(owner, tag)) as u32)?;
+        let ombxid: u32 = match kcall::decode(ipc::mailbox_create(
+            owner.into(),
+            tag,
+        ) as u32)
+        {
+            Ok(ombxid) => ombxid,
+            Err(e) => {
+                if close {
+                    let _ = sync::semaphore_release(ready);
+                }
+                return Err(e);
+            },
+        };
+
+        Ok(Sender {
+            ombxid,
+            ready,
+            close,
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates another handle to the open mailbox of the target sending half.
+    ///
+    /// **Return**
+    ///
+    /// A sending half that shares the open mailbox of the target one, and that
+    /// never closes it.
+    ///
+    pub(super) fn share(&self) -> Self {
+        Sender {
+            ombxid: self.ombxid,
+            ready: self.ready,
+            close: false,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Duplicates the target sending half.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a new sending half of the same channel is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Sending halves are not [`Clone`], because a sending half of a channel
+    ///   that is owned by another process closes the open mailbox when it is
+    ///   dropped.
+    /// - The kernel hands back the same open mailbox to all lookups for an
+    ///   owner and tag in a process, and it does not count references to it.
+    ///   For this reason, only sending halves of channels that are owned by
+    ///   the calling process may be duplicated, and this function fails with
+    ///   [`KcallError::NotSupported`] for other ones.
+    ///
+    pub fn try_clone(&self) -> Result<Self, KcallError> {
+        if self.close {
+            return Err(KcallError::NotSupported);
+        }
+
+        Ok(self.share())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sends a message.
+    ///
+    /// **Parameters**
+    /// - `msg` - Message to send.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel enforces a fixed message size of [`MAILBOX_MESSAGE_SIZE`]
+    ///   bytes. One of these bytes carries the length of the message, thus
+    ///   messages longer than [`CHANNEL_MESSAGE_MAX`] bytes are rejected.
+    /// - The message is counted before it is written, thus nothing is written
+    ///   if it may not be counted. A receiver that takes the count early
+    ///   blocks in the kernel until the message is written.
+    /// - If writing fails, the count is taken back. If a receiver took it
+    ///   already, that receiver blocks until the next message.
+    ///
+    pub fn send(&self, msg: &[u8]) -> Result<(), KcallError> {
+        if msg.len() > CHANNEL_MESSAGE_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        // Build frame.
+        let mut frame: [u8; FRAME_SIZE] = [0; FRAME_SIZE];
+        frame[0] = msg.len() as u8;
+        frame[1..=msg.len()].copy_from_slice(msg);
+
+        sync::semaphore_up(self.ready)?;
+
+        if let Err(e) = ipc::mailbox_write_buf(self.ombxid, &frame) {
+            let _ = sync::semaphore_try_down(self.ready);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for Drain<'a> {
+    type Item = Result<Vec<u8>, KcallError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut buf: [u8; CHANNEL_MESSAGE_MAX] = [0; CHANNEL_MESSAGE_MAX];
+        match self.rx.try_recv(&mut buf) {
+            Ok(Some(len)) => Some(Ok(buf[..len].to_vec())),
+            Ok(None) => {
+                self.done = true;
+                None
+            },
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+impl io::Read for Receiver {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, KcallError> {
+        // Every read receives a whole message.
+        self.recv(buf)
+    }
+}
+
+impl Drop for Receiver {
+    fn drop(&mut self) {
+        let _ = ipc::mailbox_unlink(self.ombxid);
+    }
+}
+
+impl Drop for Sender {
+    fn drop(&mut self) {
+        if self.close {
+            let _ = ipc::mailbox_close(self.ombxid);
+            let _ = sync::semaphore_release(self.ready);
+        }
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Gets the PID of the calling process.
+///
+/// **Return**
+///
+/// Upon successful completion, the PID of the calling process is returned.
+/// Upon failure, an error is returned instead.
+///
+fn self_pid() -> Result<Pid, KcallError> {
+    let mut info: ProcessInfo = ProcessInfo::default();
+    kcall::decode(pm::pinfo(Pid::SELF, &mut info) as u32)?;
+    Ok(info.pid)
+}
+
+///
+/// **Description**
+///
+/// Computes the key of the semaphore that counts pending messages of a channel.
+///
+/// **Parameters**
+/// - `owner` - Process that owns the channel.
+/// - `tag` - Name of the channel.
+///
+/// **Return**
+///
+/// The key of the semaphore that counts pending messages of the channel.
+///
+fn ready_key(owner: Pid, tag: u32) -> u32 {
+    READY_KEY_BASE | ((u32::from(owner) & 0x7fff) << 16) | tag
+}
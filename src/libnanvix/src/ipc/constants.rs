@@ -12,3 +12,9 @@ pub const MAILBOX_OPEN_MAX: u32 = 16;
 
 /// Maximum size of a message
 pub const MAILBOX_MESSAGE_SIZE: u32 = 64;
+
+/// Maximum size of a message that is sent through a channel (in bytes).
+pub const CHANNEL_MESSAGE_MAX: usize = MAILBOX_MESSAGE_SIZE as usize - 1;
+
+/// Largest tag of a channel.
+pub const CHANNEL_TAG_MAX: u32 = 0xffff;
@@ -9,6 +9,7 @@
 
 use crate::kcall::{
     self,
+    KcallError,
     KcallNumbers,
 };
 //use core::ffi;
@@ -51,10 +52,68 @@ pub fn mailbox_write(ombxid: u32, buffer: *const u32, sz: u64) -> i32 {
 pub fn mailbox_read(ombxid: u32, buffer: *const u32, sz: u64) -> i32 {
     unsafe {
         kcall::kcall3(
-            KcallNumbers::Mailboxread as u32,
+            KcallNumbers::MailboxRead as u32,
             ombxid,
             buffer as u32,
             sz as u32,
         ) as i32
     }
 }
+
+///
+/// **Description**
+///
+/// Writes a buffer to a mailbox.
+///
+/// **Parameters**
+/// - `ombxid` - ID of the open mailbox.
+/// - `buf` - Buffer to write.
+///
+/// **Return**
+///
+/// Upon successful completion, the value returned by the kernel is returned.
+/// Upon failure, an error is returned instead.
+///
+pub(crate) fn mailbox_write_buf(
+    ombxid: u32,
+    buf: &[u8],
+) -> Result<u32, KcallError> {
+    let (ptr, len): (u32, u32) = kcall::ptr_len(buf)?;
+    unsafe {
+        kcall::kcall3_checked(
+            KcallNumbers::MailboxWrite as u32,
+            ombxid,
+            ptr,
+            len,
+        )
+    }
+}
+
+///
+/// **Description**
+///
+/// Reads from a mailbox into a buffer.
+///
+/// **Parameters**
+/// - `ombxid` - ID of the open mailbox.
+/// - `buf` - Storage location for the message.
+///
+/// **Return**
+///
+/// Upon successful completion, the value returned by the kernel is returned.
+/// Upon failure, an error is returned instead.
+///
+pub(crate) fn mailbox_read_buf(
+    ombxid: u32,
+    buf: &mut [u8],
+) -> Result<u32, KcallError> {
+    let (ptr, len): (u32, u32) = kcall::ptr_len_mut(buf)?;
+    unsafe {
+        kcall::kcall3_checked(
+            KcallNumbers::MailboxRead as u32,
+            ombxid,
+            ptr,
+            len,
+        )
+    }
+}
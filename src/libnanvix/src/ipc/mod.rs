@@ -7,14 +7,22 @@
 // Modules
 //==============================================================================
 
+mod channel;
 mod constants;
 mod kcall;
+pub mod mpsc;
+mod select;
+mod shared;
+pub mod spsc;
 
 //==============================================================================
 // Exports
 //==============================================================================
 
 pub use self::{
+    channel::*,
     constants::*,
     kcall::*,
+    select::*,
+    shared::*,
 };
@@ -0,0 +1,185 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    ipc::channel,
+    kcall::KcallError,
+    pm::{
+        self,
+        Pid,
+    },
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Receiving half of a multi-producer, single-consumer queue.
+///
+#[derive(Debug)]
+pub struct Receiver {
+    /// Underlying channel.
+    mailbox: channel::Receiver,
+}
+
+///
+/// **Description**
+///
+/// Sending half of a multi-producer, single-consumer queue.
+///
+/// **Notes**
+///
+/// - Senders are cheap to clone, and they may be moved to other threads of the
+///   calling process. They never release the underlying mailbox, which is left
+///   to the receiver.
+///
+#[derive(Debug)]
+pub struct Sender {
+    /// Underlying channel.
+    mailbox: channel::Sender,
+}
+
+//==============================================================================
+// Enumerations
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Errors of a non-blocking receive.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// No message is pending.
+    Empty,
+    /// Kernel call failed.
+    Kcall(KcallError),
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Receiver {
+    ///
+    /// **Description**
+    ///
+    /// Receives a message, blocking the calling thread until one arrives.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the message.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the size of the received message is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    pub fn recv(&self, buf: &mut [u8]) -> Result<usize, KcallError> {
+        self.mailbox.recv(buf)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attempts to receive a message without blocking the calling thread.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the message.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the size of the received message is
+    /// returned. If no message is pending, [`TryRecvError::Empty`] is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn try_recv(&self, buf: &mut [u8]) -> Result<usize, TryRecvError> {
+        self.mailbox.try_recv(buf)?.ok_or(TryRecvError::Empty)
+    }
+}
+
+impl Sender {
+    ///
+    /// **Description**
+    ///
+    /// Sends a message.
+    ///
+    /// **Parameters**
+    /// - `msg` - Message to send.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - While the underlying mailbox is full, the calling thread yields the
+    ///   processor and retries.
+    ///
+    pub fn send(&self, msg: &[u8]) -> Result<(), KcallError> {
+        loop {
+            match self.mailbox.send(msg) {
+                Err(KcallError::Again) => pm::thread_yield(),
+                ret => return ret,
+            }
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Clone for Sender {
+    fn clone(&self) -> Self {
+        Sender {
+            mailbox: self.mailbox.share(),
+        }
+    }
+}
+
+impl From<KcallError> for TryRecvError {
+    fn from(e: KcallError) -> Self {
+        TryRecvError::Kcall(e)
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Creates a multi-producer, single-consumer queue in the calling process.
+///
+/// **Parameters**
+/// - `tag` - Name of the queue.
+///
+/// **Return**
+///
+/// Upon successful completion, the sending and receiving halves of the queue
+/// are returned. Upon failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - The capacity of the queue is the capacity of a mailbox.
+/// - Tags must not exceed [`crate::ipc::CHANNEL_TAG_MAX`], and a tag must not
+///   be used by two queues of a process at the same time.
+///
+pub fn channel(tag: u32) -> Result<(Sender, Receiver), KcallError> {
+    let pid: Pid = pm::process_info(Pid::SELF)?.pid;
+    let mailbox: channel::Receiver = channel::Receiver::create(tag)?;
+    let sender: channel::Sender = channel::Sender::open(pid, tag)?;
+
+    Ok((Sender { mailbox: sender }, Receiver { mailbox }))
+}
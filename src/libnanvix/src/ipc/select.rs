@@ -0,0 +1,97 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    ipc::{
+        Receiver,
+        MAILBOX_OPEN_MAX,
+    },
+    kcall::KcallError,
+    pm,
+};
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Waits for any of several channels to have a pending message.
+///
+/// **Parameters**
+/// - `receivers` - Receiving halves of the target channels.
+///
+/// **Return**
+///
+/// Upon successful completion, the index in `receivers` of a channel that has
+/// a pending message is returned. Upon failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - The kernel cannot wait on several mailboxes at once. For this reason,
+///   channels are checked in order, and the calling thread yields the
+///   processor between sweeps. If several channels have pending messages, the
+///   one with the lowest index is reported.
+/// - The message is not received, and only a receiver of the reported channel
+///   may take it.
+///
+pub fn select(receivers: &[&Receiver]) -> Result<usize, KcallError> {
+    if receivers.is_empty() {
+        return Err(KcallError::InvalidArgument);
+    }
+
+    loop {
+        for (index, receiver) in receivers.iter().enumerate() {
+            if receiver.is_ready()? {
+                return Ok(index);
+            }
+        }
+
+        pm::thread_yield();
+    }
+}
+
+///
+/// **Description**
+///
+/// Waits for any of several channels to have a pending message, with a bound
+/// on the number of channels.
+///
+/// **Parameters**
+/// - `receivers` - Receiving halves of the target channels.
+///
+/// **Return**
+///
+/// Upon successful completion, the index in `receivers` of a channel that has
+/// a pending message is returned. Upon failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - The kernel cannot block a thread on several mailboxes at once, thus this
+///   function polls the channels as [`select`] does, and it takes the
+///   processor while it waits. It is meant to block in the kernel once the
+///   kernel offers a kernel call for that, without changes to its callers.
+/// - At most [`MAILBOX_OPEN_MAX`] channels may be waited on.
+/// - The message is not received, and only a receiver of the reported channel
+///   may take it.
+///
+/// **Example**
+///
+/// ```ignore
+/// let index: usize = ipc::wait_any(&[&requests, &control])?;
+/// ```
+///
+pub fn wait_any(receivers: &[&Receiver]) -> Result<usize, KcallError> {
+    if receivers.is_empty() || receivers.len() > MAILBOX_OPEN_MAX as usize {
+        return Err(KcallError::InvalidArgument);
+    }
+
+    select(receivers)
+}
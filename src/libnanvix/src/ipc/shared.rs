@@ -0,0 +1,229 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    ipc::{
+        self,
+        MAILBOX_MESSAGE_SIZE,
+        MAILBOX_OPEN_MAX,
+    },
+    kcall::{
+        self,
+        KcallError,
+    },
+    pm::Pid,
+};
+use core::sync::atomic::{
+    self,
+    AtomicU32,
+    Ordering,
+};
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Number of shared handles to each open mailbox of the calling process.
+static REFCOUNTS: [AtomicU32; MAILBOX_OPEN_MAX as usize] =
+    [NO_REFS; MAILBOX_OPEN_MAX as usize];
+
+/// Number of shared handles to an open mailbox that is not shared.
+#[allow(clippy::declare_interior_mutable_const)]
+const NO_REFS: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A handle to an open mailbox that is shared by several threads of the
+/// calling process.
+///
+/// **Notes**
+///
+/// - Cloning a handle only bumps a reference count, and the underlying mailbox
+///   is closed when the last handle is dropped.
+/// - Open mailboxes are private to a process, thus reference counts live in a
+///   static table that is indexed by open mailbox ID. The kernel hands back
+///   the same open mailbox to all lookups for an owner and tag in a process,
+///   thus handles that are opened separately share one reference count too.
+/// - Open mailboxes that are shared must not be closed by other means, and
+///   should not be opened again while their last handle is being dropped.
+///   Clone an existing handle instead.
+///
+/// **Example**
+///
+/// ```ignore
+/// let mailbox: SharedMailbox = SharedMailbox::open(owner, tag)?;
+/// let clone: SharedMailbox = mailbox.clone();
+/// thread::spawn(move || clone.write(b"hello"))?;
+/// ```
+///
+#[derive(Debug)]
+pub struct SharedMailbox {
+    /// ID of the underlying open mailbox.
+    ombxid: u32,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl SharedMailbox {
+    ///
+    /// **Description**
+    ///
+    /// Opens a mailbox to be shared.
+    ///
+    /// **Parameters**
+    /// - `owner` - Process that owns the mailbox.
+    /// - `tag` - Name of the mailbox.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a shared handle to the open mailbox is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    pub fn open(owner: Pid, tag: u32) -> Result<Self, KcallError> {
+        let ombxid: u32 =
+            kcall::decode(ipc::mailbox_open(owner.into(), tag) as u32)?;
+
+        // Check for open mailboxes that cannot be accounted for.
+        let refcount: &AtomicU32 = match REFCOUNTS.get(ombxid as usize) {
+            Some(refcount) => refcount,
+            None => {
+                let _ = ipc::mailbox_close(ombxid);
+                return Err(KcallError::InvalidArgument);
+            },
+        };
+        refcount.fetch_add(1, Ordering::Relaxed);
+
+        Ok(SharedMailbox { ombxid })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the ID of the underlying open mailbox.
+    ///
+    /// **Return**
+    ///
+    /// The ID of the underlying open mailbox.
+    ///
+    pub fn ombxid(&self) -> u32 {
+        self.ombxid
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of shared handles to the underlying open mailbox.
+    ///
+    /// **Return**
+    ///
+    /// The number of shared handles to the underlying open mailbox.
+    ///
+    pub fn strong_count(&self) -> u32 {
+        self.refcount().load(Ordering::Relaxed)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Writes a message to the underlying mailbox.
+    ///
+    /// **Parameters**
+    /// - `msg` - Message to write.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Messages must not be empty nor exceed [`MAILBOX_MESSAGE_SIZE`] bytes.
+    ///
+    pub fn write(&self, msg: &[u8]) -> Result<(), KcallError> {
+        if msg.is_empty() || msg.len() > MAILBOX_MESSAGE_SIZE as usize {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        ipc::mailbox_write_buf(self.ombxid, msg)?;
+
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Reads a message from the underlying mailbox, blocking the calling thread
+    /// until one arrives.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the message.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Buffers must not be empty nor exceed [`MAILBOX_MESSAGE_SIZE`] bytes.
+    ///
+    pub fn read(&self, buf: &mut [u8]) -> Result<(), KcallError> {
+        if buf.is_empty() || buf.len() > MAILBOX_MESSAGE_SIZE as usize {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        ipc::mailbox_read_buf(self.ombxid, buf)?;
+
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the reference count of the underlying open mailbox.
+    ///
+    /// **Return**
+    ///
+    /// The reference count of the underlying open mailbox.
+    ///
+    fn refcount(&self) -> &'static AtomicU32 {
+        // The open mailbox ID was checked when the mailbox was opened.
+        &REFCOUNTS[self.ombxid as usize]
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Clone for SharedMailbox {
+    fn clone(&self) -> Self {
+        self.refcount().fetch_add(1, Ordering::Relaxed);
+        SharedMailbox {
+            ombxid: self.ombxid,
+        }
+    }
+}
+
+impl Drop for SharedMailbox {
+    fn drop(&mut self) {
+        if self.refcount().fetch_sub(1, Ordering::Release) == 1 {
+            // Synchronize with uses of the mailbox through other handles.
+            atomic::fence(Ordering::Acquire);
+            let _ = ipc::mailbox_close(self.ombxid);
+        }
+    }
+}
@@ -0,0 +1,605 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallError,
+    memory::Mapping,
+    thread,
+};
+use core::{
+    cmp,
+    marker::PhantomData,
+    mem,
+    ptr,
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Size of the header of a ring (in bytes).
+pub const RING_HEADER_SIZE: usize = mem::size_of::<RingHeader>();
+
+/// Magic number that marks an initialized ring.
+const RING_MAGIC: u32 = 0x52494e47;
+
+/// Size of a cache line (in bytes).
+const CACHE_LINE_SIZE: usize = 64;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Header of a ring, which lives at the start of its region.
+///
+/// **Notes**
+///
+/// - The head and the tail are kept in separate cache lines, so that the
+///   producer and the consumer do not contend for the same line.
+///
+#[repr(C, align(64))]
+struct RingHeader {
+    /// Magic number, which is written last when the ring is initialized.
+    magic: AtomicU32,
+    /// Capacity of the data region (in bytes).
+    capacity: AtomicU32,
+    /// Padding up to the cache line of the head.
+    _pad0: [u8; CACHE_LINE_SIZE - 2 * mem::size_of::<AtomicU32>()],
+    /// Number of bytes that were popped so far. Written by the consumer only.
+    head: AtomicU32,
+    /// Padding up to the cache line of the tail.
+    _pad1: [u8; CACHE_LINE_SIZE - mem::size_of::<AtomicU32>()],
+    /// Number of bytes that were pushed so far. Written by the producer only.
+    tail: AtomicU32,
+}
+
+///
+/// **Description**
+///
+/// A lock-free, single-producer, single-consumer ring of bytes that lives in
+/// a region of memory, which may be shared by two processes.
+///
+/// **Notes**
+///
+/// - The region starts with a header that holds the head and the tail of the
+///   ring, and the rest of the region holds the data. The capacity of the ring
+///   is the largest power of two that fits in the data region.
+/// - The head and the tail count the bytes that were popped and pushed so far,
+///   and they wrap around on overflow. The producer only writes the tail, and
+///   the consumer only writes the head.
+/// - The producer stores the tail with [`Ordering::Release`] after it writes
+///   the data, and the consumer loads the tail with [`Ordering::Acquire`]
+///   before it reads the data. Thus, the consumer never reads bytes that are
+///   not written yet.
+/// - The consumer stores the head with [`Ordering::Release`] after it reads
+///   the data, and the producer loads the head with [`Ordering::Acquire`]
+///   before it writes the data. Thus, the producer never overwrites bytes that
+///   are not read yet.
+/// - Both processes access the same page frames, possibly at different
+///   virtual addresses. Atomic accesses and their ordering refer to memory
+///   locations, not to addresses, thus the guarantees above hold across
+///   processes.
+/// - Pushing and popping never issue kernel calls, except to yield the
+///   processor while a blocking operation waits for room or for data.
+/// - Each process builds its own view of the ring, either with
+///   [`Ring::create`] or with [`Ring::attach`], and it must take a single half
+///   of it. Using the same half in two processes at once is a bug.
+///
+/// **Example**
+///
+/// ```ignore
+/// let mut ring: Ring = Ring::create(&mut region)?;
+/// let (producer, consumer): (Producer, Consumer) = ring.split();
+/// producer.push(b"hello");
+/// consumer.pop(&mut buf[..5]);
+/// ```
+///
+#[derive(Debug)]
+pub struct Ring<'a> {
+    /// Header of the ring.
+    header: *const RingHeader,
+    /// First byte of the data region.
+    data: *mut u8,
+    /// Capacity of the ring (in bytes).
+    capacity: u32,
+    /// Underlying region.
+    region: PhantomData<&'a mut [u8]>,
+}
+
+///
+/// **Description**
+///
+/// Producing half of a ring.
+///
+#[derive(Debug)]
+pub struct Producer<'r> {
+    /// Underlying ring.
+    ring: &'r Ring<'r>,
+}
+
+///
+/// **Description**
+///
+/// Consuming half of a ring.
+///
+#[derive(Debug)]
+pub struct Consumer<'r> {
+    /// Underlying ring.
+    ring: &'r Ring<'r>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<'a> Ring<'a> {
+    ///
+    /// **Description**
+    ///
+    /// Initializes a ring in a region of memory.
+    ///
+    /// **Parameters**
+    /// - `region` - Region of memory for the ring.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a view of the empty ring is returned. If
+    /// `region` may not hold the header and at least one byte of data,
+    /// [`KcallError::InvalidArgument`] is returned instead.
+    ///
+    pub fn create(region: &'a mut [u8]) -> Result<Self, KcallError> {
+        let ring: Self = Self::layout(region)?;
+
+        let header: &RingHeader = ring.header();
+        header.head.store(0, Ordering::Relaxed);
+        header.tail.store(0, Ordering::Relaxed);
+        header.capacity.store(ring.capacity, Ordering::Relaxed);
+
+        // Publish the header, so that other views see it once they see the
+        // magic number.
+        header.magic.store(RING_MAGIC, Ordering::Release);
+
+        Ok(ring)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attaches to a ring that was initialized in a region of memory.
+    ///
+    /// **Parameters**
+    /// - `region` - Region of memory that holds the ring.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a view of the ring is returned. If
+    /// `region` does not hold a ring that was initialized with
+    /// [`Ring::create`] over a region of the same size,
+    /// [`KcallError::InvalidArgument`] is returned instead.
+    ///
+    pub fn attach(region: &'a mut [u8]) -> Result<Self, KcallError> {
+        let ring: Self = Self::layout(region)?;
+
+        let header: &RingHeader = ring.header();
+        if header.magic.load(Ordering::Acquire) != RING_MAGIC
+            || header.capacity.load(Ordering::Relaxed) != ring.capacity
+        {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        Ok(ring)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Initializes a ring in a mapping.
+    ///
+    /// **Parameters**
+    /// - `mapping` - Mapping for the ring.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a view of the empty ring is returned. Upon
+    /// failure, an error is returned instead, as in [`Ring::create`].
+    ///
+    /// # Safety
+    ///
+    /// The mapping must be in the virtual memory space of the calling process
+    /// and be writable.
+    ///
+    pub unsafe fn create_in(
+        mapping: &'a mut Mapping<'_>,
+    ) -> Result<Self, KcallError> {
+        Self::create(Self::region_of(mapping))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attaches to a ring that was initialized in a mapping.
+    ///
+    /// **Parameters**
+    /// - `mapping` - Mapping that holds the ring.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a view of the ring is returned. Upon
+    /// failure, an error is returned instead, as in [`Ring::attach`].
+    ///
+    /// # Safety
+    ///
+    /// The mapping must be in the virtual memory space of the calling process
+    /// and be writable.
+    ///
+    pub unsafe fn attach_in(
+        mapping: &'a mut Mapping<'_>,
+    ) -> Result<Self, KcallError> {
+        Self::attach(Self::region_of(mapping))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the capacity of the target ring.
+    ///
+    /// **Return**
+    ///
+    /// The number of bytes that the target ring may hold at once.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.capacity as usize
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of bytes that are pending in the target ring.
+    ///
+    /// **Return**
+    ///
+    /// The number of bytes that were pushed and not popped yet. The value may
+    /// be stale by the time it is returned.
+    ///
+    pub fn len(&self) -> usize {
+        let header: &RingHeader = self.header();
+        let head: u32 = header.head.load(Ordering::Acquire);
+        let tail: u32 = header.tail.load(Ordering::Acquire);
+        tail.wrapping_sub(head) as usize
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target ring is empty.
+    ///
+    /// **Return**
+    ///
+    /// If no bytes are pending in the target ring, `true` is returned.
+    /// Otherwise, `false` is returned instead.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Splits the target view of a ring into its halves.
+    ///
+    /// **Return**
+    ///
+    /// The producing and consuming halves of the ring.
+    ///
+    pub fn split<'r>(&'r mut self) -> (Producer<'r>, Consumer<'r>)
+    where
+        'a: 'r,
+    {
+        let ring: &'r Ring<'r> = self;
+        (Producer { ring }, Consumer { ring })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Lays out a ring in a region of memory, without initializing it.
+    ///
+    /// **Parameters**
+    /// - `region` - Region of memory for the ring.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a view of the ring is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    fn layout(region: &'a mut [u8]) -> Result<Self, KcallError> {
+        // Align the header, skipping the leading bytes of the region.
+        let offset: usize = region.as_ptr().align_offset(CACHE_LINE_SIZE);
+        let room: usize = region
+            .len()
+            .checked_sub(offset)
+            .and_then(|room| room.checked_sub(RING_HEADER_SIZE))
+            .filter(|room| *room > 0)
+            .ok_or(KcallError::InvalidArgument)?;
+
+        // Round the capacity down to a power of two, so that indices wrap
+        // around along with the counters. It is also kept below 2^31, so that
+        // the distance between the counters never overflows.
+        let room: u32 = cmp::min(room, 1 << 31) as u32;
+        let capacity: u32 = 1 << (31 - room.leading_zeros());
+
+        let base: *mut u8 = unsafe { region.as_mut_ptr().add(offset) };
+        Ok(Self {
+            header: base as *const RingHeader,
+            data: unsafe { base.add(RING_HEADER_SIZE) },
+            capacity,
+            region: PhantomData,
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the region of memory of a mapping.
+    ///
+    /// **Parameters**
+    /// - `mapping` - Target mapping.
+    ///
+    /// **Return**
+    ///
+    /// The region of memory of the mapping.
+    ///
+    /// **Safety**
+    ///
+    /// The mapping must be in the virtual memory space of the calling process.
+    ///
+    unsafe fn region_of<'m>(mapping: &'m mut Mapping<'_>) -> &'m mut [u8] {
+        core::slice::from_raw_parts_mut(mapping.as_mut_ptr(), mapping.len())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the header of the target ring.
+    ///
+    /// **Return**
+    ///
+    /// A reference to the header.
+    ///
+    fn header(&self) -> &RingHeader {
+        // The header is in the region, which outlives the view.
+        unsafe { &*self.header }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Copies bytes into the data region of the target ring.
+    ///
+    /// **Parameters**
+    /// - `index` - Counter of the first byte.
+    /// - `buf` - Bytes to copy.
+    ///
+    /// **Safety**
+    ///
+    /// The bytes must be free, and `buf` may not be longer than the capacity.
+    ///
+    unsafe fn write(&self, index: u32, buf: &[u8]) {
+        let start: usize = (index & (self.capacity - 1)) as usize;
+        let first: usize = cmp::min(buf.len(), self.capacity() - start);
+        ptr::copy_nonoverlapping(buf.as_ptr(), self.data.add(start), first);
+        ptr::copy_nonoverlapping(
+            buf.as_ptr().add(first),
+            self.data,
+            buf.len() - first,
+        );
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Copies bytes out of the data region of the target ring.
+    ///
+    /// **Parameters**
+    /// - `index` - Counter of the first byte.
+    /// - `buf` - Storage location for the bytes.
+    ///
+    /// **Safety**
+    ///
+    /// The bytes must be pending, and `buf` may not be longer than the
+    /// capacity.
+    ///
+    unsafe fn read(&self, index: u32, buf: &mut [u8]) {
+        let start: usize = (index & (self.capacity - 1)) as usize;
+        let first: usize = cmp::min(buf.len(), self.capacity() - start);
+        ptr::copy_nonoverlapping(self.data.add(start), buf.as_mut_ptr(), first);
+        ptr::copy_nonoverlapping(
+            self.data,
+            buf.as_mut_ptr().add(first),
+            buf.len() - first,
+        );
+    }
+}
+
+impl<'r> Producer<'r> {
+    ///
+    /// **Description**
+    ///
+    /// Pushes bytes into the ring, without blocking.
+    ///
+    /// **Parameters**
+    /// - `buf` - Bytes to push.
+    ///
+    /// **Return**
+    ///
+    /// The number of bytes that were pushed, which is zero if the ring is full
+    /// or if the consumer left the head out of bounds.
+    ///
+    pub fn try_push(&self, buf: &[u8]) -> usize {
+        let header: &RingHeader = self.ring.header();
+
+        // The tail is only written by this half.
+        let tail: u32 = header.tail.load(Ordering::Relaxed);
+        // Synchronize with the consumer, so that the bytes it popped are no
+        // longer read once they are overwritten.
+        let head: u32 = header.head.load(Ordering::Acquire);
+
+        // The head is written by the peer, thus it may not be trusted.
+        let used: u32 = tail.wrapping_sub(head);
+        if used > self.ring.capacity {
+            return 0;
+        }
+
+        let free: usize = self.ring.capacity() - used as usize;
+        let len: usize = cmp::min(free, buf.len());
+        if len == 0 {
+            return 0;
+        }
+
+        unsafe { self.ring.write(tail, &buf[..len]) };
+
+        // Publish the bytes, so that the consumer sees them once it sees the
+        // new tail.
+        header
+            .tail
+            .store(tail.wrapping_add(len as u32), Ordering::Release);
+
+        len
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Pushes bytes into the ring, blocking until all of them are pushed.
+    ///
+    /// **Parameters**
+    /// - `buf` - Bytes to push.
+    ///
+    /// **Notes**
+    ///
+    /// - While the ring is full, the calling thread yields the processor.
+    ///
+    pub fn push(&self, buf: &[u8]) {
+        let mut done: usize = 0;
+        while done < buf.len() {
+            match self.try_push(&buf[done..]) {
+                0 => thread::yield_now(),
+                len => done += len,
+            }
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the ring of the target half.
+    ///
+    /// **Return**
+    ///
+    /// The underlying ring.
+    ///
+    pub fn ring(&self) -> &Ring<'r> {
+        self.ring
+    }
+}
+
+impl<'r> Consumer<'r> {
+    ///
+    /// **Description**
+    ///
+    /// Pops bytes from the ring, without blocking.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the bytes.
+    ///
+    /// **Return**
+    ///
+    /// The number of bytes that were popped, which is zero if the ring is
+    /// empty or if the producer left the tail out of bounds.
+    ///
+    pub fn try_pop(&self, buf: &mut [u8]) -> usize {
+        let header: &RingHeader = self.ring.header();
+
+        // The head is only written by this half.
+        let head: u32 = header.head.load(Ordering::Relaxed);
+        // Synchronize with the producer, so that the bytes it pushed are seen.
+        let tail: u32 = header.tail.load(Ordering::Acquire);
+
+        // The tail is written by the peer, thus it may not be trusted.
+        let pending: u32 = tail.wrapping_sub(head);
+        if pending > self.ring.capacity {
+            return 0;
+        }
+
+        let len: usize = cmp::min(pending as usize, buf.len());
+        if len == 0 {
+            return 0;
+        }
+
+        unsafe { self.ring.read(head, &mut buf[..len]) };
+
+        // Release the bytes, so that the producer overwrites them only once
+        // they were read.
+        header
+            .head
+            .store(head.wrapping_add(len as u32), Ordering::Release);
+
+        len
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Pops bytes from the ring, blocking until the buffer is filled.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the bytes.
+    ///
+    /// **Notes**
+    ///
+    /// - While the ring is empty, the calling thread yields the processor.
+    ///
+    pub fn pop(&self, buf: &mut [u8]) {
+        let mut done: usize = 0;
+        while done < buf.len() {
+            match self.try_pop(&mut buf[done..]) {
+                0 => thread::yield_now(),
+                len => done += len,
+            }
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the ring of the target half.
+    ///
+    /// **Return**
+    ///
+    /// The underlying ring.
+    ///
+    pub fn ring(&self) -> &Ring<'r> {
+        self.ring
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+unsafe impl<'r> Send for Producer<'r> {}
+
+unsafe impl<'r> Send for Consumer<'r> {}
@@ -0,0 +1,272 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: the kernel does not run on AArch64 yet, thus these kernel calls are
+// stubs. Kernel calls are issued with `svc #0`, the kernel call number is passed
+// in `x8`, arguments are passed in `x0` through `x5`, and the return value is
+// read from `x0`.
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::arch;
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Issues a kernel call with no arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall0(kcall_nr: u32) -> u32 {
+    let ret: u64;
+    arch::asm!("svc #0",
+        in("x8") kcall_nr as u64,
+        lateout("x0") ret,
+        options(nostack, preserves_flags)
+    );
+    ret as u32
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with one argument.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall1(kcall_nr: u32, arg0: u32) -> u32 {
+    let ret: u64;
+    arch::asm!("svc #0",
+        in("x8") kcall_nr as u64,
+        inout("x0") arg0 as u64 => ret,
+        options(nostack, preserves_flags)
+    );
+    ret as u32
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with two arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall2(kcall_nr: u32, arg0: u32, arg1: u32) -> u32 {
+    let ret: u64;
+    arch::asm!("svc #0",
+        in("x8") kcall_nr as u64,
+        inout("x0") arg0 as u64 => ret,
+        in("x1") arg1 as u64,
+        options(nostack, preserves_flags)
+    );
+    ret as u32
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with three arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall3(kcall_nr: u32, arg0: u32, arg1: u32, arg2: u32) -> u32 {
+    let ret: u64;
+    arch::asm!("svc #0",
+        in("x8") kcall_nr as u64,
+        inout("x0") arg0 as u64 => ret,
+        in("x1") arg1 as u64,
+        in("x2") arg2 as u64,
+        options(nostack, preserves_flags)
+    );
+    ret as u32
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with four arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall4(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+) -> u32 {
+    let ret: u64;
+    arch::asm!("svc #0",
+        in("x8") kcall_nr as u64,
+        inout("x0") arg0 as u64 => ret,
+        in("x1") arg1 as u64,
+        in("x2") arg2 as u64,
+        in("x3") arg3 as u64,
+        options(nostack, preserves_flags)
+    );
+    ret as u32
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with five arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall5(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+) -> u32 {
+    let ret: u64;
+    arch::asm!("svc #0",
+        in("x8") kcall_nr as u64,
+        inout("x0") arg0 as u64 => ret,
+        in("x1") arg1 as u64,
+        in("x2") arg2 as u64,
+        in("x3") arg3 as u64,
+        in("x4") arg4 as u64,
+        options(nostack, preserves_flags)
+    );
+    ret as u32
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with six arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
+/// - `arg5` - Sixth argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall6(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+    arg5: u32,
+) -> u32 {
+    let ret: u64;
+    arch::asm!("svc #0",
+        in("x8") kcall_nr as u64,
+        inout("x0") arg0 as u64 => ret,
+        in("x1") arg1 as u64,
+        in("x2") arg2 as u64,
+        in("x3") arg3 as u64,
+        in("x4") arg4 as u64,
+        in("x5") arg5 as u64,
+        options(nostack, preserves_flags)
+    );
+    ret as u32
+}
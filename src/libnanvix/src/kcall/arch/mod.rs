@@ -0,0 +1,40 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: kernel call stubs are not inlined by default, so that they show up in
+// stack traces. The `inline-kcalls` feature lets the compiler fold them into
+// their callers instead. Their asm constraints hold once inlined: trapping
+// into the kernel switches to the kernel stack, thus stubs that do not save
+// registers are `nostack`; and the kernel restores flags when it returns, thus
+// all stubs are `preserves_flags`.
+
+//==============================================================================
+// Modules
+//==============================================================================
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+#[cfg(target_arch = "riscv64")]
+mod riscv64;
+#[cfg(target_arch = "x86")]
+mod x86;
+
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "aarch64",
+    target_arch = "riscv64"
+)))]
+compile_error!("kernel calls are not supported on the target architecture");
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+#[cfg(target_arch = "aarch64")]
+pub use self::aarch64::*;
+#[cfg(target_arch = "riscv64")]
+pub use self::riscv64::*;
+#[cfg(target_arch = "x86")]
+pub use self::x86::*;
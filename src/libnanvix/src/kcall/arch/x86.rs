@@ -0,0 +1,290 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::arch;
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Issues a kernel call with no arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall0(kcall_nr: u32) -> u32 {
+    let ret: u32;
+    arch::asm!("int 0x80",
+        inout("eax") kcall_nr => ret,
+        options(nostack, preserves_flags)
+    );
+    ret
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with one argument.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall1(kcall_nr: u32, arg0: u32) -> u32 {
+    let ret: u32;
+    arch::asm!("int 0x80",
+        inout("eax") kcall_nr => ret,
+        in("ebx") arg0,
+        options(nostack, preserves_flags)
+    );
+    ret
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with two arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall2(kcall_nr: u32, arg0: u32, arg1: u32) -> u32 {
+    let ret: u32;
+    arch::asm!("int 0x80",
+        inout("eax") kcall_nr => ret,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        options(nostack, preserves_flags)
+    );
+    ret
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with three arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall3(kcall_nr: u32, arg0: u32, arg1: u32, arg2: u32) -> u32 {
+    let ret: u32;
+    arch::asm!("int 0x80",
+        inout("eax") kcall_nr => ret,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        options(nostack, preserves_flags)
+    );
+    ret
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with four arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall4(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+) -> u32 {
+    let ret: u32;
+    arch::asm!("int 0x80",
+        inout("eax") kcall_nr => ret,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        in("edi") arg3,
+        options(nostack, preserves_flags)
+    );
+    ret
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with five arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Notes**
+///
+/// - The fifth argument is passed in `esi`. LLVM reserves this register, thus
+///   it cannot be used as an asm operand. We save it on the stack and load the
+///   fifth argument and the kernel call number from memory pointed to by `eax`.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall5(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+) -> u32 {
+    let ret: u32;
+    let args: [u32; 2] = [arg4, kcall_nr];
+    arch::asm!("push esi",
+        "mov esi, [eax]",
+        "mov eax, [eax + 4]",
+        "int 0x80",
+        "pop esi",
+        inout("eax") args.as_ptr() => ret,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        in("edi") arg3,
+        options(preserves_flags)
+    );
+    ret
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with six arguments.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
+/// - `arg5` - Sixth argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns the value returned by the kernel call.
+///
+/// **Notes**
+///
+/// - The fifth and sixth arguments are passed in `esi` and `ebp`,
+///   respectively. Neither register can be used as an asm operand, because
+///   LLVM reserves `esi` and `ebp` is the frame pointer. We save both on the
+///   stack and load the arguments and the kernel call number from memory
+///   pointed to by `eax`.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+///
+#[cfg_attr(feature = "inline-kcalls", inline)]
+#[cfg_attr(not(feature = "inline-kcalls"), inline(never))]
+pub unsafe fn kcall6(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+    arg5: u32,
+) -> u32 {
+    let ret: u32;
+    let args: [u32; 3] = [arg4, arg5, kcall_nr];
+    arch::asm!("push ebp",
+        "push esi",
+        "mov esi, [eax]",
+        "mov ebp, [eax + 4]",
+        "mov eax, [eax + 8]",
+        "int 0x80",
+        "pop esi",
+        "pop ebp",
+        inout("eax") args.as_ptr() => ret,
+        in("ebx") arg0,
+        in("ecx") arg1,
+        in("edx") arg2,
+        in("edi") arg3,
+        options(preserves_flags)
+    );
+    ret
+}
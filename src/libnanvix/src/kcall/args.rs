@@ -0,0 +1,95 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::kcall::KcallError;
+use core::convert::TryFrom;
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Packs a buffer into the pointer and length arguments of a kernel call.
+///
+/// **Parameters**
+/// - `buf` - Target buffer.
+///
+/// **Return**
+///
+/// Upon successful completion, the address and the length of `buf` are
+/// returned. Upon failure, an error is returned instead. If the length of
+/// `buf` does not fit in a kernel call argument,
+/// [`KcallError::InvalidArgument`] is returned, and if its address does not,
+/// [`KcallError::BadAddress`] is returned.
+///
+/// **Notes**
+///
+/// - An empty buffer is packed as a null pointer and a zero length, so that
+///   the kernel never sees the dangling address of an empty slice.
+///
+/// **Example**
+///
+/// ```ignore
+/// let (ptr, len): (u32, u32) = kcall::ptr_len(buf)?;
+/// kcall::kcall3_checked(KcallNumbers::Write as u32, fd, ptr, len)?;
+/// ```
+///
+pub fn ptr_len(buf: &[u8]) -> Result<(u32, u32), KcallError> {
+    pack(buf.as_ptr() as usize, buf.len())
+}
+
+///
+/// **Description**
+///
+/// Packs a mutable buffer into the pointer and length arguments of a kernel
+/// call.
+///
+/// **Parameters**
+/// - `buf` - Target buffer.
+///
+/// **Return**
+///
+/// Upon successful completion, the address and the length of `buf` are
+/// returned. Upon failure, an error is returned instead. See [`ptr_len`].
+///
+pub fn ptr_len_mut(buf: &mut [u8]) -> Result<(u32, u32), KcallError> {
+    pack(buf.as_mut_ptr() as usize, buf.len())
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Packs the address and the length of a buffer into kernel call arguments.
+///
+/// **Parameters**
+/// - `addr` - Address of the buffer.
+/// - `len` - Length of the buffer (in bytes).
+///
+/// **Return**
+///
+/// Upon successful completion, the packed address and length are returned.
+/// Upon failure, an error is returned instead.
+///
+fn pack(addr: usize, len: usize) -> Result<(u32, u32), KcallError> {
+    if len == 0 {
+        return Ok((0, 0));
+    }
+
+    let len: u32 =
+        u32::try_from(len).map_err(|_| KcallError::InvalidArgument)?;
+    let addr: u32 = u32::try_from(addr).map_err(|_| KcallError::BadAddress)?;
+
+    Ok((addr, len))
+}
@@ -0,0 +1,206 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use super::{
+    kcall6,
+    KcallError,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum number of kernel calls in a batch.
+pub const KCALL_BATCH_MAX: usize = 16;
+
+/// Maximum number of arguments of a kernel call in a batch.
+pub const KCALL_BATCH_ARGS_MAX: usize = 6;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Kernel call in a batch.
+///
+#[derive(Clone, Copy, Default)]
+struct KcallEntry {
+    /// Kernel call number.
+    kcall_nr: u32,
+    /// Arguments of the kernel call (unused ones are zero).
+    args: [u32; KCALL_BATCH_ARGS_MAX],
+}
+
+///
+/// **Description**
+///
+/// A batch of kernel calls that are submitted together (experimental).
+///
+/// **Notes**
+///
+/// - The kernel may not take several kernel calls at once. For this reason,
+///   kernel calls in a batch are issued one by one, in the order that they
+///   were appended, and submitting a batch costs as much as issuing its kernel
+///   calls. Batches only gather kernel calls and their results for now.
+/// - Results are raw return values, which should be decoded with
+///   [`crate::kcall::decode`].
+///
+/// **Example**
+///
+/// ```ignore
+/// let mut batch: KcallBatch = KcallBatch::new();
+/// batch.push(KcallNumbers::Void1 as u32, &[1])?;
+/// batch.push(KcallNumbers::Void2 as u32, &[1, 2])?;
+/// let results: &[u32] = batch.submit();
+/// ```
+///
+pub struct KcallBatch {
+    /// Kernel calls.
+    entries: [KcallEntry; KCALL_BATCH_MAX],
+    /// Results of kernel calls.
+    results: [u32; KCALL_BATCH_MAX],
+    /// Number of kernel calls.
+    len: usize,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl KcallBatch {
+    ///
+    /// **Description**
+    ///
+    /// Creates an empty batch of kernel calls.
+    ///
+    /// **Return**
+    ///
+    /// A batch that has no kernel calls.
+    ///
+    pub fn new() -> Self {
+        Self {
+            entries: [KcallEntry::default(); KCALL_BATCH_MAX],
+            results: [0; KCALL_BATCH_MAX],
+            len: 0,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends a kernel call to the target batch.
+    ///
+    /// **Parameters**
+    /// - `kcall_nr` - Kernel call number.
+    /// - `args` - Arguments of the kernel call.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    pub fn push(
+        &mut self,
+        kcall_nr: u32,
+        args: &[u32],
+    ) -> Result<(), KcallError> {
+        if self.len == KCALL_BATCH_MAX || args.len() > KCALL_BATCH_ARGS_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let entry: &mut KcallEntry = &mut self.entries[self.len];
+        entry.kcall_nr = kcall_nr;
+        entry.args = [0; KCALL_BATCH_ARGS_MAX];
+        entry.args[..args.len()].copy_from_slice(args);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of kernel calls in the target batch.
+    ///
+    /// **Return**
+    ///
+    /// The number of kernel calls in the target batch.
+    ///
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target batch has no kernel calls.
+    ///
+    /// **Return**
+    ///
+    /// If the target batch has no kernel calls, `true` is returned. Otherwise,
+    /// `false` is returned instead.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Removes all kernel calls from the target batch.
+    ///
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Issues all kernel calls in the target batch.
+    ///
+    /// **Return**
+    ///
+    /// The values returned by the kernel calls, in the order that they were
+    /// appended.
+    ///
+    pub fn submit(&mut self) -> &[u32] {
+        let len: usize = self.len;
+
+        for (entry, result) in
+            self.entries[..len].iter().zip(self.results.iter_mut())
+        {
+            let args: &[u32; KCALL_BATCH_ARGS_MAX] = &entry.args;
+            *result = unsafe {
+                kcall6(
+                    entry.kcall_nr,
+                    args[0],
+                    args[1],
+                    args[2],
+                    args[3],
+                    args[4],
+                    args[5],
+                )
+            };
+        }
+
+        &self.results[..len]
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Default for KcallBatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::kcall::{
+    KcallError,
+    KcallNumbers,
+};
+use core::fmt;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// An error reported by a kernel call, along with the kernel call that
+/// reported it.
+///
+/// **Notes**
+///
+/// - Converting into a [`KcallError`] drops the kernel call, thus `?` keeps
+///   working in functions that return a plain [`KcallError`].
+///
+/// **Example**
+///
+/// ```ignore
+/// if let Err(e) = vmem.map_with_context(vaddr, &frame, flags) {
+///     // Prints "VmemMap failed: NoMemory".
+///     nanvix::log!("{}", e);
+/// }
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KcallContextError {
+    /// Kernel call that failed.
+    kcall: KcallNumbers,
+    /// Error that the kernel call reported.
+    error: KcallError,
+}
+
+//==============================================================================
+// Traits
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Attaches the kernel call that failed to an error.
+///
+pub trait KcallContext<T> {
+    ///
+    /// **Description**
+    ///
+    /// Attaches a kernel call to the error of the target result, if any.
+    ///
+    /// **Parameters**
+    /// - `kcall` - Kernel call that produced the target result.
+    ///
+    /// **Return**
+    ///
+    /// The target result, with `kcall` attached to its error.
+    ///
+    fn context(self, kcall: KcallNumbers) -> Result<T, KcallContextError>;
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl KcallContextError {
+    ///
+    /// **Description**
+    ///
+    /// Creates a kernel call error with context.
+    ///
+    /// **Parameters**
+    /// - `kcall` - Kernel call that failed.
+    /// - `error` - Error that the kernel call reported.
+    ///
+    /// **Return**
+    ///
+    /// The kernel call error with context.
+    ///
+    pub const fn new(kcall: KcallNumbers, error: KcallError) -> Self {
+        Self { kcall, error }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the kernel call that failed.
+    ///
+    /// **Return**
+    ///
+    /// The kernel call that failed.
+    ///
+    pub const fn kcall(&self) -> KcallNumbers {
+        self.kcall
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the error that the kernel call reported.
+    ///
+    /// **Return**
+    ///
+    /// The raw error.
+    ///
+    pub const fn source(&self) -> KcallError {
+        self.error
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl<T> KcallContext<T> for Result<T, KcallError> {
+    fn context(self, kcall: KcallNumbers) -> Result<T, KcallContextError> {
+        self.map_err(|error| KcallContextError::new(kcall, error))
+    }
+}
+
+impl From<KcallContextError> for KcallError {
+    fn from(error: KcallContextError) -> Self {
+        error.error
+    }
+}
+
+impl fmt::Display for KcallContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed: {:?}", self.kcall.name(), self.error)
+    }
+}
@@ -0,0 +1,243 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Permission denied.
+const EACCES: i32 = 2;
+
+/// Address already in use.
+const EADDRINUSE: i32 = 3;
+
+/// Resource temporarily unavailable.
+const EAGAIN: i32 = 6;
+
+/// Device or resource busy.
+const EBUSY: i32 = 10;
+
+/// Entry already exists.
+const EEXIST: i32 = 20;
+
+/// Bad address.
+const EFAULT: i32 = 21;
+
+/// Interrupted system call.
+const EINTR: i32 = 27;
+
+/// Invalid argument.
+const EINVAL: i32 = 28;
+
+/// I/O error.
+const EIO: i32 = 29;
+
+/// No such file or directory.
+const ENOENT: i32 = 44;
+
+/// Not enough space.
+const ENOMEM: i32 = 48;
+
+/// Not supported.
+const ENOTSUP: i32 = 58;
+
+/// Not owner.
+const EPERM: i32 = 64;
+
+///
+/// **Description**
+///
+/// Mapping between kernel call errors and error codes (positive).
+///
+/// **Notes**
+///
+/// - Errors that are not in this table are reported as
+///   [`KcallError::Unknown`].
+/// - [`KcallError::WriteZero`] is not in this table, because it is only
+///   reported by this library.
+///
+pub const ERRNO_TABLE: [(KcallError, i32); 13] = [
+    (KcallError::AccessDenied, EACCES),
+    (KcallError::AddressInUse, EADDRINUSE),
+    (KcallError::Again, EAGAIN),
+    (KcallError::AlreadyExists, EEXIST),
+    (KcallError::BadAddress, EFAULT),
+    (KcallError::Busy, EBUSY),
+    (KcallError::Interrupted, EINTR),
+    (KcallError::InvalidArgument, EINVAL),
+    (KcallError::Io, EIO),
+    (KcallError::NoMemory, ENOMEM),
+    (KcallError::NotFound, ENOENT),
+    (KcallError::NotSupported, ENOTSUP),
+    (KcallError::PermissionDenied, EPERM),
+];
+
+//==============================================================================
+// Enumerations
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Errors reported by kernel calls.
+///
+/// **Notes**
+///
+/// - Error codes should match the ones in `include/nanvix/errno.h`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KcallError {
+    /// Permission denied (`EACCES`).
+    AccessDenied,
+    /// Address already in use (`EADDRINUSE`).
+    AddressInUse,
+    /// Resource temporarily unavailable (`EAGAIN`).
+    Again,
+    /// Entry already exists (`EEXIST`).
+    AlreadyExists,
+    /// Bad address (`EFAULT`).
+    BadAddress,
+    /// Device or resource busy (`EBUSY`).
+    Busy,
+    /// Interrupted system call (`EINTR`).
+    Interrupted,
+    /// Invalid argument (`EINVAL`).
+    InvalidArgument,
+    /// I/O error (`EIO`).
+    Io,
+    /// Not enough memory (`ENOMEM`).
+    NoMemory,
+    /// No such entry (`ENOENT`).
+    NotFound,
+    /// Not supported (`ENOTSUP`).
+    NotSupported,
+    /// Operation not permitted (`EPERM`).
+    PermissionDenied,
+    /// A write accepted no bytes. Only reported by this library, and
+    /// converted into `EIO`.
+    WriteZero,
+    /// Unknown error code.
+    Unknown(i32),
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl KcallError {
+    ///
+    /// **Description**
+    ///
+    /// Converts an error code into a kernel call error.
+    ///
+    /// **Parameters**
+    /// - `errno` - Error code (positive).
+    ///
+    /// **Return**
+    ///
+    /// The kernel call error that corresponds to `errno` is returned. If
+    /// `errno` is not a known error code, [`KcallError::Unknown`] is returned
+    /// instead.
+    ///
+    pub fn from_errno(errno: i32) -> Self {
+        for (error, code) in ERRNO_TABLE.iter() {
+            if *code == errno {
+                return *error;
+            }
+        }
+
+        KcallError::Unknown(errno)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Converts the target kernel call error into an error code.
+    ///
+    /// **Return**
+    ///
+    /// The error code (positive) that corresponds to the target kernel call
+    /// error is returned.
+    ///
+    pub fn as_errno(&self) -> i32 {
+        match self {
+            KcallError::Unknown(errno) => return *errno,
+            KcallError::WriteZero => return EIO,
+            _ => {},
+        }
+
+        for (error, code) in ERRNO_TABLE.iter() {
+            if error == self {
+                return *code;
+            }
+        }
+
+        unreachable!("kernel call error missing from errno table")
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Decodes the value returned by a kernel call.
+///
+/// **Parameters**
+/// - `ret` - Value returned by the kernel call.
+///
+/// **Return**
+///
+/// If `ret`, reinterpreted as a signed integer, is non-negative, it is returned
+/// unchanged. Otherwise, it is treated as a negated error code and the
+/// corresponding error is returned instead.
+///
+pub fn decode(ret: u32) -> Result<u32, KcallError> {
+    let ret_signed: i32 = ret as i32;
+    if ret_signed < 0 {
+        Err(KcallError::from_errno(ret_signed.wrapping_neg()))
+    } else {
+        Ok(ret)
+    }
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call again for as long as it is interrupted.
+///
+/// **Parameters**
+/// - `f` - Closure that issues the kernel call.
+///
+/// **Return**
+///
+/// The first result of `f` that is not [`KcallError::Interrupted`] is
+/// returned.
+///
+/// **Notes**
+///
+/// - A kernel call that blocks reports `-EINTR` if it returns before it has
+///   completed, and it has no effect in that case. Only this error is retried:
+///   any other one is returned unchanged.
+///
+/// **Example**
+///
+/// ```ignore
+/// let info: ExceptionInfo = kcall::retry_interrupted(excp::wait)?;
+/// ```
+///
+pub fn retry_interrupted<T, F>(mut f: F) -> Result<T, KcallError>
+where
+    F: FnMut() -> Result<T, KcallError>,
+{
+    loop {
+        match f() {
+            Err(KcallError::Interrupted) => continue,
+            result => return result,
+        }
+    }
+}
@@ -0,0 +1,319 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallNumbers,
+    sync::{
+        SpinMutex,
+        SpinMutexGuard,
+    },
+};
+use core::{
+    hint,
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Number of kernel calls that are kept in the log.
+pub const KCALL_LOG_MAX: usize = 16;
+
+/// Maximum number of arguments of a kernel call in the log.
+const KCALL_LOG_ARGS_MAX: usize = 6;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A kernel call that was issued by the calling process.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KcallRecord {
+    /// Kernel call number.
+    kcall_nr: u32,
+    /// Arguments of the kernel call (unused ones are zero).
+    args: [u32; KCALL_LOG_ARGS_MAX],
+    /// Number of arguments of the kernel call.
+    nargs: usize,
+    /// Value returned by the kernel call.
+    ret: u32,
+}
+
+///
+/// **Description**
+///
+/// Ring of the last kernel calls that were issued.
+///
+struct KcallLog {
+    /// Kernel calls.
+    records: [KcallRecord; KCALL_LOG_MAX],
+    /// Index of the slot where the next kernel call is stored.
+    next: usize,
+    /// Number of kernel calls in the ring.
+    len: usize,
+}
+
+///
+/// **Description**
+///
+/// An iterator over a snapshot of the kernel call log, from the oldest kernel
+/// call to the newest one.
+///
+#[derive(Debug)]
+pub struct LastCalls {
+    /// Kernel calls, in the order that they were stored.
+    records: [KcallRecord; KCALL_LOG_MAX],
+    /// Index of the next kernel call to yield.
+    index: usize,
+    /// Number of kernel calls that remain to be yielded.
+    remaining: usize,
+}
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Log of the last kernel calls issued by the calling process.
+static LOG: SpinMutex<KcallLog> = SpinMutex::new(0, KcallLog::new());
+
+/// Number of kernel calls that were not logged.
+static DROPPED: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl KcallRecord {
+    /// A record that describes no kernel call.
+    const EMPTY: KcallRecord = KcallRecord {
+        kcall_nr: 0,
+        args: [0; KCALL_LOG_ARGS_MAX],
+        nargs: 0,
+        ret: 0,
+    };
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw number of the kernel call.
+    ///
+    /// **Return**
+    ///
+    /// The raw number of the kernel call.
+    ///
+    pub fn kcall_nr(&self) -> u32 {
+        self.kcall_nr
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of the kernel call.
+    ///
+    /// **Return**
+    ///
+    /// If the raw number of the kernel call is known, the kernel call number
+    /// is returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn kcall(&self) -> Option<KcallNumbers> {
+        KcallNumbers::from_u32(self.kcall_nr)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the arguments of the kernel call.
+    ///
+    /// **Return**
+    ///
+    /// The arguments that were passed to the kernel call.
+    ///
+    pub fn args(&self) -> &[u32] {
+        &self.args[..self.nargs]
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value returned by the kernel call.
+    ///
+    /// **Return**
+    ///
+    /// The raw value returned by the kernel call, which should be decoded with
+    /// [`crate::kcall::decode`].
+    ///
+    pub fn ret(&self) -> u32 {
+        self.ret
+    }
+}
+
+impl KcallLog {
+    ///
+    /// **Description**
+    ///
+    /// Creates an empty kernel call log.
+    ///
+    /// **Return**
+    ///
+    /// A kernel call log that holds no kernel calls.
+    ///
+    const fn new() -> Self {
+        Self {
+            records: [KcallRecord::EMPTY; KCALL_LOG_MAX],
+            next: 0,
+            len: 0,
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Iterator for LastCalls {
+    type Item = KcallRecord;
+
+    fn next(&mut self) -> Option<KcallRecord> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let record: KcallRecord = self.records[self.index];
+        self.index = (self.index + 1) % KCALL_LOG_MAX;
+        self.remaining -= 1;
+
+        Some(record)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for LastCalls {}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Takes a snapshot of the last kernel calls issued by the calling process.
+///
+/// **Return**
+///
+/// An iterator over the last kernel calls, at most [`KCALL_LOG_MAX`], from the
+/// oldest one to the newest one.
+///
+/// **Notes**
+///
+/// - The log is a ring that overwrites its oldest kernel calls. It is shared
+///   by all threads of the calling process.
+/// - Kernel calls that are issued while another thread updates or reads the
+///   log are not logged. See [`dropped_calls`].
+///
+pub fn last_calls() -> impl Iterator<Item = KcallRecord> {
+    let log: SpinMutexGuard<KcallLog> = lock();
+    let first: usize = (log.next + KCALL_LOG_MAX - log.len) % KCALL_LOG_MAX;
+
+    LastCalls {
+        records: log.records,
+        index: first,
+        remaining: log.len,
+    }
+}
+
+///
+/// **Description**
+///
+/// Gets the number of kernel calls that were not logged.
+///
+/// **Return**
+///
+/// The number of kernel calls that were issued while the log was busy.
+///
+pub fn dropped_calls() -> u32 {
+    DROPPED.load(Ordering::Relaxed)
+}
+
+///
+/// **Description**
+///
+/// Stores a kernel call in the log.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `args` - Arguments of the kernel call.
+/// - `ret` - Value returned by the kernel call.
+///
+/// **Notes**
+///
+/// - This function never waits and issues no kernel calls, thus it is safe to
+///   call it from kernel call stubs. If the log is busy, the kernel call is
+///   dropped instead.
+///
+pub(crate) fn record(kcall_nr: u32, args: &[u32], ret: u32) {
+    let mut log: SpinMutexGuard<KcallLog> = match LOG.try_lock() {
+        Some(log) => log,
+        None => {
+            DROPPED.fetch_add(1, Ordering::Relaxed);
+            return;
+        },
+    };
+
+    let nargs: usize = args.len().min(KCALL_LOG_ARGS_MAX);
+    let mut record: KcallRecord = KcallRecord {
+        kcall_nr,
+        nargs,
+        ret,
+        ..KcallRecord::EMPTY
+    };
+    record.args[..nargs].copy_from_slice(&args[..nargs]);
+
+    let next: usize = log.next;
+    log.records[next] = record;
+    log.next = (next + 1) % KCALL_LOG_MAX;
+    log.len = (log.len + 1).min(KCALL_LOG_MAX);
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Acquires the kernel call log.
+///
+/// **Return**
+///
+/// A guard to the kernel call log is returned.
+///
+/// **Notes**
+///
+/// - The calling thread spins rather than yields the processor, because
+///   yielding issues a kernel call, which would be logged.
+///
+fn lock() -> SpinMutexGuard<'static, KcallLog> {
+    loop {
+        if let Some(log) = LOG.try_lock() {
+            return log;
+        }
+        hint::spin_loop();
+    }
+}
@@ -7,24 +7,66 @@
 // Modules
 //==============================================================================
 
+mod arch;
+mod args;
+mod batch;
+mod context;
+mod error;
+#[cfg(feature = "kcall-log")]
+mod log;
+mod ret;
+#[cfg(feature = "trace")]
+mod trace;
 mod void;
+#[cfg(any(feature = "trace", feature = "kcall-log"))]
+mod wrap;
 
 //==============================================================================
 // Exports
 //==============================================================================
 
-pub use self::void::*;
+pub use self::{
+    args::*,
+    batch::*,
+    context::*,
+    error::*,
+    ret::*,
+    void::*,
+};
+
+#[cfg(not(any(feature = "trace", feature = "kcall-log")))]
+pub use self::arch::*;
+#[cfg(feature = "kcall-log")]
+pub use self::log::*;
+#[cfg(feature = "trace")]
+pub use self::trace::*;
+#[cfg(any(feature = "trace", feature = "kcall-log"))]
+pub use self::wrap::*;
 
 //==============================================================================
 // Imports
 //==============================================================================
 
-use core::arch;
+use core::convert::TryFrom;
 
 //==============================================================================
 // Enumerations
 //==============================================================================
 
+///
+/// **Description**
+///
+/// Kernel call numbers.
+///
+/// **Notes**
+///
+/// - Numbers match the ones in `include/nanvix/kernel/kcall.h`. Number 5 was
+///   once missing here, and it is [`KcallNumbers::Void5`], as in the kernel.
+/// - Numbers are dense, and [`KcallNumbers::ALL`] lists them in order. This is
+///   checked at compile time, thus a duplicate or skipped number fails to
+///   build.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(C)]
 pub enum KcallNumbers {
     Void0 = 0,
@@ -32,6 +74,7 @@ pub enum KcallNumbers {
     Void2 = 2,
     Void3 = 3,
     Void4 = 4,
+    Void5 = 5,
     Shutdown = 6,
     Write = 7,
     FrameAlloc = 8,
@@ -70,63 +113,453 @@ pub enum KcallNumbers {
     MailboxUnlink = 41,
     MailboxClose = 42,
     MailboxWrite = 43,
-    Mailboxread = 44,
+    MailboxRead = 44,
 }
 
 //==============================================================================
-// Private Standalone Functions
+// Compile-Time Checks
 //==============================================================================
 
+// Every kernel call number should be listed once, at the index of its number.
+const _: () = {
+    let mut i: usize = 0;
+    while i < KcallNumbers::ALL.len() {
+        assert!(
+            KcallNumbers::ALL[i] as usize == i,
+            "kernel call numbers are not dense and in order"
+        );
+        i += 1;
+    }
+};
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl KcallNumbers {
+    /// All kernel call numbers, in ascending order.
+    pub const ALL: [KcallNumbers; 45] = [
+        KcallNumbers::Void0,
+        KcallNumbers::Void1,
+        KcallNumbers::Void2,
+        KcallNumbers::Void3,
+        KcallNumbers::Void4,
+        KcallNumbers::Void5,
+        KcallNumbers::Shutdown,
+        KcallNumbers::Write,
+        KcallNumbers::FrameAlloc,
+        KcallNumbers::FrameFree,
+        KcallNumbers::VmemCreate,
+        KcallNumbers::VmemRemove,
+        KcallNumbers::VmemMap,
+        KcallNumbers::VmemUnmap,
+        KcallNumbers::VmemControl,
+        KcallNumbers::VmemInfo,
+        KcallNumbers::KmodGet,
+        KcallNumbers::Spawn,
+        KcallNumbers::Semget,
+        KcallNumbers::Semop,
+        KcallNumbers::Semctl,
+        KcallNumbers::ThreadGet,
+        KcallNumbers::ThreadCreate,
+        KcallNumbers::ThreadExit,
+        KcallNumbers::ThreadYield,
+        KcallNumbers::ThreadJoin,
+        KcallNumbers::ThreadDetach,
+        KcallNumbers::ProcessInfo,
+        KcallNumbers::GetUserID,
+        KcallNumbers::GetEffectiveUserID,
+        KcallNumbers::GetUserGroupID,
+        KcallNumbers::GetEffectiveUserGroupID,
+        KcallNumbers::SetUserID,
+        KcallNumbers::SetEffectiveUserID,
+        KcallNumbers::SetUserGroupID,
+        KcallNumbers::SetEffectiveUserGroupID,
+        KcallNumbers::ExcpCtrl,
+        KcallNumbers::ExcpWait,
+        KcallNumbers::ExcpResume,
+        KcallNumbers::MailboxCreate,
+        KcallNumbers::MailboxOpen,
+        KcallNumbers::MailboxUnlink,
+        KcallNumbers::MailboxClose,
+        KcallNumbers::MailboxWrite,
+        KcallNumbers::MailboxRead,
+    ];
+    /// Former name of [`KcallNumbers::MailboxRead`].
+    #[deprecated(note = "use `KcallNumbers::MailboxRead` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Mailboxread: KcallNumbers = KcallNumbers::MailboxRead;
+
+    ///
+    /// **Description**
+    ///
+    /// Converts a raw number into a kernel call number.
+    ///
+    /// **Parameters**
+    /// - `n` - Raw number.
+    ///
+    /// **Return**
+    ///
+    /// If `n` is a kernel call number, it is returned. Otherwise, `None` is
+    /// returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Number 5 is [`KcallNumbers::Void5`], as in the kernel, thus it is not
+    ///   an unused number. Numbers past the last kernel call are.
+    /// - [`KcallNumbers::try_from`] converts raw numbers as well, but it fails
+    ///   with [`KcallError::InvalidArgument`] instead.
+    ///
+    pub fn from_u32(n: u32) -> Option<KcallNumbers> {
+        KcallNumbers::ALL
+            .iter()
+            .find(|kcall_nr| **kcall_nr as u32 == n)
+            .copied()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the name of the target kernel call number.
+    ///
+    /// **Return**
+    ///
+    /// The name of the target kernel call number is returned.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            KcallNumbers::Void0 => "Void0",
+            KcallNumbers::Void1 => "Void1",
+            KcallNumbers::Void2 => "Void2",
+            KcallNumbers::Void3 => "Void3",
+            KcallNumbers::Void4 => "Void4",
+            KcallNumbers::Void5 => "Void5",
+            KcallNumbers::Shutdown => "Shutdown",
+            KcallNumbers::Write => "Write",
+            KcallNumbers::FrameAlloc => "FrameAlloc",
+            KcallNumbers::FrameFree => "FrameFree",
+            KcallNumbers::VmemCreate => "VmemCreate",
+            KcallNumbers::VmemRemove => "VmemRemove",
+            KcallNumbers::VmemMap => "VmemMap",
+            KcallNumbers::VmemUnmap => "VmemUnmap",
+            KcallNumbers::VmemControl => "VmemControl",
+            KcallNumbers::VmemInfo => "VmemInfo",
+            KcallNumbers::KmodGet => "KmodGet",
+            KcallNumbers::Spawn => "Spawn",
+            KcallNumbers::Semget => "Semget",
+            KcallNumbers::Semop => "Semop",
+            KcallNumbers::Semctl => "Semctl",
+            KcallNumbers::ThreadGet => "ThreadGet",
+            KcallNumbers::ThreadCreate => "ThreadCreate",
+            KcallNumbers::ThreadExit => "ThreadExit",
+            KcallNumbers::ThreadYield => "ThreadYield",
+            KcallNumbers::ThreadJoin => "ThreadJoin",
+            KcallNumbers::ThreadDetach => "ThreadDetach",
+            KcallNumbers::ProcessInfo => "ProcessInfo",
+            KcallNumbers::GetUserID => "GetUserID",
+            KcallNumbers::GetEffectiveUserID => "GetEffectiveUserID",
+            KcallNumbers::GetUserGroupID => "GetUserGroupID",
+            KcallNumbers::GetEffectiveUserGroupID => "GetEffectiveUserGroupID",
+            KcallNumbers::SetUserID => "SetUserID",
+            KcallNumbers::SetEffectiveUserID => "SetEffectiveUserID",
+            KcallNumbers::SetUserGroupID => "SetUserGroupID",
+            KcallNumbers::SetEffectiveUserGroupID => "SetEffectiveUserGroupID",
+            KcallNumbers::ExcpCtrl => "ExcpCtrl",
+            KcallNumbers::ExcpWait => "ExcpWait",
+            KcallNumbers::ExcpResume => "ExcpResume",
+            KcallNumbers::MailboxCreate => "MailboxCreate",
+            KcallNumbers::MailboxOpen => "MailboxOpen",
+            KcallNumbers::MailboxUnlink => "MailboxUnlink",
+            KcallNumbers::MailboxClose => "MailboxClose",
+            KcallNumbers::MailboxWrite => "MailboxWrite",
+            KcallNumbers::MailboxRead => "MailboxRead",
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl From<KcallNumbers> for u32 {
+    fn from(kcall_nr: KcallNumbers) -> u32 {
+        kcall_nr as u32
+    }
+}
+
+impl TryFrom<u32> for KcallNumbers {
+    type Error = KcallError;
+
+    fn try_from(n: u32) -> Result<Self, Self::Error> {
+        // Unused numbers are the ones past the last kernel call, since 5 is
+        // Void5, as in the kernel.
+        KcallNumbers::from_u32(n).ok_or(KcallError::InvalidArgument)
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Issues a kernel call with no arguments and decodes its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+///
+/// **Return**
+///
+/// Upon successful completion, the value returned by the kernel call is
+/// returned. Upon failure, the error reported by the kernel is returned
+/// instead.
+///
+/// **Safety**
+///
+/// - The caller must ensure that the kernel call is valid for its arguments.
+///   Arguments that the kernel takes as addresses must refer to memory of the
+///   calling process that stays valid while the kernel call runs, and the
+///   kernel call must not break state that this library manages, such as
+///   mappings, thread stacks and handles to kernel objects.
+///
+pub unsafe fn kcall0_checked(kcall_nr: u32) -> Result<u32, KcallError> {
+    decode(kcall0(kcall_nr))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with one argument and decodes its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+///
+/// **Return**
+///
+/// Upon successful completion, the value returned by the kernel call is
+/// returned. Upon failure, the error reported by the kernel is returned
+/// instead.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall1_checked(
+    kcall_nr: u32,
+    arg0: u32,
+) -> Result<u32, KcallError> {
+    decode(kcall1(kcall_nr, arg0))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with two arguments and decodes its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+///
+/// **Return**
+///
+/// Upon successful completion, the value returned by the kernel call is
+/// returned. Upon failure, the error reported by the kernel is returned
+/// instead.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall2_checked(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+) -> Result<u32, KcallError> {
+    decode(kcall2(kcall_nr, arg0, arg1))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with three arguments and decodes its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+///
+/// **Return**
+///
+/// Upon successful completion, the value returned by the kernel call is
+/// returned. Upon failure, the error reported by the kernel is returned
+/// instead.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall3_checked(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+) -> Result<u32, KcallError> {
+    decode(kcall3(kcall_nr, arg0, arg1, arg2))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with four arguments and decodes its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+///
+/// **Return**
+///
+/// Upon successful completion, the value returned by the kernel call is
+/// returned. Upon failure, the error reported by the kernel is returned
+/// instead.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall4_checked(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+) -> Result<u32, KcallError> {
+    decode(kcall4(kcall_nr, arg0, arg1, arg2, arg3))
+}
+
 ///
 /// **Description**
 ///
-/// Issues a kernel call with no arguments.
+/// Issues a kernel call with five arguments and decodes its return value.
 ///
 /// **Parameters**
 /// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
 ///
 /// **Return**
 ///
-/// This function returns the value returned by the kernel call.
+/// Upon successful completion, the value returned by the kernel call is
+/// returned. Upon failure, the error reported by the kernel is returned
+/// instead.
+///
+/// **Safety**
 ///
-#[inline(never)]
-pub unsafe fn kcall0(kcall_nr: u32) -> u32 {
-    let ret: u32;
-    arch::asm!("int 0x80",
-        inout("eax") kcall_nr => ret,
-        options(nostack, preserves_flags)
-    );
-    ret
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall5_checked(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+) -> Result<u32, KcallError> {
+    decode(kcall5(kcall_nr, arg0, arg1, arg2, arg3, arg4))
 }
 
 ///
 /// **Description**
 ///
-/// Issues a kernel call with one argument.
+/// Issues a kernel call with six arguments and decodes its return value.
 ///
 /// **Parameters**
 /// - `kcall_nr` - Kernel call number.
 /// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
+/// - `arg5` - Sixth argument for the kernel call.
 ///
 /// **Return**
 ///
-/// This function returns the value returned by the kernel call.
+/// Upon successful completion, the value returned by the kernel call is
+/// returned. Upon failure, the error reported by the kernel is returned
+/// instead.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall6_checked(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+    arg5: u32,
+) -> Result<u32, KcallError> {
+    decode(kcall6(kcall_nr, arg0, arg1, arg2, arg3, arg4, arg5))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with no arguments and wraps its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+///
+/// **Return**
+///
+/// The value returned by the kernel call is returned, wrapped so that it can
+/// be interpreted either as signed or unsigned.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall0_raw(kcall_nr: u32) -> KcallRet {
+    KcallRet::from(kcall0(kcall_nr))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with one argument and wraps its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+///
+/// **Return**
+///
+/// The value returned by the kernel call is returned, wrapped so that it can
+/// be interpreted either as signed or unsigned.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
 ///
-#[inline(never)]
-pub unsafe fn kcall1(kcall_nr: u32, arg0: u32) -> u32 {
-    let ret: u32;
-    arch::asm!("int 0x80",
-        inout("eax") kcall_nr => ret,
-        in("ebx") arg0,
-        options(nostack, preserves_flags)
-    );
-    ret
+pub unsafe fn kcall1_raw(kcall_nr: u32, arg0: u32) -> KcallRet {
+    KcallRet::from(kcall1(kcall_nr, arg0))
 }
 
 ///
 /// **Description**
 ///
-/// Issues a kernel call with two arguments.
+/// Issues a kernel call with two arguments and wraps its return value.
 ///
 /// **Parameters**
 /// - `kcall_nr` - Kernel call number.
@@ -135,52 +568,114 @@ pub unsafe fn kcall1(kcall_nr: u32, arg0: u32) -> u32 {
 ///
 /// **Return**
 ///
-/// This function returns the value returned by the kernel call.
+/// The value returned by the kernel call is returned, wrapped so that it can
+/// be interpreted either as signed or unsigned.
 ///
-#[inline(never)]
-pub unsafe fn kcall2(kcall_nr: u32, arg0: u32, arg1: u32) -> u32 {
-    let ret: u32;
-    arch::asm!("int 0x80",
-        inout("eax") kcall_nr => ret,
-        in("ebx") arg0,
-        in("ecx") arg1,
-        options(nostack, preserves_flags)
-    );
-    ret
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall2_raw(kcall_nr: u32, arg0: u32, arg1: u32) -> KcallRet {
+    KcallRet::from(kcall2(kcall_nr, arg0, arg1))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with three arguments and wraps its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+///
+/// **Return**
+///
+/// The value returned by the kernel call is returned, wrapped so that it can
+/// be interpreted either as signed or unsigned.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall3_raw(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+) -> KcallRet {
+    KcallRet::from(kcall3(kcall_nr, arg0, arg1, arg2))
+}
+
+///
+/// **Description**
+///
+/// Issues a kernel call with four arguments and wraps its return value.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+///
+/// **Return**
+///
+/// The value returned by the kernel call is returned, wrapped so that it can
+/// be interpreted either as signed or unsigned.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
+///
+pub unsafe fn kcall4_raw(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+) -> KcallRet {
+    KcallRet::from(kcall4(kcall_nr, arg0, arg1, arg2, arg3))
 }
 
 ///
 /// **Description**
 ///
-/// Issues a kernel call with three arguments.
+/// Issues a kernel call with five arguments and wraps its return value.
 ///
 /// **Parameters**
 /// - `kcall_nr` - Kernel call number.
 /// - `arg0` - First argument for the kernel call.
 /// - `arg1` - Second argument for the kernel call.
 /// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
 ///
 /// **Return**
 ///
-/// This function returns the value returned by the kernel call.
+/// The value returned by the kernel call is returned, wrapped so that it can
+/// be interpreted either as signed or unsigned.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
 ///
-#[inline(never)]
-pub unsafe fn kcall3(kcall_nr: u32, arg0: u32, arg1: u32, arg2: u32) -> u32 {
-    let ret: u32;
-    arch::asm!("int 0x80",
-        inout("eax") kcall_nr => ret,
-        in("ebx") arg0,
-        in("ecx") arg1,
-        in("edx") arg2,
-        options(nostack, preserves_flags)
-    );
-    ret
+pub unsafe fn kcall5_raw(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+) -> KcallRet {
+    KcallRet::from(kcall5(kcall_nr, arg0, arg1, arg2, arg3, arg4))
 }
 
 ///
 /// **Description**
 ///
-/// Issues a kernel call with four arguments.
+/// Issues a kernel call with six arguments and wraps its return value.
 ///
 /// **Parameters**
 /// - `kcall_nr` - Kernel call number.
@@ -188,27 +683,26 @@ pub unsafe fn kcall3(kcall_nr: u32, arg0: u32, arg1: u32, arg2: u32) -> u32 {
 /// - `arg1` - Second argument for the kernel call.
 /// - `arg2` - Third argument for the kernel call.
 /// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
+/// - `arg5` - Sixth argument for the kernel call.
 ///
 /// **Return**
 ///
-/// This function returns the value returned by the kernel call.
+/// The value returned by the kernel call is returned, wrapped so that it can
+/// be interpreted either as signed or unsigned.
+///
+/// **Safety**
+///
+/// - Same as for [`kcall0_checked`].
 ///
-#[inline(never)]
-pub unsafe fn kcall4(
+pub unsafe fn kcall6_raw(
     kcall_nr: u32,
     arg0: u32,
     arg1: u32,
     arg2: u32,
     arg3: u32,
-) -> u32 {
-    let ret: u32;
-    arch::asm!("int 0x80",
-        inout("eax") kcall_nr => ret,
-        in("ebx") arg0,
-        in("ecx") arg1,
-        in("edx") arg2,
-        in("edi") arg3,
-        options(nostack, preserves_flags)
-    );
-    ret
+    arg4: u32,
+    arg5: u32,
+) -> KcallRet {
+    KcallRet::from(kcall6(kcall_nr, arg0, arg1, arg2, arg3, arg4, arg5))
 }
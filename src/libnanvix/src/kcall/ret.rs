@@ -0,0 +1,113 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::kcall::{
+    self,
+    KcallError,
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Raw value returned by a kernel call.
+///
+/// **Notes**
+///
+/// - The kernel returns either a non-negative value or a negated error code in
+///   the same register. This type interprets the register consistently, so that
+///   values near the sign boundary are not misread.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KcallRet(u32);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl KcallRet {
+    ///
+    /// **Description**
+    ///
+    /// Interprets the target value as a signed integer.
+    ///
+    /// **Return**
+    ///
+    /// The target value with its bits reinterpreted as a signed integer.
+    ///
+    pub fn as_i32(&self) -> i32 {
+        self.0 as i32
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Interprets the target value as an unsigned integer.
+    ///
+    /// **Return**
+    ///
+    /// The target value, unchanged.
+    ///
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target value carries an error code.
+    ///
+    /// **Return**
+    ///
+    /// If the target value is negative when interpreted as a signed integer,
+    /// `true` is returned. Otherwise, `false` is returned instead.
+    ///
+    pub fn is_err(&self) -> bool {
+        self.as_i32() < 0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the error carried by the target value.
+    ///
+    /// **Return**
+    ///
+    /// If the target value carries an error code, the corresponding error is
+    /// returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn err(&self) -> Option<KcallError> {
+        kcall::decode(self.0).err()
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl From<u32> for KcallRet {
+    fn from(ret: u32) -> Self {
+        KcallRet(ret)
+    }
+}
+
+impl From<KcallRet> for u32 {
+    fn from(ret: KcallRet) -> Self {
+        ret.0
+    }
+}
+
+impl From<KcallRet> for Result<u32, KcallError> {
+    fn from(ret: KcallRet) -> Self {
+        kcall::decode(ret.0)
+    }
+}
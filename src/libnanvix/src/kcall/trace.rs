@@ -0,0 +1,159 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::kcall::{
+    arch,
+    KcallNumbers,
+};
+use core::{
+    fmt::{
+        self,
+        Write,
+    },
+    mem,
+    ptr,
+    sync::atomic::{
+        AtomicBool,
+        AtomicPtr,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// File descriptor where traces are written by default.
+const TRACE_FD: u32 = 2;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Hook that is called before every kernel call (null for the default one).
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Asserted while a hook runs.
+static TRACING: AtomicBool = AtomicBool::new(false);
+
+//==============================================================================
+// Types
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Hook that receives the number and the arguments of a kernel call.
+///
+pub type TraceHook = fn(kcall_nr: u32, args: &[u32]);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A writer that issues untraced write kernel calls.
+///
+struct TraceWriter;
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl fmt::Write for TraceWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        unsafe {
+            arch::kcall3(
+                KcallNumbers::Write as u32,
+                TRACE_FD,
+                s.as_ptr() as u32,
+                s.len() as u32,
+            );
+        }
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Overrides the hook that is called before every kernel call.
+///
+/// **Parameters**
+/// - `hook` - New hook.
+///
+pub fn set_trace_hook(hook: TraceHook) {
+    HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+///
+/// **Description**
+///
+/// Writes the name, or the number if it is unknown, and the arguments of a
+/// kernel call to the standard error.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `args` - Arguments of the kernel call.
+///
+pub fn default_trace_hook(kcall_nr: u32, args: &[u32]) {
+    let mut writer: TraceWriter = TraceWriter;
+    let _ = match KcallNumbers::from_u32(kcall_nr) {
+        Some(kcall) => write!(writer, "kcall: {}", kcall.name()),
+        None => write!(writer, "kcall: nr={}", kcall_nr),
+    };
+    for arg in args.iter() {
+        let _ = write!(writer, " {:#x}", arg);
+    }
+    let _ = writer.write_str("\n");
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Calls the trace hook for a kernel call.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `args` - Arguments of the kernel call.
+///
+/// **Notes**
+///
+/// - Kernel calls that are issued while a hook runs, including the ones issued
+///   by the hook itself, are not traced. This prevents the hook from recursing.
+///   The guard is shared by all threads of the calling process, thus kernel
+///   calls of other threads may be missed while a hook runs.
+///
+pub(crate) fn trace(kcall_nr: u32, args: &[u32]) {
+    if TRACING.swap(true, Ordering::Acquire) {
+        return;
+    }
+
+    let hook: *mut () = HOOK.load(Ordering::Acquire);
+    if hook.is_null() {
+        default_trace_hook(kcall_nr, args);
+    } else {
+        // SAFETY: non-null values are only stored by `set_trace_hook()`.
+        let hook: TraceHook =
+            unsafe { mem::transmute::<*mut (), TraceHook>(hook) };
+        hook(kcall_nr, args);
+    }
+
+    TRACING.store(false, Ordering::Release);
+}
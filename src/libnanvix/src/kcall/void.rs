@@ -13,6 +13,7 @@ use super::{
     kcall2,
     kcall3,
     kcall4,
+    kcall5,
     KcallNumbers,
 };
 
@@ -102,3 +103,57 @@ pub fn void3(arg0: u32, arg1: u32, arg2: u32) -> u32 {
 pub fn void4(arg0: u32, arg1: u32, arg2: u32, arg3: u32) -> u32 {
     unsafe { kcall4(KcallNumbers::Void4 as u32, arg0, arg1, arg2, arg3) }
 }
+
+///
+/// **Description**
+///
+/// Issues a void kernel call that takes five arguments.
+///
+/// **Parameters**
+/// - `arg0` - First argument for the kernel call.
+/// - `arg1` - Second argument for the kernel call.
+/// - `arg2` - Third argument for the kernel call.
+/// - `arg3` - Fourth argument for the kernel call.
+/// - `arg4` - Fifth argument for the kernel call.
+///
+/// **Return**
+///
+/// This function returns `arg0 + arg1 + arg2 + arg3 + arg4`
+///
+pub fn void5(arg0: u32, arg1: u32, arg2: u32, arg3: u32, arg4: u32) -> u32 {
+    unsafe { kcall5(KcallNumbers::Void5 as u32, arg0, arg1, arg2, arg3, arg4) }
+}
+
+///
+/// **Description**
+///
+/// Measures the latency of kernel calls.
+///
+/// **Parameters**
+/// - `iterations` - Number of void kernel calls to issue.
+///
+/// **Return**
+///
+/// This function returns the average number of timestamp counter cycles that
+/// a void kernel call takes. If `iterations` is zero, zero is returned.
+///
+/// **Notes**
+///
+/// - The timestamp counter is read with [`crate::arch::cycles`]. On
+///   processors with frequency scaling, the count may not match core cycles,
+///   thus results are estimates.
+///
+#[cfg(target_arch = "x86")]
+pub fn measure_latency(iterations: u32) -> u64 {
+    if iterations == 0 {
+        return 0;
+    }
+
+    let start: u64 = crate::arch::cycles();
+    for _ in 0..iterations {
+        void0();
+    }
+    let end: u64 = crate::arch::cycles();
+
+    end.wrapping_sub(start) / iterations as u64
+}
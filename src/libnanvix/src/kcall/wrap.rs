@@ -0,0 +1,170 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: these kernel call stubs wrap the ones of the target architecture when
+// kernel calls are traced or logged. Kernel calls are traced before they are
+// issued, and they are logged once they return.
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::kcall::arch;
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+/// Issues a kernel call with no arguments, tracing and logging it.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+#[inline(never)]
+pub unsafe fn kcall0(kcall_nr: u32) -> u32 {
+    let args: &[u32] = &[];
+    before(kcall_nr, args);
+    let ret: u32 = arch::kcall0(kcall_nr);
+    after(kcall_nr, args, ret)
+}
+
+/// Issues a kernel call with one argument, tracing and logging it.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+#[inline(never)]
+pub unsafe fn kcall1(kcall_nr: u32, arg0: u32) -> u32 {
+    let args: &[u32] = &[arg0];
+    before(kcall_nr, args);
+    let ret: u32 = arch::kcall1(kcall_nr, arg0);
+    after(kcall_nr, args, ret)
+}
+
+/// Issues a kernel call with two arguments, tracing and logging it.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+#[inline(never)]
+pub unsafe fn kcall2(kcall_nr: u32, arg0: u32, arg1: u32) -> u32 {
+    let args: &[u32] = &[arg0, arg1];
+    before(kcall_nr, args);
+    let ret: u32 = arch::kcall2(kcall_nr, arg0, arg1);
+    after(kcall_nr, args, ret)
+}
+
+/// Issues a kernel call with three arguments, tracing and logging it.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+#[inline(never)]
+pub unsafe fn kcall3(kcall_nr: u32, arg0: u32, arg1: u32, arg2: u32) -> u32 {
+    let args: &[u32] = &[arg0, arg1, arg2];
+    before(kcall_nr, args);
+    let ret: u32 = arch::kcall3(kcall_nr, arg0, arg1, arg2);
+    after(kcall_nr, args, ret)
+}
+
+/// Issues a kernel call with four arguments, tracing and logging it.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+#[inline(never)]
+pub unsafe fn kcall4(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+) -> u32 {
+    let args: &[u32] = &[arg0, arg1, arg2, arg3];
+    before(kcall_nr, args);
+    let ret: u32 = arch::kcall4(kcall_nr, arg0, arg1, arg2, arg3);
+    after(kcall_nr, args, ret)
+}
+
+/// Issues a kernel call with five arguments, tracing and logging it.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+#[inline(never)]
+pub unsafe fn kcall5(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+) -> u32 {
+    let args: &[u32] = &[arg0, arg1, arg2, arg3, arg4];
+    before(kcall_nr, args);
+    let ret: u32 = arch::kcall5(kcall_nr, arg0, arg1, arg2, arg3, arg4);
+    after(kcall_nr, args, ret)
+}
+
+/// Issues a kernel call with six arguments, tracing and logging it.
+///
+/// **Safety**
+///
+/// - Same as for [`crate::kcall::kcall0_checked`].
+#[inline(never)]
+pub unsafe fn kcall6(
+    kcall_nr: u32,
+    arg0: u32,
+    arg1: u32,
+    arg2: u32,
+    arg3: u32,
+    arg4: u32,
+    arg5: u32,
+) -> u32 {
+    let args: &[u32] = &[arg0, arg1, arg2, arg3, arg4, arg5];
+    before(kcall_nr, args);
+    let ret: u32 = arch::kcall6(kcall_nr, arg0, arg1, arg2, arg3, arg4, arg5);
+    after(kcall_nr, args, ret)
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Runs before a kernel call is issued.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `args` - Arguments of the kernel call.
+///
+#[allow(unused_variables)]
+fn before(kcall_nr: u32, args: &[u32]) {
+    #[cfg(feature = "trace")]
+    super::trace::trace(kcall_nr, args);
+}
+
+///
+/// **Description**
+///
+/// Runs after a kernel call returns.
+///
+/// **Parameters**
+/// - `kcall_nr` - Kernel call number.
+/// - `args` - Arguments of the kernel call.
+/// - `ret` - Value returned by the kernel call.
+///
+/// **Return**
+///
+/// The value returned by the kernel call is returned.
+///
+#[allow(unused_variables)]
+fn after(kcall_nr: u32, args: &[u32], ret: u32) -> u32 {
+    #[cfg(feature = "kcall-log")]
+    super::log::record(kcall_nr, args, ret);
+    ret
+}
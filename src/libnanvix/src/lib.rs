@@ -6,6 +6,9 @@
 #![no_std]
 #![feature(panic_info_message)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 //==============================================================================
 // Modules
 //==============================================================================
@@ -14,16 +17,30 @@ pub mod logging;
 
 mod unwind;
 
+#[cfg(feature = "panic-handler")]
+pub mod panic;
+
+#[cfg(feature = "rt")]
+pub mod rt;
+
+pub mod arch;
 pub mod devices;
 pub mod excp;
+pub mod ffi;
+pub mod fmt;
 pub mod iam;
+pub mod io;
 pub mod ipc;
 pub mod kcall;
 pub mod memory;
 pub mod misc;
 pub mod pm;
 pub mod power;
+pub mod prelude;
 pub mod security;
+pub mod sync;
+pub mod thread;
+pub mod time;
 
 #[macro_export]
 macro_rules! log{
@@ -33,3 +50,44 @@ macro_rules! log{
 		let _ = write!(&mut Logger::get(module_path!()), $($arg)*);
 	})
 }
+
+///
+/// **Description**
+///
+/// Prints formatted text to the standard output.
+///
+/// **Example**
+///
+/// ```ignore
+/// let pid = nanvix::pm::Pid::SELF;
+/// nanvix::print!("pid={}", pid);
+/// ```
+///
+#[macro_export]
+macro_rules! print {
+	( $($arg:tt)* ) => ({
+		use core::fmt::Write;
+		let _ = write!(&mut $crate::io::Stdout::new(), $($arg)*);
+	})
+}
+
+///
+/// **Description**
+///
+/// Prints formatted text to the standard output, followed by a newline.
+///
+/// **Example**
+///
+/// ```ignore
+/// let pid = nanvix::pm::Pid::SELF;
+/// nanvix::println!("pid={}", pid);
+/// ```
+///
+#[macro_export]
+macro_rules! println {
+	() => ($crate::print!("\n"));
+	( $($arg:tt)* ) => ({
+		use core::fmt::Write;
+		let _ = writeln!(&mut $crate::io::Stdout::new(), $($arg)*);
+	})
+}
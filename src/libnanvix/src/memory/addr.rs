@@ -0,0 +1,413 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::memory::{
+    FrameNumber,
+    PhysicalAddress,
+    VirtualAddress,
+    PAGE_SIZE,
+};
+use core::fmt;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A physical address.
+///
+/// **Notes**
+///
+/// - This structure is transparent, so that it has the same layout of the
+///   `paddr_t` type in the kernel space.
+/// - A physical address may not be used where a virtual address is expected,
+///   and vice versa. Raw addresses convert into either of them.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct PhysAddr(PhysicalAddress);
+
+///
+/// **Description**
+///
+/// A virtual address.
+///
+/// **Notes**
+///
+/// - This structure is transparent, so that it has the same layout of the
+///   `vaddr_t` type in the kernel space.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct VirtAddr(VirtualAddress);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl PhysAddr {
+    ///
+    /// **Description**
+    ///
+    /// Creates a physical address.
+    ///
+    /// **Parameters**
+    /// - `addr` - Raw physical address.
+    ///
+    /// **Return**
+    ///
+    /// The physical address `addr`.
+    ///
+    pub const fn new(addr: PhysicalAddress) -> Self {
+        Self(addr)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the physical address of the first byte of a page frame.
+    ///
+    /// **Parameters**
+    /// - `frame` - Number of the page frame.
+    ///
+    /// **Return**
+    ///
+    /// The physical address of the page frame, or `None` if it overflows.
+    ///
+    pub const fn from_frame(frame: FrameNumber) -> Option<Self> {
+        match frame.checked_mul(PAGE_SIZE) {
+            Some(addr) => Some(Self(addr)),
+            None => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value of the target physical address.
+    ///
+    /// **Return**
+    ///
+    /// The raw physical address.
+    ///
+    pub const fn as_raw(&self) -> PhysicalAddress {
+        self.0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Rounds the target physical address up to a boundary.
+    ///
+    /// **Parameters**
+    /// - `align` - Boundary (in bytes). It must be a power of two.
+    ///
+    /// **Return**
+    ///
+    /// The smallest aligned address that is not below the target one, or
+    /// `None` if it overflows.
+    ///
+    pub const fn align_up(self, align: u32) -> Option<Self> {
+        match align_up(self.0, align) {
+            Some(addr) => Some(Self(addr)),
+            None => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Rounds the target physical address down to a boundary.
+    ///
+    /// **Parameters**
+    /// - `align` - Boundary (in bytes). It must be a power of two.
+    ///
+    /// **Return**
+    ///
+    /// The largest aligned address that is not above the target one.
+    ///
+    pub const fn align_down(self, align: u32) -> Self {
+        Self(align_down(self.0, align))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target physical address lies on a boundary.
+    ///
+    /// **Parameters**
+    /// - `align` - Boundary (in bytes). It must be a power of two.
+    ///
+    /// **Return**
+    ///
+    /// If the target address is aligned, `true` is returned. Otherwise,
+    /// `false` is returned instead.
+    ///
+    pub const fn is_aligned(&self, align: u32) -> bool {
+        self.0 & (align - 1) == 0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Offsets the target physical address.
+    ///
+    /// **Parameters**
+    /// - `offset` - Offset (in bytes).
+    ///
+    /// **Return**
+    ///
+    /// The target address plus `offset`, or `None` if it overflows.
+    ///
+    pub const fn offset(self, offset: u32) -> Option<Self> {
+        match self.0.checked_add(offset) {
+            Some(addr) => Some(Self(addr)),
+            None => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of the page frame that holds the target physical
+    /// address.
+    ///
+    /// **Return**
+    ///
+    /// The number of the page frame.
+    ///
+    pub const fn page_number(&self) -> FrameNumber {
+        self.0 / PAGE_SIZE
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the offset of the target physical address in its page frame.
+    ///
+    /// **Return**
+    ///
+    /// The offset in the page frame (in bytes).
+    ///
+    pub const fn page_offset(&self) -> u32 {
+        self.0 % PAGE_SIZE
+    }
+}
+
+impl VirtAddr {
+    ///
+    /// **Description**
+    ///
+    /// Creates a virtual address.
+    ///
+    /// **Parameters**
+    /// - `addr` - Raw virtual address.
+    ///
+    /// **Return**
+    ///
+    /// The virtual address `addr`.
+    ///
+    pub const fn new(addr: VirtualAddress) -> Self {
+        Self(addr)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value of the target virtual address.
+    ///
+    /// **Return**
+    ///
+    /// The raw virtual address.
+    ///
+    pub const fn as_raw(&self) -> VirtualAddress {
+        self.0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Rounds the target virtual address up to a boundary.
+    ///
+    /// **Parameters**
+    /// - `align` - Boundary (in bytes). It must be a power of two.
+    ///
+    /// **Return**
+    ///
+    /// The smallest aligned address that is not below the target one, or
+    /// `None` if it overflows.
+    ///
+    pub const fn align_up(self, align: u32) -> Option<Self> {
+        match align_up(self.0, align) {
+            Some(addr) => Some(Self(addr)),
+            None => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Rounds the target virtual address down to a boundary.
+    ///
+    /// **Parameters**
+    /// - `align` - Boundary (in bytes). It must be a power of two.
+    ///
+    /// **Return**
+    ///
+    /// The largest aligned address that is not above the target one.
+    ///
+    pub const fn align_down(self, align: u32) -> Self {
+        Self(align_down(self.0, align))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target virtual address lies on a boundary.
+    ///
+    /// **Parameters**
+    /// - `align` - Boundary (in bytes). It must be a power of two.
+    ///
+    /// **Return**
+    ///
+    /// If the target address is aligned, `true` is returned. Otherwise,
+    /// `false` is returned instead.
+    ///
+    pub const fn is_aligned(&self, align: u32) -> bool {
+        self.0 & (align - 1) == 0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Offsets the target virtual address.
+    ///
+    /// **Parameters**
+    /// - `offset` - Offset (in bytes).
+    ///
+    /// **Return**
+    ///
+    /// The target address plus `offset`, or `None` if it overflows.
+    ///
+    pub const fn offset(self, offset: u32) -> Option<Self> {
+        match self.0.checked_add(offset) {
+            Some(addr) => Some(Self(addr)),
+            None => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of the page that holds the target virtual address.
+    ///
+    /// **Return**
+    ///
+    /// The number of the page.
+    ///
+    pub const fn page_number(&self) -> u32 {
+        self.0 / PAGE_SIZE
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the offset of the target virtual address in its page.
+    ///
+    /// **Return**
+    ///
+    /// The offset in the page (in bytes).
+    ///
+    pub const fn page_offset(&self) -> u32 {
+        self.0 % PAGE_SIZE
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl From<PhysicalAddress> for PhysAddr {
+    fn from(addr: PhysicalAddress) -> Self {
+        Self(addr)
+    }
+}
+
+impl From<PhysAddr> for PhysicalAddress {
+    fn from(addr: PhysAddr) -> Self {
+        addr.0
+    }
+}
+
+impl From<VirtualAddress> for VirtAddr {
+    fn from(addr: VirtualAddress) -> Self {
+        Self(addr)
+    }
+}
+
+impl From<VirtAddr> for VirtualAddress {
+    fn from(addr: VirtAddr) -> Self {
+        addr.0
+    }
+}
+
+impl fmt::Display for PhysAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
+
+impl fmt::Display for VirtAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Rounds a raw address up to a boundary.
+///
+/// **Parameters**
+/// - `addr` - Raw address.
+/// - `align` - Boundary (in bytes). It must be a power of two.
+///
+/// **Return**
+///
+/// The rounded address, or `None` if it overflows.
+///
+const fn align_up(addr: u32, align: u32) -> Option<u32> {
+    match addr.checked_add(align - 1) {
+        Some(addr) => Some(align_down(addr, align)),
+        None => None,
+    }
+}
+
+///
+/// **Description**
+///
+/// Rounds a raw address down to a boundary.
+///
+/// **Parameters**
+/// - `addr` - Raw address.
+/// - `align` - Boundary (in bytes). It must be a power of two.
+///
+/// **Return**
+///
+/// The rounded address.
+///
+const fn align_down(addr: u32, align: u32) -> u32 {
+    addr & !(align - 1)
+}
@@ -0,0 +1,399 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::{
+        self,
+        KcallError,
+    },
+    memory::{
+        self,
+        Frame,
+        PageFlags,
+        VirtualAddress,
+        VirtualMemory,
+        VmCtrlRequest,
+        PAGE_SIZE,
+    },
+    pm::{
+        self,
+        Pid,
+    },
+};
+use core::{
+    alloc::{
+        GlobalAlloc,
+        Layout,
+    },
+    cell::UnsafeCell,
+    mem,
+    ptr,
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Block of memory that was released to the allocator.
+///
+struct FreeBlock {
+    /// Size of the block (in bytes).
+    size: usize,
+    /// Next block in the free list.
+    next: *mut FreeBlock,
+}
+
+///
+/// **Description**
+///
+/// Mutable state of a frame-backed allocator.
+///
+struct Heap {
+    /// Next address that was never handed out.
+    brk: VirtualAddress,
+    /// End of the mapped part of the heap.
+    mapped: VirtualAddress,
+    /// Virtual memory space of the calling process (lazily retrieved).
+    vmem: Option<VirtualMemory>,
+    /// List of released blocks.
+    free: *mut FreeBlock,
+}
+
+///
+/// **Description**
+///
+/// Global allocator that backs a range of virtual memory with page frames.
+///
+/// **Notes**
+///
+/// - Blocks are first taken from a free list of released blocks, and then
+///   carved out of the never-used part of the range, which grows one page at a
+///   time by allocating a page frame and mapping it in the virtual memory space
+///   of the calling process.
+/// - Released blocks are not coalesced and page frames are never returned to
+///   the kernel.
+/// - Exhaustion of either the range or page frames is reported by returning a
+///   null pointer.
+///
+/// **Example**
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: FrameBackedAllocator =
+///     FrameBackedAllocator::new(0x05000000, 0x06000000);
+/// ```
+///
+pub struct FrameBackedAllocator {
+    /// End of the range.
+    end: VirtualAddress,
+    /// Asserted while the heap is being used.
+    locked: AtomicBool,
+    /// Heap state.
+    heap: UnsafeCell<Heap>,
+}
+
+// The heap state is only accessed while the allocator is locked.
+unsafe impl Sync for FrameBackedAllocator {}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl FrameBackedAllocator {
+    ///
+    /// **Description**
+    ///
+    /// Creates a frame-backed allocator.
+    ///
+    /// **Parameters**
+    ///
+    /// - `start` - Start address of the range (rounded up to a page boundary).
+    /// - `end` - End address of the range (exclusive).
+    ///
+    /// **Return**
+    ///
+    /// A frame-backed allocator that manages the range `start..end`.
+    ///
+    /// **Notes**
+    ///
+    /// - The range should lie in user memory and should not overlap anything
+    ///   else that is mapped in the calling process. The heap stops growing at
+    ///   the first page that lies in a region that is reserved by this
+    ///   library. See [`memory::is_reserved`].
+    ///
+    pub const fn new(start: VirtualAddress, end: VirtualAddress) -> Self {
+        let start: VirtualAddress = (start + PAGE_SIZE - 1) & !(PAGE_SIZE - 1);
+        Self {
+            end,
+            locked: AtomicBool::new(false),
+            heap: UnsafeCell::new(Heap {
+                brk: start,
+                mapped: start,
+                vmem: None,
+                free: ptr::null_mut(),
+            }),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Runs a closure with exclusive access to the heap state.
+    ///
+    /// **Parameters**
+    ///
+    /// - `f` - Closure to run.
+    ///
+    /// **Return**
+    ///
+    /// The value returned by `f`.
+    ///
+    fn with_heap<R>(&self, f: impl FnOnce(&mut Heap) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            pm::thread_yield();
+        }
+
+        let ret: R = f(unsafe { &mut *self.heap.get() });
+
+        self.locked.store(false, Ordering::Release);
+
+        ret
+    }
+}
+
+impl Heap {
+    ///
+    /// **Description**
+    ///
+    /// Takes a suitable block from the free list.
+    ///
+    /// **Parameters**
+    ///
+    /// - `size` - Size of the block.
+    /// - `align` - Alignment of the block.
+    ///
+    /// **Return**
+    ///
+    /// If a suitable block is found, a pointer to it is returned. Otherwise,
+    /// a null pointer is returned instead.
+    ///
+    unsafe fn take_free(&mut self, size: usize, align: usize) -> *mut u8 {
+        let mut link: *mut *mut FreeBlock = &mut self.free;
+
+        while !(*link).is_null() {
+            let block: *mut FreeBlock = *link;
+
+            if (block as usize) % align == 0 && (*block).size >= size {
+                let remainder: usize = (*block).size - size;
+
+                // Split the block, if the remainder is large enough.
+                if remainder >= mem::size_of::<FreeBlock>() {
+                    let rest: *mut FreeBlock =
+                        (block as *mut u8).add(size) as *mut FreeBlock;
+                    rest.write(FreeBlock {
+                        size: remainder,
+                        next: (*block).next,
+                    });
+                    *link = rest;
+                } else {
+                    *link = (*block).next;
+                }
+
+                return block as *mut u8;
+            }
+
+            link = &mut (*block).next;
+        }
+
+        ptr::null_mut()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Releases a block to the free list.
+    ///
+    /// **Parameters**
+    ///
+    /// - `ptr` - Pointer to the block.
+    /// - `size` - Size of the block.
+    ///
+    unsafe fn put_free(&mut self, ptr: *mut u8, size: usize) {
+        let block: *mut FreeBlock = ptr as *mut FreeBlock;
+        block.write(FreeBlock {
+            size,
+            next: self.free,
+        });
+        self.free = block;
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Carves a block out of the never-used part of the heap.
+    ///
+    /// **Parameters**
+    ///
+    /// - `size` - Size of the block.
+    /// - `align` - Alignment of the block.
+    /// - `end` - End address of the heap.
+    ///
+    /// **Return**
+    ///
+    /// On successful completion, a pointer to the block is returned. On
+    /// failure, a null pointer is returned instead.
+    ///
+    unsafe fn take_brk(
+        &mut self,
+        size: usize,
+        align: usize,
+        end: VirtualAddress,
+    ) -> *mut u8 {
+        let brk: usize = self.brk as usize;
+        let addr: usize = match brk.checked_add(align - 1) {
+            Some(addr) => addr & !(align - 1),
+            None => return ptr::null_mut(),
+        };
+        let new_brk: usize = match addr.checked_add(size) {
+            Some(new_brk) if new_brk <= end as usize => new_brk,
+            _ => return ptr::null_mut(),
+        };
+
+        // Grow the mapped part of the heap.
+        while (self.mapped as usize) < new_brk {
+            if self.map_page().is_err() {
+                return ptr::null_mut();
+            }
+        }
+
+        self.brk = new_brk as VirtualAddress;
+
+        // Recycle the padding that was introduced by the alignment.
+        if addr - brk >= mem::size_of::<FreeBlock>() {
+            self.put_free(brk as *mut u8, addr - brk);
+        }
+
+        addr as *mut u8
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Maps a page frame at the end of the mapped part of the heap.
+    ///
+    /// **Return**
+    ///
+    /// On successful completion, empty is returned. On failure, an error is
+    /// returned instead. If the page lies in a region that is reserved by this
+    /// library, [`KcallError::InvalidArgument`] is returned.
+    ///
+    fn map_page(&mut self) -> Result<(), KcallError> {
+        // Check for reserved regions.
+        if memory::is_reserved(self.mapped) {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let vmem: VirtualMemory = match self.vmem {
+            Some(vmem) => vmem,
+            None => {
+                let vmem: VirtualMemory = pm::process_info(Pid::SELF)?.vmem;
+                self.vmem = Some(vmem);
+                vmem
+            },
+        };
+
+        let frame: Frame = Frame::alloc()?;
+        kcall::decode(memory::vmmap(vmem, self.mapped, frame.number()))?;
+
+        let flags: PageFlags = PageFlags::READ | PageFlags::WRITE;
+        let request: VmCtrlRequest =
+            VmCtrlRequest::ChangePermissions(self.mapped, flags.into());
+        if let Err(e) = kcall::decode(memory::vmctrl(vmem, request)) {
+            let _ = memory::vmunmap(vmem, self.mapped);
+            return Err(e);
+        }
+
+        // The page frame is now owned by the heap.
+        frame.leak();
+        self.mapped += PAGE_SIZE;
+
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+unsafe impl GlobalAlloc for FrameBackedAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let (size, align): (usize, usize) = match block_layout(layout) {
+            Some(block) => block,
+            None => return ptr::null_mut(),
+        };
+
+        self.with_heap(|heap| {
+            let ptr: *mut u8 = heap.take_free(size, align);
+            if !ptr.is_null() {
+                return ptr;
+            }
+            heap.take_brk(size, align, self.end)
+        })
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if let Some((size, _)) = block_layout(layout) {
+            self.with_heap(|heap| heap.put_free(ptr, size));
+        }
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Computes the size and alignment of the block that backs an allocation.
+///
+/// **Parameters**
+///
+/// - `layout` - Layout of the allocation.
+///
+/// **Return**
+///
+/// If the block can be represented, its size and alignment are returned.
+/// Otherwise, `None` is returned instead.
+///
+/// **Notes**
+///
+/// - Blocks are large and aligned enough to hold a free-list entry, so that
+///   they can be released to the free list.
+///
+fn block_layout(layout: Layout) -> Option<(usize, usize)> {
+    let layout: Layout = layout
+        .align_to(mem::align_of::<FreeBlock>())
+        .ok()?
+        .pad_to_align();
+    let size: usize = layout.size().max(mem::size_of::<FreeBlock>());
+
+    Some((size, layout.align()))
+}
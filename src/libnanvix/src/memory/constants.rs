@@ -3,6 +3,19 @@
  * Licensed under the MIT License.
  */
 
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    pm::THREADS_MAX,
+    sync::ARC_MAX,
+    thread::{
+        THREAD_STACKS_MAX,
+        THREAD_STACK_MAX,
+    },
+};
+
 //==============================================================================
 // Constants
 //==============================================================================
@@ -13,11 +26,59 @@ pub const KERNEL_BASE_ADDRESS: u32 = 0x00000000;
 /// Base address for user memory.
 pub const USER_BASE_ADDRESS: u32 = 0x04000000;
 
+/// End address for user memory.
+pub const USER_END_ADDRESS: u32 = 0x08000000;
+
 /// Page size (in bytes).
 pub const PAGE_SIZE: u32 = 4096;
 
+/// Lowest address of the region at the top of user memory where the kernel
+/// places the stacks of threads, one page per thread.
+pub const USER_STACKS_BASE_ADDRESS: u32 =
+    USER_END_ADDRESS - THREADS_MAX as u32 * PAGE_SIZE;
+
+/// Base address of the region of user memory where the control blocks of
+/// [`crate::sync::Arc`] are mapped, one page per control block.
+pub const ARC_REGION_BASE_ADDRESS: u32 = 0x07000000;
+
+/// End address of the region of user memory where the control blocks of
+/// [`crate::sync::Arc`] are mapped.
+pub const ARC_REGION_END_ADDRESS: u32 =
+    ARC_REGION_BASE_ADDRESS + ARC_MAX as u32 * PAGE_SIZE;
+
+/// Base address of the region of user memory where the stacks that a
+/// [`crate::thread::Builder`] allocates are mapped.
+pub const THREAD_STACKS_REGION_BASE_ADDRESS: u32 = 0x07800000;
+
+/// End address of the region of user memory where the stacks that a
+/// [`crate::thread::Builder`] allocates are mapped. Every stack takes a slot
+/// with one extra page, which is never mapped.
+pub const THREAD_STACKS_REGION_END_ADDRESS: u32 =
+    THREAD_STACKS_REGION_BASE_ADDRESS
+        + THREAD_STACKS_MAX as u32 * (THREAD_STACK_MAX as u32 + PAGE_SIZE);
+
 /// Null page frame.
 pub const NULL_FRAME: u32 = u32::MAX;
 
 /// Null virtual memory space.
 pub const NULL_VMEM: i32 = i32::MIN;
+
+/// Size of page information (in bytes). See `struct pageinfo` in the kernel.
+pub const PAGE_INFO_SIZE: usize = 8;
+
+//==============================================================================
+// Compile-Time Checks
+//==============================================================================
+
+// Reserved regions should lie in user memory, below the stacks of threads.
+const _: () = {
+    if ARC_REGION_BASE_ADDRESS < USER_BASE_ADDRESS {
+        panic!("the arc region lies below user memory");
+    }
+    if ARC_REGION_END_ADDRESS > THREAD_STACKS_REGION_BASE_ADDRESS {
+        panic!("the arc region overlaps the region of thread stacks");
+    }
+    if THREAD_STACKS_REGION_END_ADDRESS > USER_STACKS_BASE_ADDRESS {
+        panic!("the region of thread stacks overlaps the stacks of threads");
+    }
+};
@@ -0,0 +1,418 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::{
+        self,
+        KcallError,
+    },
+    memory::{
+        self,
+        FrameNumber,
+        PageFlags,
+        PhysAddr,
+        VirtualAddress,
+        VirtualMemory,
+        VmCtrlRequest,
+    },
+    pm::{
+        self,
+        Pid,
+    },
+    sync::{
+        SpinMutex,
+        SpinMutexGuard,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum number of page frames that are set aside while looking for
+/// contiguous page frames.
+const FRAME_RANGE_SPARE_MAX: usize = 64;
+
+/// Page where page frames are mapped while they are zeroed. It lies below the
+/// stacks of threads.
+const ZERO_WINDOW: VirtualAddress =
+    memory::USER_STACKS_BASE_ADDRESS - 2 * memory::PAGE_SIZE;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Serializes accesses to [`ZERO_WINDOW`].
+static ZERO_WINDOW_LOCK: SpinMutex<()> = SpinMutex::new(0, ());
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// An owned page frame.
+///
+/// **Notes**
+///
+/// - The page frame is released when it is dropped.
+///
+#[derive(Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// Number of the page frame.
+    number: FrameNumber,
+}
+
+///
+/// **Description**
+///
+/// An owned range of physically contiguous page frames.
+///
+/// **Notes**
+///
+/// - The page frames are released when the range is dropped.
+///
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrameRange {
+    /// Number of the first page frame.
+    start: FrameNumber,
+    /// Number of page frames.
+    count: usize,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Frame {
+    ///
+    /// **Description**
+    ///
+    /// Allocates a page frame.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the allocated page frame is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn alloc() -> Result<Self, KcallError> {
+        let number: FrameNumber = memory::fralloc();
+        if number == memory::NULL_FRAME {
+            return Err(KcallError::NoMemory);
+        }
+
+        Ok(Frame { number })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Allocates a page frame that is filled with zeros.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the allocated page frame is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel neither zeroes page frames when they are released nor when
+    ///   they are allocated, thus a page frame that is returned by
+    ///   [`Frame::alloc`] may hold the data of its previous owner. Only pages
+    ///   that the kernel allocates on behalf of a process are zeroed.
+    /// - The page frame is zeroed by mapping it temporarily into the virtual
+    ///   memory space of the calling process. Threads that zero page frames at
+    ///   once take turns.
+    ///
+    pub fn alloc_zeroed() -> Result<Self, KcallError> {
+        let frame: Frame = Frame::alloc()?;
+        frame.zero()?;
+        Ok(frame)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Allocates a page frame whose physical address is aligned.
+    ///
+    /// **Parameters**
+    /// - `align` - Alignment of the physical address (in bytes). It must be a
+    ///   power of two.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the allocated page frame is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn alloc_aligned(align: usize) -> Result<Self, KcallError> {
+        let range: FrameRange = FrameRange::alloc_contiguous(1, align)?;
+        let number: FrameNumber = range.leak();
+        Ok(Frame { number })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of the target page frame.
+    ///
+    /// **Return**
+    ///
+    /// The number of the target page frame is returned.
+    ///
+    pub fn number(&self) -> FrameNumber {
+        self.number
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the physical address of the target page frame.
+    ///
+    /// **Return**
+    ///
+    /// The physical address of the target page frame is returned.
+    ///
+    pub fn addr(&self) -> PhysAddr {
+        PhysAddr::new(self.number * memory::PAGE_SIZE)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target page frame without releasing it.
+    ///
+    /// **Return**
+    ///
+    /// The number of the target page frame is returned.
+    ///
+    pub fn leak(self) -> FrameNumber {
+        let number: FrameNumber = self.number;
+        core::mem::forget(self);
+        number
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Fills the target page frame with zeros.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    fn zero(&self) -> Result<(), KcallError> {
+        let _guard: SpinMutexGuard<()> = ZERO_WINDOW_LOCK.lock();
+        let vmem: VirtualMemory = pm::process_info(Pid::SELF)?.vmem;
+
+        kcall::decode(memory::vmmap(vmem, ZERO_WINDOW, self.number))?;
+
+        let flags: PageFlags = PageFlags::READ | PageFlags::WRITE;
+        let request: VmCtrlRequest =
+            VmCtrlRequest::ChangePermissions(ZERO_WINDOW, flags.into());
+        let result: Result<u32, KcallError> =
+            kcall::decode(memory::vmctrl(vmem, request));
+        if result.is_ok() {
+            unsafe {
+                core::ptr::write_bytes(
+                    ZERO_WINDOW as *mut u8,
+                    0,
+                    memory::PAGE_SIZE as usize,
+                )
+            };
+        }
+
+        // Unmapping does not release the page frame, which stays owned.
+        let _ = memory::vmunmap(vmem, ZERO_WINDOW);
+        result.map(|_| ())
+    }
+}
+
+impl FrameRange {
+    ///
+    /// **Description**
+    ///
+    /// Allocates physically contiguous page frames.
+    ///
+    /// **Parameters**
+    /// - `count` - Number of page frames.
+    /// - `align` - Alignment of the physical address of the first page frame
+    ///   (in bytes). It must be a power of two.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the allocated page frames are returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel allocates one page frame at a time. For this reason, page
+    ///   frames are allocated until enough of them are contiguous and aligned.
+    ///   Page frames that do not fit are set aside, so that they are not handed
+    ///   out again, and released once the search completes.
+    /// - Only a bounded number of page frames is set aside. If no suitable
+    ///   range is found by then, the allocation fails.
+    ///
+    pub fn alloc_contiguous(
+        count: usize,
+        align: usize,
+    ) -> Result<Self, KcallError> {
+        if count == 0 || !align.is_power_of_two() {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        // Alignment in page frames.
+        let align: u32 = (align / memory::PAGE_SIZE as usize).max(1) as u32;
+
+        let mut spare: [FrameNumber; FRAME_RANGE_SPARE_MAX] =
+            [memory::NULL_FRAME; FRAME_RANGE_SPARE_MAX];
+        let mut nspare: usize = 0;
+        let mut start: FrameNumber = memory::NULL_FRAME;
+        let mut len: usize = 0;
+
+        let result: Result<(), KcallError> = loop {
+            if len == count {
+                break Ok(());
+            }
+
+            let number: FrameNumber = memory::fralloc();
+            if number == memory::NULL_FRAME {
+                break Err(KcallError::NoMemory);
+            }
+
+            // Extend the current range.
+            if len > 0 && number == start + len as u32 {
+                len += 1;
+                continue;
+            }
+
+            // Set aside page frames that do not fit.
+            let (first, n): (FrameNumber, usize) = if number % align == 0 {
+                let discarded: (FrameNumber, usize) = (start, len);
+                start = number;
+                len = 1;
+                discarded
+            } else {
+                (number, 1)
+            };
+            if nspare + n > FRAME_RANGE_SPARE_MAX {
+                for i in 0..n {
+                    let _ = memory::frfree(first + i as u32);
+                }
+                break Err(KcallError::NoMemory);
+            }
+            for i in 0..n {
+                spare[nspare] = first + i as u32;
+                nspare += 1;
+            }
+        };
+
+        for number in spare[..nspare].iter() {
+            let _ = memory::frfree(*number);
+        }
+
+        match result {
+            Ok(()) => Ok(FrameRange { start, count }),
+            Err(e) => {
+                for i in 0..len {
+                    let _ = memory::frfree(start + i as u32);
+                }
+                Err(e)
+            },
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of the first page frame in the target range.
+    ///
+    /// **Return**
+    ///
+    /// The number of the first page frame in the target range is returned.
+    ///
+    pub fn start(&self) -> FrameNumber {
+        self.start
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of page frames in the target range.
+    ///
+    /// **Return**
+    ///
+    /// The number of page frames in the target range is returned.
+    ///
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target range is empty.
+    ///
+    /// **Return**
+    ///
+    /// This function always returns `false`, because a range spans at least
+    /// one page frame.
+    ///
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the physical base address of the target range.
+    ///
+    /// **Return**
+    ///
+    /// The physical address of the first page frame in the target range is
+    /// returned.
+    ///
+    pub fn addr(&self) -> PhysAddr {
+        PhysAddr::new(self.start * memory::PAGE_SIZE)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target range without releasing its page frames.
+    ///
+    /// **Return**
+    ///
+    /// The number of the first page frame in the target range is returned.
+    ///
+    pub fn leak(self) -> FrameNumber {
+        let start: FrameNumber = self.start;
+        core::mem::forget(self);
+        start
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Drop for Frame {
+    fn drop(&mut self) {
+        let _ = memory::frfree(self.number);
+    }
+}
+
+impl Drop for FrameRange {
+    fn drop(&mut self) {
+        for i in 0..self.count {
+            let _ = memory::frfree(self.start + i as u32);
+        }
+    }
+}
@@ -20,6 +20,7 @@ use crate::{
         FrameNumber,
         VirtualAddress,
         VirtualMemory,
+        PAGE_INFO_SIZE,
     },
     security::AccessMode,
 };
@@ -42,13 +43,29 @@ impl Into<u32> for VmCtrlRequest {
     }
 }
 
+///
+/// **Description**
+///
+/// Information about a page, as reported by the kernel.
+///
+/// **Notes**
+///
+/// - The layout of this structure should match the one of `struct pageinfo`
+///   in the kernel. See `include/nanvix/kernel/mm/upool.h` for more
+///   information.
+///
 #[derive(Clone, Debug, Default)]
 #[repr(C)]
 pub struct PageInfo {
+    /// Page frame.
     pub frame: FrameNumber,
+    /// Access permissions.
     pub mode: AccessMode,
 }
 
+// Ensure that the layout of page information matches the kernel's.
+const _: () = assert!(core::mem::size_of::<PageInfo>() == PAGE_INFO_SIZE);
+
 //==============================================================================
 // Public Standalone Functions
 //==============================================================================
@@ -7,16 +7,26 @@
 // Modules
 //==============================================================================
 
+mod addr;
+#[cfg(feature = "alloc")]
+mod alloc;
 mod constants;
+mod frame;
 mod kcall;
 mod types;
+mod vmem;
 
 //==============================================================================
 // Exports
 //==============================================================================
 
+#[cfg(feature = "alloc")]
+pub use self::alloc::*;
 pub use self::{
+    addr::*,
     constants::*,
+    frame::*,
     kcall::*,
     types::*,
+    vmem::*,
 };
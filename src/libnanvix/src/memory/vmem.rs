@@ -0,0 +1,1458 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::{
+        self,
+        KcallContext,
+        KcallContextError,
+        KcallError,
+        KcallNumbers,
+    },
+    memory::{
+        self,
+        Frame,
+        FrameNumber,
+        PageInfo,
+        VirtAddr,
+        VirtualAddress,
+        VirtualMemory,
+        PAGE_SIZE,
+    },
+    pm::{
+        self,
+        Pid,
+    },
+    security::AccessMode,
+};
+use core::{
+    cell::Cell,
+    convert::TryFrom,
+    marker::PhantomData,
+    mem::{
+        self,
+        ManuallyDrop,
+    },
+    ops::{
+        BitOr,
+        BitOrAssign,
+    },
+    slice::{
+        self,
+        SliceIndex,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Control command that changes the access permissions of a page.
+const VMEM_CHMOD: u32 = 0;
+
+/// Number of page flags that are accounted for in virtual memory spaces.
+const PAGE_FLAGS_COUNT: usize = 4;
+
+/// Page flags that are accounted for in virtual memory spaces.
+const PAGE_FLAGS: [PageFlags; PAGE_FLAGS_COUNT] = [
+    PageFlags::READ,
+    PageFlags::WRITE,
+    PageFlags::EXEC,
+    PageFlags::USER,
+];
+
+/// Access permissions that pages of a virtual memory space may take by default.
+const PAGE_FLAGS_ALL: PageFlags = PageFlags(
+    PageFlags::READ.0
+        | PageFlags::WRITE.0
+        | PageFlags::EXEC.0
+        | PageFlags::USER.0,
+);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Access permissions of a page.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PageFlags(u32);
+
+///
+/// **Description**
+///
+/// Information about a virtual memory space.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VmemInfo {
+    /// Number of pages that are mapped.
+    pub resident_pages: usize,
+    /// Union of the access permissions of pages that are mapped.
+    pub flags: PageFlags,
+    /// Lowest virtual address that may be mapped.
+    pub start: VirtualAddress,
+    /// Virtual address past the highest one that may be mapped.
+    pub end: VirtualAddress,
+}
+
+///
+/// **Description**
+///
+/// An owned virtual memory space.
+///
+/// **Notes**
+///
+/// - The virtual memory space is removed when it is dropped, unless the handle
+///   was obtained with [`Vmem::current`].
+/// - A virtual memory space is in use while pages that were mapped through
+///   its handle are still mapped. This includes pages of mappings that were
+///   forgotten with [`Mapping::forget`]. Such a virtual memory space is not
+///   removed: [`Vmem::remove`] fails, and dropping the handle panics in debug
+///   builds and leaves the virtual memory space in place in release builds.
+///
+#[derive(Debug)]
+pub struct Vmem {
+    /// Number of the virtual memory space.
+    id: VirtualMemory,
+    /// Is the virtual memory space removed when the handle is dropped?
+    owned: bool,
+    /// Lowest virtual address that may be mapped through this handle.
+    start: VirtualAddress,
+    /// Virtual address past the highest one that may be mapped through this
+    /// handle.
+    end: VirtualAddress,
+    /// Access permissions that pages mapped through this handle may take.
+    flags: PageFlags,
+    /// Number of pages that were mapped through this handle.
+    resident_pages: Cell<usize>,
+    /// Number of pages that were mapped through this handle, per permission.
+    flag_pages: Cell<[usize; PAGE_FLAGS_COUNT]>,
+}
+
+///
+/// **Description**
+///
+/// A builder that configures a virtual memory space before it is created.
+///
+/// **Notes**
+///
+/// - The [`KcallNumbers::VmemCreate`] kernel call takes no arguments, and the
+///   kernel lays out every virtual memory space the same way. For this reason,
+///   the region and the access permissions are enforced by the handle: pages
+///   outside of the region fail to be mapped with
+///   [`KcallError::InvalidArgument`], and permissions outside of the set fail
+///   with [`KcallError::AccessDenied`]. Reserving the region in the kernel
+///   requires the kernel call to take the base address and the size.
+/// - Invalid settings are reported when the virtual memory space is created,
+///   before the kernel is called.
+///
+/// **Example**
+///
+/// ```ignore
+/// let vmem: Vmem = VmemBuilder::new()
+///     .base(0x05000000)
+///     .size(16 * PAGE_SIZE as usize)
+///     .flags(PageFlags::READ | PageFlags::WRITE)
+///     .create()?;
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VmemBuilder {
+    /// Lowest virtual address that may be mapped.
+    base: VirtualAddress,
+    /// Size of the region that may be mapped (in bytes), if any.
+    size: Option<usize>,
+    /// Access permissions that pages may take.
+    flags: PageFlags,
+}
+
+///
+/// **Description**
+///
+/// Pages that are mapped into a virtual memory space.
+///
+/// **Notes**
+///
+/// - Pages are unmapped when the mapping is dropped.
+/// - A mapping that is created with [`Vmem::map`] spans a single page, and it
+///   borrows both the virtual memory space and the page frame, thus neither of
+///   them may go away while the page is mapped.
+/// - A mapping that is created with [`map_anonymous`] owns the page frames
+///   that back its pages, and it releases them when the pages are unmapped.
+/// - Pointers to mapped pages are only meaningful if the virtual memory space
+///   is the one of the calling process.
+///
+#[derive(Debug)]
+#[must_use = "pages are unmapped when the mapping is dropped"]
+pub struct Mapping<'a> {
+    /// Virtual memory space where pages are mapped.
+    vmem: &'a Vmem,
+    /// Virtual address of the first page.
+    vaddr: VirtualAddress,
+    /// Number of pages.
+    pages: usize,
+    /// Does the mapping own the page frames that back its pages?
+    anonymous: bool,
+    /// Page frame that backs the page.
+    frame: PhantomData<&'a Frame>,
+}
+
+//==============================================================================
+// Enumerations
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Control commands for virtual memory spaces.
+///
+/// **Notes**
+///
+/// - Commands should match the ones in `include/nanvix/kernel/kcall.h`. The
+///   kernel currently supports changing the access permissions of a page
+///   only.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VmemCommand {
+    /// Changes the access permissions of the page at a virtual address.
+    SetFlags(VirtualAddress, PageFlags),
+}
+
+///
+/// **Description**
+///
+/// Sizes of pages that may be requested when mapping a page frame.
+///
+/// **Notes**
+///
+/// - The kernel currently maps small pages only. See [`Vmem::map_sized`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PageSize {
+    /// Small page of 4 KiB.
+    #[default]
+    Size4K,
+    /// Huge page of 2 MiB.
+    Size2M,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl PageFlags {
+    /// Page is executable.
+    pub const EXEC: PageFlags = PageFlags(1 << 2);
+    /// Page is readable.
+    pub const READ: PageFlags = PageFlags(1 << 0);
+    /// Page is accessible from user mode.
+    pub const USER: PageFlags = PageFlags(1 << 3);
+    /// Page is writable.
+    pub const WRITE: PageFlags = PageFlags(1 << 1);
+
+    ///
+    /// **Description**
+    ///
+    /// Creates an empty set of page flags.
+    ///
+    /// **Return**
+    ///
+    /// An empty set of page flags is returned.
+    ///
+    pub const fn empty() -> Self {
+        PageFlags(0)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw bits of the target page flags.
+    ///
+    /// **Return**
+    ///
+    /// The raw bits of the target page flags are returned.
+    ///
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target page flags contain all flags in `other`.
+    ///
+    /// **Parameters**
+    /// - `other` - Flags to check.
+    ///
+    /// **Return**
+    ///
+    /// If all flags in `other` are set, `true` is returned. Otherwise, `false`
+    /// is returned instead.
+    ///
+    pub const fn contains(&self, other: PageFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl PageSize {
+    ///
+    /// **Description**
+    ///
+    /// Gets the size of the target page size in bytes.
+    ///
+    /// **Return**
+    ///
+    /// The number of bytes that a page of the target size spans.
+    ///
+    pub const fn bytes(&self) -> usize {
+        match self {
+            PageSize::Size4K => PAGE_SIZE as usize,
+            PageSize::Size2M => 2 * 1024 * 1024,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of small pages that a page of the target size spans.
+    ///
+    /// **Return**
+    ///
+    /// The number of pages of [`PAGE_SIZE`] bytes that a page of the target
+    /// size spans.
+    ///
+    pub const fn small_pages(&self) -> usize {
+        self.bytes() / PAGE_SIZE as usize
+    }
+}
+
+impl VmemCommand {
+    ///
+    /// **Description**
+    ///
+    /// Encodes the target command into the arguments of the control kernel
+    /// call.
+    ///
+    /// **Return**
+    ///
+    /// The command number, the target virtual address and the argument of the
+    /// command are returned, in that order.
+    ///
+    pub fn encode(&self) -> (u32, VirtualAddress, u32) {
+        match *self {
+            VmemCommand::SetFlags(vaddr, flags) => {
+                (VMEM_CHMOD, vaddr, AccessMode::from(flags).into())
+            },
+        }
+    }
+}
+
+impl Vmem {
+    ///
+    /// **Description**
+    ///
+    /// Creates a virtual memory space.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the created virtual memory space is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    pub fn create() -> Result<Self, KcallError> {
+        let id: VirtualMemory = memory::vmcreate();
+        if id == memory::NULL_VMEM {
+            return Err(KcallError::NoMemory);
+        }
+
+        Ok(Vmem {
+            id,
+            owned: true,
+            start: memory::USER_BASE_ADDRESS,
+            end: memory::USER_END_ADDRESS,
+            flags: PAGE_FLAGS_ALL,
+            resident_pages: Cell::new(0),
+            flag_pages: Cell::new([0; PAGE_FLAGS_COUNT]),
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets a handle to the virtual memory space of the calling process.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the virtual memory space of the
+    /// calling process is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The handle does not own the virtual memory space, thus dropping it
+    ///   leaves the virtual memory space in place, and [`Vmem::remove`] fails
+    ///   with [`KcallError::PermissionDenied`].
+    /// - Pages that are mapped through the handle may be accessed directly.
+    ///   See [`Mapping::bytes`].
+    ///
+    pub fn current() -> Result<Self, KcallError> {
+        let id: VirtualMemory = pm::process_info(Pid::SELF)?.vmem;
+
+        Ok(Vmem {
+            id,
+            owned: false,
+            start: memory::USER_BASE_ADDRESS,
+            end: memory::USER_END_ADDRESS,
+            flags: PAGE_FLAGS_ALL,
+            resident_pages: Cell::new(0),
+            flag_pages: Cell::new([0; PAGE_FLAGS_COUNT]),
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of the target virtual memory space.
+    ///
+    /// **Return**
+    ///
+    /// The number of the target virtual memory space is returned.
+    ///
+    pub fn id(&self) -> VirtualMemory {
+        self.id
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Duplicates the handle to the target virtual memory space.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a new handle to the same virtual memory
+    /// space is returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Handles are not [`Clone`], because each of them removes the virtual
+    ///   memory space when it is dropped.
+    /// - The kernel does not count references to virtual memory spaces, thus
+    ///   this function currently fails with [`KcallError::NotSupported`].
+    ///
+    pub fn try_clone(&self) -> Result<Self, KcallError> {
+        Err(KcallError::NotSupported)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target virtual memory space is in use.
+    ///
+    /// **Return**
+    ///
+    /// If pages that were mapped through the target handle are still mapped,
+    /// `true` is returned. Otherwise, `false` is returned instead.
+    ///
+    pub fn is_in_use(&self) -> bool {
+        self.resident_pages.get() != 0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Removes the target virtual memory space.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, the
+    /// handle to the virtual memory space is handed back along with an error.
+    /// If the virtual memory space is in use, it is not removed and
+    /// [`KcallError::Again`] is returned. If the handle does not own the
+    /// virtual memory space, [`KcallError::PermissionDenied`] is returned.
+    ///
+    pub fn remove(self) -> Result<(), (Self, KcallError)> {
+        if !self.owned {
+            return Err((self, KcallError::PermissionDenied));
+        }
+        if self.is_in_use() {
+            return Err((self, KcallError::Again));
+        }
+
+        let vmem: ManuallyDrop<Self> = ManuallyDrop::new(self);
+        match kcall::decode(memory::vmremove(vmem.id)) {
+            Ok(_) => Ok(()),
+            Err(e) => Err((ManuallyDrop::into_inner(vmem), e)),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Maps a page frame into the target virtual memory space.
+    ///
+    /// **Parameters**
+    /// - `vaddr` - Target virtual address.
+    /// - `frame` - Target page frame.
+    /// - `flags` - Access permissions for the page.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a mapping that unmaps the page when it is
+    /// dropped is returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Pages in user memory are always accessible from user mode, thus
+    ///   [`PageFlags::USER`] is implied.
+    /// - If the handle was built with a [`VmemBuilder`], pages outside of its
+    ///   region and permissions outside of its set are rejected.
+    ///
+    pub fn map<'a>(
+        &'a self,
+        vaddr: impl Into<VirtAddr>,
+        frame: &'a Frame,
+        flags: PageFlags,
+    ) -> Result<Mapping<'a>, KcallError> {
+        Ok(self.map_with_context(vaddr, frame, flags)?)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Maps a page frame into the target virtual memory space, reporting which
+    /// kernel call failed.
+    ///
+    /// **Parameters**
+    /// - `vaddr` - Target virtual address.
+    /// - `frame` - Target page frame.
+    /// - `flags` - Access permissions for the page.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a mapping that unmaps the page when it is
+    /// dropped is returned. Upon failure, an error is returned instead, along
+    /// with the kernel call that reported it.
+    ///
+    /// **Notes**
+    ///
+    /// - This behaves as [`Vmem::map`]. Invalid addresses and access
+    ///   permissions are reported as failures of [`KcallNumbers::VmemMap`],
+    ///   and failures to set access permissions as failures of
+    ///   [`KcallNumbers::VmemControl`].
+    ///
+    pub fn map_with_context<'a>(
+        &'a self,
+        vaddr: impl Into<VirtAddr>,
+        frame: &'a Frame,
+        flags: PageFlags,
+    ) -> Result<Mapping<'a>, KcallContextError> {
+        let vaddr: VirtualAddress =
+            check_vaddr(vaddr.into()).context(KcallNumbers::VmemMap)?;
+        self.check_region(vaddr, flags)
+            .context(KcallNumbers::VmemMap)?;
+
+        kcall::decode(memory::vmmap(self.id, vaddr, frame.number()))
+            .context(KcallNumbers::VmemMap)?;
+
+        // Set access permissions, undoing the mapping on failure. The page is
+        // accounted for below, thus bypass the bookkeeping of `control()`.
+        let (cmd, _, arg): (u32, VirtualAddress, u32) =
+            VmemCommand::SetFlags(vaddr, flags).encode();
+        if let Err(e) = unsafe { self.control_raw(cmd, vaddr, arg) } {
+            let _ = memory::vmunmap(self.id, vaddr);
+            return Err(KcallContextError::new(KcallNumbers::VmemControl, e));
+        }
+
+        self.resident_pages.set(self.resident_pages.get() + 1);
+        self.account(flags, true);
+
+        Ok(Mapping {
+            vmem: self,
+            vaddr,
+            pages: 1,
+            anonymous: false,
+            frame: PhantomData,
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Maps a page frame into the target virtual memory space with a page of
+    /// a given size.
+    ///
+    /// **Parameters**
+    /// - `vaddr` - Target virtual address.
+    /// - `frame` - Target page frame.
+    /// - `flags` - Access permissions for the page.
+    /// - `size` - Size of the page.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a mapping that unmaps the page when it is
+    /// dropped is returned. Upon failure, an error is returned instead. If the
+    /// kernel does not support pages of `size`, [`KcallError::NotSupported`]
+    /// is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The [`KcallNumbers::VmemMap`] kernel call takes no page size and
+    ///   always maps a single small page. Furthermore, 32-bit x86 paging
+    ///   without physical address extension has no 2 MiB pages. For these
+    ///   reasons, only [`PageSize::Size4K`] is supported, and other sizes fail
+    ///   before the kernel is called, so that no small page is mapped in place
+    ///   of a huge one.
+    ///
+    pub fn map_sized<'a>(
+        &'a self,
+        vaddr: impl Into<VirtAddr>,
+        frame: &'a Frame,
+        flags: PageFlags,
+        size: PageSize,
+    ) -> Result<Mapping<'a>, KcallError> {
+        match size {
+            PageSize::Size4K => self.map(vaddr, frame, flags),
+            PageSize::Size2M => Err(KcallError::NotSupported),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Unmaps a page from the target virtual memory space.
+    ///
+    /// **Parameters**
+    /// - `vaddr` - Virtual address of the target page.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the number of the page frame that was
+    /// mapped at `vaddr` is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn unmap(
+        &mut self,
+        vaddr: impl Into<VirtAddr>,
+    ) -> Result<FrameNumber, KcallError> {
+        let vaddr: VirtualAddress = check_vaddr(vaddr.into())?;
+        self.unmap_page(vaddr)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Issues a control command on the target virtual memory space.
+    ///
+    /// **Parameters**
+    /// - `command` - Control command.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the value returned by the kernel is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - When the access permissions of a page are changed, the permissions
+    ///   reported by [`Vmem::info`] are updated accordingly.
+    /// - Access permissions outside of the set of a handle that was built with
+    ///   a [`VmemBuilder`] are rejected with [`KcallError::AccessDenied`].
+    ///
+    pub fn control(&self, command: VmemCommand) -> Result<u32, KcallError> {
+        let (cmd, vaddr, arg): (u32, VirtualAddress, u32) = command.encode();
+
+        match command {
+            VmemCommand::SetFlags(_, flags) => {
+                self.check_region(vaddr, flags)?;
+
+                // Permissions may have changed since the page was mapped.
+                let old: PageFlags = self.page_info(vaddr)?.mode.into();
+
+                // SAFETY: the command was encoded from a valid `VmemCommand`.
+                let ret: u32 = unsafe { self.control_raw(cmd, vaddr, arg)? };
+
+                self.account(old, false);
+                self.account(flags | PageFlags::USER, true);
+
+                Ok(ret)
+            },
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Issues a raw control command on the target virtual memory space.
+    ///
+    /// **Parameters**
+    /// - `cmd` - Command number.
+    /// - `vaddr` - Target virtual address.
+    /// - `arg` - Argument of the command.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the value returned by the kernel is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Safety**
+    ///
+    /// - The caller must ensure that the command is valid, and that it does
+    ///   not invalidate the bookkeeping of mappings, which [`Vmem::control`]
+    ///   cannot check for commands that [`VmemCommand`] does not cover.
+    ///
+    pub unsafe fn control_raw(
+        &self,
+        cmd: u32,
+        vaddr: VirtualAddress,
+        arg: u32,
+    ) -> Result<u32, KcallError> {
+        kcall::kcall4_checked(
+            KcallNumbers::VmemControl as u32,
+            self.id as u32,
+            cmd,
+            vaddr,
+            arg,
+        )
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets information about a page of the target virtual memory space.
+    ///
+    /// **Parameters**
+    /// - `vaddr` - Virtual address of the target page.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, information about the target page is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    pub fn page_info(
+        &self,
+        vaddr: impl Into<VirtAddr>,
+    ) -> Result<PageInfo, KcallError> {
+        let vaddr: VirtualAddress = check_vaddr(vaddr.into())?;
+
+        let mut info: PageInfo = PageInfo::default();
+        kcall::decode(memory::vminfo(self.id, vaddr, &mut info))?;
+
+        Ok(info)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets information about the target virtual memory space.
+    ///
+    /// **Return**
+    ///
+    /// Information about the target virtual memory space is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel only reports information on individual pages, thus the
+    ///   number of resident pages and their access permissions account for
+    ///   pages mapped through this handle.
+    /// - The region that may be mapped is the one of the handle. See
+    ///   [`VmemBuilder`].
+    ///
+    pub fn info(&self) -> VmemInfo {
+        let mut flags: PageFlags = PageFlags::empty();
+        for (flag, count) in PAGE_FLAGS.iter().zip(self.flag_pages.get()) {
+            if count > 0 {
+                flags |= *flag;
+            }
+        }
+
+        VmemInfo {
+            resident_pages: self.resident_pages.get(),
+            flags,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Unmaps a page from the target virtual memory space.
+    ///
+    /// **Parameters**
+    /// - `vaddr` - Virtual address of the target page.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the number of the page frame that was
+    /// mapped at `vaddr` is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    fn unmap_page(
+        &self,
+        vaddr: VirtualAddress,
+    ) -> Result<FrameNumber, KcallError> {
+        // Permissions may have changed since the page was mapped.
+        let flags: PageFlags = match self.page_info(vaddr) {
+            Ok(info) => info.mode.into(),
+            Err(_) => PageFlags::empty(),
+        };
+
+        let frame: FrameNumber =
+            kcall::decode(memory::vmunmap(self.id, vaddr))?;
+
+        self.resident_pages
+            .set(self.resident_pages.get().saturating_sub(1));
+        self.account(flags, false);
+
+        Ok(frame)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if a page may be given access permissions through the target
+    /// handle.
+    ///
+    /// **Parameters**
+    /// - `vaddr` - Virtual address of the target page.
+    /// - `flags` - Access permissions for the page.
+    ///
+    /// **Return**
+    ///
+    /// If the page lies in the region of the handle and `flags` are in its set
+    /// of access permissions, empty is returned. Otherwise, an error is
+    /// returned instead.
+    ///
+    fn check_region(
+        &self,
+        vaddr: VirtualAddress,
+        flags: PageFlags,
+    ) -> Result<(), KcallError> {
+        if !(self.start..self.end).contains(&vaddr) {
+            return Err(KcallError::InvalidArgument);
+        }
+        if !self.flags.contains(flags) {
+            return Err(KcallError::AccessDenied);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Accounts for the access permissions of a page that was mapped or
+    /// unmapped.
+    ///
+    /// **Parameters**
+    /// - `flags` - Access permissions of the page.
+    /// - `mapped` - Was the page mapped?
+    ///
+    fn account(&self, flags: PageFlags, mapped: bool) {
+        let mut counts: [usize; PAGE_FLAGS_COUNT] = self.flag_pages.get();
+        for (flag, count) in PAGE_FLAGS.iter().zip(counts.iter_mut()) {
+            if flags.contains(*flag) {
+                *count = if mapped {
+                    *count + 1
+                } else {
+                    count.saturating_sub(1)
+                };
+            }
+        }
+        self.flag_pages.set(counts);
+    }
+}
+
+impl VmemBuilder {
+    ///
+    /// **Description**
+    ///
+    /// Creates a builder for a virtual memory space.
+    ///
+    /// **Return**
+    ///
+    /// A builder for a virtual memory space that spans user memory, and whose
+    /// pages may take any access permissions.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            base: memory::USER_BASE_ADDRESS,
+            size: None,
+            flags: PAGE_FLAGS_ALL,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the lowest virtual address that may be mapped.
+    ///
+    /// **Parameters**
+    /// - `addr` - Base address of the region. It must be page-aligned and lie
+    ///   in user memory.
+    ///
+    /// **Return**
+    ///
+    /// The updated builder.
+    ///
+    /// **Notes**
+    ///
+    /// - If no size is set, the region spans up to the end of user memory.
+    ///
+    pub fn base(mut self, addr: impl Into<VirtAddr>) -> Self {
+        self.base = addr.into().as_raw();
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the size of the region that may be mapped.
+    ///
+    /// **Parameters**
+    /// - `bytes` - Size of the region (in bytes). It must be a non-zero
+    ///   multiple of the page size, and the region must end in user memory.
+    ///
+    /// **Return**
+    ///
+    /// The updated builder.
+    ///
+    pub fn size(mut self, bytes: usize) -> Self {
+        self.size = Some(bytes);
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the access permissions that pages may take.
+    ///
+    /// **Parameters**
+    /// - `flags` - Access permissions. [`PageFlags::USER`] is implied.
+    ///
+    /// **Return**
+    ///
+    /// The updated builder.
+    ///
+    pub fn flags(mut self, flags: PageFlags) -> Self {
+        self.flags = flags | PageFlags::USER;
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates the virtual memory space.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the created virtual memory space is
+    /// returned. Upon failure, an error is returned instead. If the base
+    /// address is not page-aligned or lies outside of user memory, or if the
+    /// size is not a non-zero multiple of the page size or the region does not
+    /// end in user memory, [`KcallError::InvalidArgument`] is returned.
+    ///
+    pub fn create(&self) -> Result<Vmem, KcallError> {
+        let start: VirtualAddress = check_vaddr(VirtAddr::new(self.base))?;
+        let end: VirtualAddress = match self.size {
+            None => memory::USER_END_ADDRESS,
+            Some(size) => u32::try_from(size)
+                .ok()
+                .filter(|size| *size != 0 && size % PAGE_SIZE == 0)
+                .and_then(|size| start.checked_add(size))
+                .filter(|end| *end <= memory::USER_END_ADDRESS)
+                .ok_or(KcallError::InvalidArgument)?,
+        };
+
+        let mut vmem: Vmem = Vmem::create()?;
+        vmem.start = start;
+        vmem.end = end;
+        vmem.flags = self.flags;
+
+        Ok(vmem)
+    }
+}
+
+impl<'a> Mapping<'a> {
+    ///
+    /// **Description**
+    ///
+    /// Gets the virtual address of the target mapping.
+    ///
+    /// **Return**
+    ///
+    /// The virtual address of the first mapped page is returned.
+    ///
+    pub fn vaddr(&self) -> VirtualAddress {
+        self.vaddr
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the size of the target mapping.
+    ///
+    /// **Return**
+    ///
+    /// The size of the mapped region (in bytes) is returned.
+    ///
+    pub fn len(&self) -> usize {
+        self.pages * PAGE_SIZE as usize
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target mapping is empty.
+    ///
+    /// **Return**
+    ///
+    /// If the target mapping spans no pages, `true` is returned. Otherwise,
+    /// `false` is returned instead. Mappings that are handed out always span
+    /// at least a page.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.pages == 0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets a pointer to the first byte of the target mapping.
+    ///
+    /// **Return**
+    ///
+    /// A pointer to the first byte of the first mapped page is returned.
+    ///
+    pub fn as_ptr(&self) -> *const u8 {
+        self.vaddr as *const u8
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets a mutable pointer to the first byte of the target mapping.
+    ///
+    /// **Return**
+    ///
+    /// A mutable pointer to the first byte of the first mapped page is
+    /// returned.
+    ///
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.vaddr as *mut u8
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the target mapping as a byte slice.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a slice that spans all mapped pages is
+    /// returned. Upon failure, an error is returned instead. If the pages are
+    /// mapped into the virtual memory space of another process,
+    /// [`KcallError::BadAddress`] is returned. If some page may not be read,
+    /// [`KcallError::AccessDenied`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - Access permissions are queried from the kernel on each call, thus
+    ///   permissions that are changed while the slice is borrowed are not
+    ///   noticed.
+    ///
+    pub fn bytes(&self) -> Result<&[u8], KcallError> {
+        self.check_access(false)?;
+        Ok(unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the target mapping as a mutable byte slice.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a slice that spans all mapped pages is
+    /// returned. Upon failure, an error is returned instead. If the pages are
+    /// mapped into the virtual memory space of another process,
+    /// [`KcallError::BadAddress`] is returned. If some page may not be
+    /// written, [`KcallError::AccessDenied`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The same restrictions as in [`Mapping::bytes`] apply.
+    ///
+    pub fn bytes_mut(&mut self) -> Result<&mut [u8], KcallError> {
+        self.check_access(true)?;
+        Ok(unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets a byte or a range of bytes of the target mapping.
+    ///
+    /// **Parameters**
+    /// - `index` - Offset or range of offsets into the mapping.
+    ///
+    /// **Return**
+    ///
+    /// If `index` is in bounds and the mapping may be read, the bytes are
+    /// returned. Otherwise, `None` is returned instead.
+    ///
+    /// **Example**
+    ///
+    /// ```ignore
+    /// let header: &[u8] = mapping.get(..HEADER_SIZE).ok_or(KcallError::InvalidArgument)?;
+    /// ```
+    ///
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[u8]>,
+    {
+        self.bytes().ok()?.get(index)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets a mutable byte or a mutable range of bytes of the target mapping.
+    ///
+    /// **Parameters**
+    /// - `index` - Offset or range of offsets into the mapping.
+    ///
+    /// **Return**
+    ///
+    /// If `index` is in bounds and the mapping may be written, the bytes are
+    /// returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[u8]>,
+    {
+        self.bytes_mut().ok()?.get_mut(index)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Unmaps the target mapping.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the number of the page frame that was
+    /// mapped at the first page is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - All pages are unmapped, even if unmapping a previous one fails. In
+    ///   that case, the first error is returned.
+    /// - Page frames that are owned by the mapping are released.
+    ///
+    pub fn unmap(self) -> Result<FrameNumber, KcallError> {
+        let mapping: ManuallyDrop<Self> = ManuallyDrop::new(self);
+        mapping.release()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target mapping without unmapping the page.
+    ///
+    /// **Return**
+    ///
+    /// The virtual address of the first page is returned. Pages stay mapped
+    /// until they are unmapped with [`Vmem::unmap`], and page frames that
+    /// are owned by the mapping are leaked.
+    ///
+    pub fn forget(self) -> VirtualAddress {
+        let vaddr: VirtualAddress = self.vaddr;
+        mem::forget(self);
+        vaddr
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the calling process may access all pages of the target
+    /// mapping.
+    ///
+    /// **Parameters**
+    /// - `write` - Check for write access, rather than for read access?
+    ///
+    /// **Return**
+    ///
+    /// If the pages may be accessed, empty is returned. Otherwise, an error is
+    /// returned instead.
+    ///
+    fn check_access(&self, write: bool) -> Result<(), KcallError> {
+        if pm::process_info(Pid::SELF)?.vmem != self.vmem.id {
+            return Err(KcallError::BadAddress);
+        }
+
+        for i in 0..self.pages {
+            let vaddr: VirtualAddress = self.vaddr + (i as u32) * PAGE_SIZE;
+            let mode: AccessMode = self.vmem.page_info(vaddr)?.mode;
+            let allowed: bool = if write { mode.write() } else { mode.read() };
+            if !allowed {
+                return Err(KcallError::AccessDenied);
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Unmaps all pages of the target mapping and releases the page frames that
+    /// it owns.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the number of the page frame that was
+    /// mapped at the first page is returned. Upon failure, the first error is
+    /// returned instead.
+    ///
+    fn release(&self) -> Result<FrameNumber, KcallError> {
+        let mut first: Option<FrameNumber> = None;
+        let mut error: Option<KcallError> = None;
+
+        for i in 0..self.pages {
+            let vaddr: VirtualAddress = self.vaddr + (i as u32) * PAGE_SIZE;
+            match self.vmem.unmap_page(vaddr) {
+                Ok(frame) => {
+                    if self.anonymous {
+                        let _ = memory::frfree(frame);
+                    }
+                    if i == 0 {
+                        first = Some(frame);
+                    }
+                },
+                Err(e) => {
+                    error.get_or_insert(e);
+                },
+            }
+        }
+
+        match (error, first) {
+            (Some(e), _) => Err(e),
+            (None, Some(frame)) => Ok(frame),
+            (None, None) => Err(KcallError::InvalidArgument),
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Default for VmemBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BitOr for PageFlags {
+    type Output = PageFlags;
+
+    fn bitor(self, rhs: PageFlags) -> PageFlags {
+        PageFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for PageFlags {
+    fn bitor_assign(&mut self, rhs: PageFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<AccessMode> for PageFlags {
+    fn from(mode: AccessMode) -> PageFlags {
+        let mut flags: PageFlags = PageFlags::USER;
+        if mode.read() {
+            flags |= PageFlags::READ;
+        }
+        if mode.write() {
+            flags |= PageFlags::WRITE;
+        }
+        if mode.exec() {
+            flags |= PageFlags::EXEC;
+        }
+        flags
+    }
+}
+
+impl From<PageFlags> for AccessMode {
+    fn from(flags: PageFlags) -> AccessMode {
+        AccessMode::new(
+            flags.contains(PageFlags::READ),
+            flags.contains(PageFlags::WRITE),
+            flags.contains(PageFlags::EXEC),
+        )
+    }
+}
+
+impl<'a> Drop for Mapping<'a> {
+    fn drop(&mut self) {
+        let _ = self.release();
+    }
+}
+
+impl Drop for Vmem {
+    fn drop(&mut self) {
+        // Removing a virtual memory space that is in use is a bug.
+        debug_assert!(
+            !self.is_in_use(),
+            "dropped virtual memory space {} with {} mapped pages",
+            self.id,
+            self.resident_pages.get()
+        );
+
+        if self.owned && !self.is_in_use() {
+            let _ = memory::vmremove(self.id);
+        }
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Maps fresh page frames into a virtual memory space.
+///
+/// **Parameters**
+/// - `vmem` - Target virtual memory space.
+/// - `vaddr` - Virtual address of the first page.
+/// - `len` - Size of the region to map (in bytes). It is rounded up to a
+///   multiple of the page size.
+/// - `flags` - Access permissions for the pages.
+///
+/// **Return**
+///
+/// Upon successful completion, a mapping that unmaps the pages and releases
+/// their page frames when it is dropped is returned. Upon failure, an error is
+/// returned instead.
+///
+/// **Notes**
+///
+/// - Page frames are allocated and mapped one page at a time. If any step
+///   fails, pages that were mapped so far are unmapped and all page frames
+///   that were allocated are released, before the error is returned.
+/// - Page frames are not cleared.
+///
+pub fn map_anonymous(
+    vmem: &Vmem,
+    vaddr: impl Into<VirtAddr>,
+    len: usize,
+    flags: PageFlags,
+) -> Result<Mapping, KcallError> {
+    let vaddr: VirtAddr = vaddr.into();
+    let pages: usize = len.div_ceil(PAGE_SIZE as usize);
+
+    // Check if the whole region lies in user memory.
+    if pages == 0 {
+        return Err(KcallError::InvalidArgument);
+    }
+    let last: VirtAddr = u32::try_from(pages - 1)
+        .ok()
+        .and_then(|n| n.checked_mul(PAGE_SIZE))
+        .and_then(|offset| vaddr.offset(offset))
+        .ok_or(KcallError::InvalidArgument)?;
+    let vaddr: VirtualAddress = check_vaddr(vaddr)?;
+    check_vaddr(last)?;
+
+    // Pages that were mapped so far, which are released on failure.
+    let mut mapping: Mapping = Mapping {
+        vmem,
+        vaddr,
+        pages: 0,
+        anonymous: true,
+        frame: PhantomData,
+    };
+
+    for i in 0..pages {
+        let frame: Frame = Frame::alloc()?;
+        let page: VirtualAddress = vaddr + (i as u32) * PAGE_SIZE;
+        vmem.map(page, &frame, flags)?.forget();
+
+        // The page frame now belongs to the mapping.
+        frame.leak();
+        mapping.pages += 1;
+    }
+
+    Ok(mapping)
+}
+
+///
+/// **Description**
+///
+/// Checks if a virtual address lies in a region of user memory that this
+/// library reserves for itself.
+///
+/// **Parameters**
+/// - `vaddr` - Target virtual address.
+///
+/// **Return**
+///
+/// If `vaddr` lies in a reserved region, `true` is returned. Otherwise, `false`
+/// is returned instead.
+///
+/// **Notes**
+///
+/// - The reserved regions are the one where the control blocks of
+///   [`crate::sync::Arc`] are mapped and the one where the stacks that a
+///   [`crate::thread::Builder`] allocates are mapped. Mappings there are
+///   rejected.
+///
+pub fn is_reserved(vaddr: VirtualAddress) -> bool {
+    (memory::ARC_REGION_BASE_ADDRESS..memory::ARC_REGION_END_ADDRESS)
+        .contains(&vaddr)
+        || (memory::THREAD_STACKS_REGION_BASE_ADDRESS
+            ..memory::THREAD_STACKS_REGION_END_ADDRESS)
+            .contains(&vaddr)
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Checks if a virtual address is page-aligned and lies in user memory that is
+/// not reserved. See [`is_reserved`].
+///
+/// **Parameters**
+/// - `vaddr` - Target virtual address.
+///
+/// **Return**
+///
+/// If `vaddr` is valid, its raw value is returned. Otherwise, an error is
+/// returned instead.
+///
+fn check_vaddr(vaddr: VirtAddr) -> Result<VirtualAddress, KcallError> {
+    if !vaddr.is_aligned(memory::PAGE_SIZE)
+        || !(memory::USER_BASE_ADDRESS..memory::USER_END_ADDRESS)
+            .contains(&vaddr.as_raw())
+        || is_reserved(vaddr.as_raw())
+    {
+        return Err(KcallError::InvalidArgument);
+    }
+
+    Ok(vaddr.as_raw())
+}
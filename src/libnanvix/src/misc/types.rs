@@ -28,6 +28,71 @@ pub struct KernelModule {
     pub cmdline: [u8; KMOD_CMDLINE_MAX],
 }
 
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl KernelModule {
+    ///
+    /// **Description**
+    ///
+    /// Gets the name of the kernel module.
+    ///
+    /// **Return**
+    ///
+    /// The command line of the kernel module, up to its first null byte. If
+    /// the command line is not valid UTF-8, an empty string is returned.
+    ///
+    pub fn name(&self) -> &str {
+        let len: usize = self
+            .cmdline
+            .iter()
+            .position(|b| *b == 0)
+            .unwrap_or(KMOD_CMDLINE_MAX);
+        core::str::from_utf8(&self.cmdline[..len]).unwrap_or("")
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the size of the kernel module.
+    ///
+    /// **Return**
+    ///
+    /// The size of the kernel module in bytes.
+    ///
+    pub fn size(&self) -> usize {
+        self.end.saturating_sub(self.start) as usize
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the image of the kernel module.
+    ///
+    /// **Return**
+    ///
+    /// The bytes of the kernel module, which may be handed over to
+    /// [`crate::pm::spawn`].
+    ///
+    /// **Notes**
+    ///
+    /// - Kernel modules are loaded by the boot loader and stay at the same
+    ///   address for the lifetime of the system.
+    ///
+    /// # Safety
+    ///
+    /// - The addresses of the kernel module are taken as is, and they are
+    ///   physical addresses. The caller must ensure that they describe a
+    ///   kernel module that was reported by the kernel, such as one from
+    ///   [`crate::pm::kmods`], and that its bytes are readable from the
+    ///   calling process.
+    ///
+    pub unsafe fn image(&self) -> &[u8] {
+        core::slice::from_raw_parts(self.start as *const u8, self.size())
+    }
+}
+
 //==============================================================================
 // Trait Implementations
 //==============================================================================
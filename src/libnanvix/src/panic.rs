@@ -0,0 +1,149 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    io::{
+        self,
+        Fd,
+    },
+    pm::{
+        self,
+        ShutdownReason,
+    },
+};
+use core::{
+    fmt::{
+        self,
+        Write,
+    },
+    panic::PanicInfo,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum length of a panic report (in bytes).
+pub const PANIC_REPORT_MAX: usize = 256;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A writer that formats into a fixed-size buffer, truncating the output that
+/// does not fit.
+///
+struct Report<'a> {
+    /// Underlying buffer.
+    buf: &'a mut [u8],
+    /// Number of bytes written.
+    len: usize,
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl<'a> fmt::Write for Report<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let avail: usize = self.buf.len() - self.len;
+        let mut n: usize = s.len().min(avail);
+
+        // Do not split a character.
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+
+        self.buf[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+
+        if n < s.len() {
+            return Err(fmt::Error);
+        }
+
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Formats a panic report.
+///
+/// **Parameters**
+/// - `buf` - Storage location for the report.
+/// - `file` - File where the panic occurred.
+/// - `line` - Line where the panic occurred.
+/// - `msg` - Panic message.
+///
+/// **Return**
+///
+/// The formatted report is returned. It is truncated if it does not fit in
+/// `buf`.
+///
+pub fn format_report<'a>(
+    buf: &'a mut [u8],
+    file: &str,
+    line: u32,
+    msg: &dyn fmt::Display,
+) -> &'a str {
+    let mut report: Report = Report { buf, len: 0 };
+    let _ = writeln!(
+        &mut report,
+        "PANIC file='{}', line={} :: {}",
+        file, line, msg
+    );
+
+    let Report { buf, len } = report;
+    // Only whole characters are written, thus this never fails.
+    core::str::from_utf8(&buf[..len]).unwrap_or("")
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Reports a panic to the standard error and shuts down the system.
+///
+/// **Parameters**
+/// - `info` - Information about the panic.
+///
+#[panic_handler]
+fn panic_implementation(info: &PanicInfo) -> ! {
+    let (file, line) = match info.location() {
+        Some(loc) => (loc.file(), loc.line()),
+        None => ("", 0),
+    };
+
+    let mut buf: [u8; PANIC_REPORT_MAX] = [0; PANIC_REPORT_MAX];
+    let report: &str = if let Some(m) = info.message() {
+        format_report(&mut buf, file, line, m)
+    } else if let Some(m) = info.payload().downcast_ref::<&str>() {
+        format_report(&mut buf, file, line, m)
+    } else {
+        format_report(&mut buf, file, line, &"?")
+    };
+
+    // Write report, one chunk at a time.
+    for chunk in report.as_bytes().chunks(io::WRITE_MAX) {
+        let _ = io::write(Fd::STDERR, chunk);
+    }
+
+    pm::shutdown(ShutdownReason::Panic)
+}
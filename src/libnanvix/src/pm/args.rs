@@ -0,0 +1,271 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallError,
+    pm::{
+        ARG_BLOCK_SIZE,
+        ENV_VARS_MAX,
+        SPAWN_ARGS_MAX,
+    },
+};
+use core::mem;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Storage of an argument block, aligned to hold pointers.
+///
+#[repr(C, align(4))]
+struct Buffer([u8; ARG_BLOCK_SIZE]);
+
+///
+/// **Description**
+///
+/// Arguments and environment variables of a process, laid out contiguously.
+///
+/// **Notes**
+///
+/// - Once packed, the block starts with a table of pointers that follows the C
+///   convention: the pointers of the arguments (`argv`) and a null pointer,
+///   followed by the pointers of the environment variables (`envp`) and a null
+///   pointer. Strings are null-terminated and stored at the end of the block.
+/// - Strings that have interior null bytes are rejected.
+///
+pub struct ArgBlock {
+    /// Underlying storage.
+    buf: Buffer,
+    /// Offsets of arguments.
+    args: [usize; SPAWN_ARGS_MAX],
+    /// Number of arguments.
+    argc: usize,
+    /// Offsets of environment variables.
+    envs: [usize; ENV_VARS_MAX],
+    /// Number of environment variables.
+    envc: usize,
+    /// Offset of the first byte of strings.
+    bottom: usize,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl ArgBlock {
+    ///
+    /// **Description**
+    ///
+    /// Creates an empty argument block.
+    ///
+    /// **Return**
+    ///
+    /// An argument block that has no arguments and no environment variables.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            buf: Buffer([0; ARG_BLOCK_SIZE]),
+            args: [0; SPAWN_ARGS_MAX],
+            argc: 0,
+            envs: [0; ENV_VARS_MAX],
+            envc: 0,
+            bottom: ARG_BLOCK_SIZE,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends an argument.
+    ///
+    /// **Parameters**
+    /// - `arg` - Argument.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the target argument block is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn arg(&mut self, arg: &str) -> Result<&mut Self, KcallError> {
+        if self.argc == SPAWN_ARGS_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let offset: usize = self.push(&[arg.as_bytes()])?;
+        self.args[self.argc] = offset;
+        self.argc += 1;
+
+        Ok(self)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends an environment variable.
+    ///
+    /// **Parameters**
+    /// - `key` - Name of the variable.
+    /// - `value` - Value of the variable.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the target argument block is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn env(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<&mut Self, KcallError> {
+        if self.envc == ENV_VARS_MAX || key.is_empty() || key.contains('=') {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let offset: usize =
+            self.push(&[key.as_bytes(), b"=", value.as_bytes()])?;
+        self.envs[self.envc] = offset;
+        self.envc += 1;
+
+        Ok(self)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of arguments.
+    ///
+    /// **Return**
+    ///
+    /// The number of arguments in the target argument block.
+    ///
+    pub fn argc(&self) -> usize {
+        self.argc
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of environment variables.
+    ///
+    /// **Return**
+    ///
+    /// The number of environment variables in the target argument block.
+    ///
+    pub fn envc(&self) -> usize {
+        self.envc
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Lays out the table of pointers of the target argument block.
+    ///
+    /// **Return**
+    ///
+    /// The bytes of the argument block, whose pointer and length are handed
+    /// over to the kernel.
+    ///
+    /// **Notes**
+    ///
+    /// - Pointers in the table are absolute, thus they are only valid while
+    ///   the returned slice is borrowed.
+    ///
+    pub fn pack(&mut self) -> &[u8] {
+        let base: usize = self.buf.0.as_ptr() as usize;
+        let mut slot: usize = 0;
+
+        for offset in self.args[..self.argc].iter() {
+            write_word(&mut self.buf.0, slot, base + offset);
+            slot += 1;
+        }
+        write_word(&mut self.buf.0, slot, 0);
+        slot += 1;
+
+        for offset in self.envs[..self.envc].iter() {
+            write_word(&mut self.buf.0, slot, base + offset);
+            slot += 1;
+        }
+        write_word(&mut self.buf.0, slot, 0);
+
+        &self.buf.0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stores a null-terminated string at the bottom of the string area.
+    ///
+    /// **Parameters**
+    /// - `parts` - Parts of the string, which are concatenated.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the offset of the string is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    fn push(&mut self, parts: &[&[u8]]) -> Result<usize, KcallError> {
+        let len: usize = parts.iter().map(|part| part.len()).sum::<usize>() + 1;
+
+        // Reject interior null bytes.
+        if parts.iter().any(|part| part.contains(&0)) {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        // Check if the string and the pointer table fit, accounting for the
+        // new entry and both null terminators of the table.
+        let table: usize = (self.argc + self.envc + 3) * mem::size_of::<u32>();
+        let offset: usize = match self.bottom.checked_sub(len) {
+            Some(offset) if offset >= table => offset,
+            _ => return Err(KcallError::InvalidArgument),
+        };
+
+        let mut cursor: usize = offset;
+        for part in parts.iter() {
+            self.buf.0[cursor..cursor + part.len()].copy_from_slice(part);
+            cursor += part.len();
+        }
+        self.buf.0[cursor] = 0;
+        self.bottom = offset;
+
+        Ok(offset)
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Default for ArgBlock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Writes a pointer into the table of an argument block.
+///
+/// **Parameters**
+/// - `buf` - Storage of the argument block.
+/// - `slot` - Index of the target entry in the table.
+/// - `ptr` - Pointer to write.
+///
+fn write_word(buf: &mut [u8], slot: usize, ptr: usize) {
+    let size: usize = mem::size_of::<u32>();
+    buf[slot * size..(slot + 1) * size]
+        .copy_from_slice(&(ptr as u32).to_ne_bytes());
+}
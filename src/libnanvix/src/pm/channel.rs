@@ -0,0 +1,378 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    ipc::{
+        Receiver,
+        Sender,
+        CHANNEL_TAG_MAX,
+    },
+    kcall::KcallError,
+    pm::{
+        self,
+        kcall::SPAWN_FORWARDS_ARGS,
+        Pid,
+        SPAWN_ARGS_MAX,
+        SPAWN_CHANNEL_ARG,
+        SPAWN_CHANNEL_ARG_MAX,
+    },
+};
+use core::{
+    fmt::{
+        self,
+        Write,
+    },
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// First tag that is handed out to channels of spawned processes.
+const SPAWN_CHANNEL_TAG_BASE: u32 = 0x8000;
+
+/// Number of pairs of tags that are handed out to channels of spawned
+/// processes.
+const SPAWN_CHANNEL_TAGS: u32 =
+    (CHANNEL_TAG_MAX - SPAWN_CHANNEL_TAG_BASE + 1) / 2;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Number of channels that were handed out to spawned processes.
+static NEXT_CHANNEL: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Names of the two channels that connect a parent process to a child.
+///
+/// **Notes**
+///
+/// - A spawn channel is made of a parent process and a tag. Messages from the
+///   child to the parent flow through the channel that the parent owns with
+///   that tag, and messages from the parent to the child flow through the
+///   channel that the child owns with the next tag.
+/// - The child finds its ends in the argument that starts with
+///   [`SPAWN_CHANNEL_ARG`], which is followed by the PID of the parent and the
+///   tag, separated by a colon (e.g. `nanvix.channel=3:32768`).
+///
+/// **Example**
+///
+/// ```ignore
+/// // In the child process.
+/// let channel: SpawnChannel = SpawnChannel::find(args).ok_or(err)?;
+/// let (tx, rx): (Sender, Receiver) = channel.connect()?;
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpawnChannel {
+    /// Parent process.
+    parent: Pid,
+    /// Tag of the channel that the parent owns.
+    tag: u32,
+}
+
+///
+/// **Description**
+///
+/// A writer that formats into a fixed-size buffer.
+///
+pub(super) struct ArgWriter<'a> {
+    /// Underlying buffer.
+    pub(super) buf: &'a mut [u8],
+    /// Number of bytes written.
+    pub(super) len: usize,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl SpawnChannel {
+    ///
+    /// **Description**
+    ///
+    /// Creates the names of a spawn channel.
+    ///
+    /// **Parameters**
+    /// - `parent` - Parent process.
+    /// - `tag` - Tag of the channel that the parent owns.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the names of the spawn channel are
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The tag of the channel that the child owns is `tag + 1`, thus `tag`
+    ///   must be less than [`CHANNEL_TAG_MAX`].
+    ///
+    pub fn new(parent: Pid, tag: u32) -> Result<Self, KcallError> {
+        if tag >= CHANNEL_TAG_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        Ok(Self { parent, tag })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the parent process of the target spawn channel.
+    ///
+    /// **Return**
+    ///
+    /// The PID of the parent process.
+    ///
+    pub fn parent(&self) -> Pid {
+        self.parent
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the tag of the channel that the parent owns.
+    ///
+    /// **Return**
+    ///
+    /// The tag of the channel that the parent owns.
+    ///
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Encodes the target spawn channel as an argument for the child.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for the argument.
+    ///
+    /// **Return**
+    ///
+    /// The argument that names the target spawn channel.
+    ///
+    pub fn encode<'a>(
+        &self,
+        buf: &'a mut [u8; SPAWN_CHANNEL_ARG_MAX],
+    ) -> &'a str {
+        let mut writer: ArgWriter = ArgWriter { buf, len: 0 };
+        // The longest argument fits in the buffer, thus this never fails.
+        let _ = write!(
+            &mut writer,
+            "{}{}:{}",
+            SPAWN_CHANNEL_ARG, self.parent, self.tag
+        );
+
+        let ArgWriter { buf, len } = writer;
+        core::str::from_utf8(&buf[..len]).unwrap_or("")
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Decodes a spawn channel from an argument.
+    ///
+    /// **Parameters**
+    /// - `arg` - Argument to decode.
+    ///
+    /// **Return**
+    ///
+    /// If `arg` names a spawn channel, the spawn channel is returned.
+    /// Otherwise, `None` is returned instead.
+    ///
+    pub fn parse(arg: &str) -> Option<Self> {
+        let (parent, tag): (&str, &str) =
+            arg.strip_prefix(SPAWN_CHANNEL_ARG)?.split_once(':')?;
+        let parent: i32 = parent.parse().ok()?;
+        let tag: u32 = tag.parse().ok()?;
+
+        Self::new(Pid::new(parent), tag).ok()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Finds the spawn channel in the arguments of a process.
+    ///
+    /// **Parameters**
+    /// - `args` - Arguments of the process.
+    ///
+    /// **Return**
+    ///
+    /// If one of `args` names a spawn channel, the spawn channel is returned.
+    /// Otherwise, `None` is returned instead.
+    ///
+    pub fn find(args: &[&str]) -> Option<Self> {
+        args.iter().find_map(|arg| Self::parse(arg))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates the receiving end of the parent.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the receiving end of the parent is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - This function must be called by the parent process before the child
+    ///   connects.
+    ///
+    pub fn listen(&self) -> Result<Receiver, KcallError> {
+        Receiver::create(self.tag)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Opens the sending end of the parent.
+    ///
+    /// **Parameters**
+    /// - `child` - Child process.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the sending end of the parent is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The channel of the child is created on its behalf, thus the parent
+    ///   may send messages before the child connects.
+    ///
+    pub fn accept(&self, child: Pid) -> Result<Sender, KcallError> {
+        Sender::create_for(child, self.tag + 1)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Connects the child to the target spawn channel.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the sending and receiving ends of the
+    /// child are returned. Upon failure, an error is returned instead.
+    ///
+    pub fn connect(&self) -> Result<(Sender, Receiver), KcallError> {
+        let rx: Receiver = Receiver::attach(self.tag + 1)?;
+        let tx: Sender = Sender::open(self.parent, self.tag)?;
+        Ok((tx, rx))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Hands out a spawn channel for the calling process.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a spawn channel whose parent is the calling
+    /// process is returned. Upon failure, an error is returned instead.
+    ///
+    fn allocate() -> Result<Self, KcallError> {
+        let parent: Pid = pm::process_info(Pid::SELF)?.pid;
+        let index: u32 =
+            NEXT_CHANNEL.fetch_add(1, Ordering::Relaxed) % SPAWN_CHANNEL_TAGS;
+        Self::new(parent, SPAWN_CHANNEL_TAG_BASE + 2 * index)
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl<'a> fmt::Write for ArgWriter<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end: usize = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Spawns a new process that is connected to the calling process by a pair of
+/// channels.
+///
+/// **Parameters**
+/// - `image` - Image of the target process.
+/// - `args` - Arguments for the target process.
+///
+/// **Return**
+///
+/// Upon successful completion, the PID of the spawned process and the sending
+/// and receiving ends of the calling process are returned. Upon failure, an
+/// error is returned instead. Until the kernel forwards arguments to spawned
+/// processes, [`KcallError::NotSupported`] is returned and no process is
+/// spawned.
+///
+/// **Notes**
+///
+/// - An argument that names the channels is appended to `args`, thus at most
+///   `SPAWN_ARGS_MAX - 1` arguments may be given. The child finds its ends
+///   with [`SpawnChannel::find`] and [`SpawnChannel::connect`].
+/// - The kernel currently ignores the arguments that are handed over to it,
+///   thus the child would have no means to find its ends.
+///
+pub fn spawn_with_channel(
+    image: &[u8],
+    args: &[&str],
+) -> Result<(Pid, Sender, Receiver), KcallError> {
+    // Check for too many arguments.
+    if args.len() >= SPAWN_ARGS_MAX {
+        return Err(KcallError::InvalidArgument);
+    }
+
+    // Check if the child may find its ends.
+    if !SPAWN_FORWARDS_ARGS {
+        return Err(KcallError::NotSupported);
+    }
+
+    let channel: SpawnChannel = SpawnChannel::allocate()?;
+    let rx: Receiver = channel.listen()?;
+
+    // Append the argument that names the channels.
+    let mut buf: [u8; SPAWN_CHANNEL_ARG_MAX] = [0; SPAWN_CHANNEL_ARG_MAX];
+    let mut argv: [&str; SPAWN_ARGS_MAX] = [""; SPAWN_ARGS_MAX];
+    argv[..args.len()].copy_from_slice(args);
+    argv[args.len()] = channel.encode(&mut buf);
+
+    let pid: Pid = pm::spawn(image, &argv[..=args.len()])?;
+    let tx: Sender = channel.accept(pid)?;
+
+    Ok((pid, tx, rx))
+}
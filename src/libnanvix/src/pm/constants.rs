@@ -14,4 +14,50 @@ use super::Pid;
 //==============================================================================
 
 /// Special PID for a process to refer to itself.
-pub const PID_SELF: Pid = -1;
+#[deprecated(note = "use `Pid::SELF` instead")]
+pub const PID_SELF: Pid = Pid::SELF;
+
+/// Maximum number of processes in the system.
+pub const PROCESS_MAX: usize = 16;
+
+/// Maximum number of threads in the system.
+pub const THREADS_MAX: usize = 16;
+
+/// Maximum number of arguments that may be passed to a spawned process.
+pub const SPAWN_ARGS_MAX: usize = 16;
+
+/// Maximum number of environment variables that may be passed to a spawned
+/// process.
+pub const ENV_VARS_MAX: usize = 16;
+
+/// Size of an argument block (in bytes).
+pub const ARG_BLOCK_SIZE: usize = 1024;
+
+/// Size of the `struct process_info` structure in the kernel space.
+pub const PROCESS_INFO_SIZE: usize = 12;
+
+/// Bit of an exit status word that is set if the thread was terminated by an
+/// exception.
+pub const EXIT_STATUS_FAULTED: u32 = 1 << 8;
+
+/// Bits of an exit status word that hold the exit code or the exception vector.
+pub const EXIT_STATUS_VALUE_MASK: u32 = 0xff;
+
+/// Prefix of the argument that names the channel of a process spawned with
+/// [`crate::pm::spawn_with_channel`].
+pub const SPAWN_CHANNEL_ARG: &str = "nanvix.channel=";
+
+/// Maximum length of the argument that names the channel of a spawned process
+/// (in bytes).
+pub const SPAWN_CHANNEL_ARG_MAX: usize = 32;
+
+/// Prefix of the arguments that hold the environment variables of a process
+/// spawned with [`crate::pm::ProcessBuilder`].
+pub const SPAWN_ENV_ARG: &str = "nanvix.env=";
+
+/// Prefix of the arguments that name the mailboxes that a process spawned with
+/// [`crate::pm::ProcessBuilder`] inherits.
+pub const SPAWN_MAILBOX_ARG: &str = "nanvix.mailbox=";
+
+/// Maximum number of mailboxes that a spawned process may inherit.
+pub const PROCESS_MAILBOXES_MAX: usize = 4;
@@ -8,21 +8,95 @@
 //==============================================================================
 
 use crate::{
+    iam::cred::{
+        Gid,
+        Uid,
+    },
     kcall::{
+        self,
         kcall0,
         kcall1,
         kcall2,
         kcall3,
+        KcallError,
         KcallNumbers,
     },
     pm::*,
 };
 use core::ffi;
 
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Does the kernel forward arguments to spawned processes?
+pub(super) const SPAWN_FORWARDS_ARGS: bool = false;
+
+/// Does the kernel apply the credentials that are handed over to spawned
+/// processes?
+pub(super) const SPAWN_SETS_CREDENTIALS: bool = false;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// An argument that is passed to a spawned process.
+///
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct SpawnArg {
+    /// Pointer to the argument.
+    ptr: u32,
+    /// Length of the argument (in bytes).
+    len: u32,
+}
+
+///
+/// **Description**
+///
+/// Credentials of a spawned process.
+///
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub(super) struct SpawnCredentials {
+    /// User ID.
+    pub(super) uid: Uid,
+    /// Group ID.
+    pub(super) gid: Gid,
+}
+
 //==============================================================================
 // Public Standalone Functions
 //==============================================================================
 
+///
+/// **Description**
+///
+/// Shutdowns the system.
+///
+/// **Parameters**
+///
+/// - `code` - Exit code to report, usually a
+///   [`crate::pm::ShutdownReason`].
+///
+/// **Notes**
+///
+/// - The exit code is passed in the first argument of the kernel call. The
+///   kernel reports a nonzero exit code as an abnormal shutdown.
+///
+pub fn shutdown<C: Into<u32>>(code: C) -> ! {
+    unsafe {
+        kcall1(KcallNumbers::Shutdown as u32, code.into());
+    }
+    // Never gets here.
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
 ///
 /// **Description**
 ///
@@ -31,14 +105,98 @@ use core::ffi;
 /// **Parameters**
 ///
 /// - `image` - Image of the target process.
+/// - `args` - Arguments for the target process.
 ///
 /// **Return**
 ///
 /// Upon successful completion, the PID of the spawned process is returned.
-/// Upon failure, a negative error code is returned instead.
+/// Upon failure, an error is returned instead. Until the kernel forwards
+/// arguments to spawned processes, [`KcallError::NotSupported`] is returned
+/// if `args` is not empty, and no process is spawned.
+///
+/// **Notes**
+///
+/// - Arguments for the kernel call are laid out as follows:
+///   - `ebx` - Pointer to the image.
+///   - `ecx` - Size of the image (in bytes).
+///   - `edx` - Pointer to an array of `argc` (pointer, length) pairs.
+///   - `edi` - Number of arguments (`argc`).
+///   - `esi` - Pointer to the credentials of the process, or null to inherit
+///     the ones of the calling process.
+/// - The kernel currently reads only the image pointer. Arguments are
+///   marshalled so that the interface does not change once it reads them, but
+///   they are refused rather than dropped.
+/// - This replaces `spawn(image: *const c_void) -> i32`. Callers that passed
+///   a raw pointer now pass the image as a slice, along with its arguments,
+///   and match on the result instead of checking for a negative PID.
+///
+pub fn spawn(image: &[u8], args: &[&str]) -> Result<Pid, KcallError> {
+    spawn_as(image, args, None)
+}
+
+///
+/// **Description**
+///
+/// Spawns a new process with given credentials.
+///
+/// **Parameters**
+///
+/// - `image` - Image of the target process.
+/// - `args` - Arguments for the target process.
+/// - `cred` - Credentials of the target process, or `None` to inherit the
+///   ones of the calling process.
 ///
-pub fn spawn(image: *const ffi::c_void) -> i32 {
-    unsafe { kcall1(KcallNumbers::Spawn as u32, image as u32) as i32 }
+/// **Return**
+///
+/// Upon successful completion, the PID of the spawned process is returned.
+/// Upon failure, an error is returned instead. Arguments are refused as in
+/// [`spawn`].
+///
+pub(super) fn spawn_as(
+    image: &[u8],
+    args: &[&str],
+    cred: Option<&SpawnCredentials>,
+) -> Result<Pid, KcallError> {
+    // Check for invalid image.
+    if image.is_empty() {
+        return Err(KcallError::InvalidArgument);
+    }
+
+    // Check for too many arguments.
+    if args.len() > SPAWN_ARGS_MAX {
+        return Err(KcallError::InvalidArgument);
+    }
+
+    // Check if the kernel may hand arguments over.
+    if !args.is_empty() && !SPAWN_FORWARDS_ARGS {
+        return Err(KcallError::NotSupported);
+    }
+
+    // Marshall arguments.
+    let mut argv: [SpawnArg; SPAWN_ARGS_MAX] =
+        [SpawnArg::default(); SPAWN_ARGS_MAX];
+    for (arg, raw) in args.iter().zip(argv.iter_mut()) {
+        (raw.ptr, raw.len) = kcall::ptr_len(arg.as_bytes())?;
+    }
+
+    let cred: u32 = match cred {
+        Some(cred) => cred as *const SpawnCredentials as u32,
+        None => 0,
+    };
+
+    let (image, image_len): (u32, u32) = kcall::ptr_len(image)?;
+    let pid: u32 = unsafe {
+        kcall::kcall5_checked(
+            KcallNumbers::Spawn as u32,
+            image,
+            image_len,
+            argv.as_ptr() as u32,
+            args.len() as u32,
+            cred,
+        )?
+    };
+
+    Ok(Pid::new(pid as i32))
 }
 
 ///
@@ -57,13 +215,39 @@ pub fn spawn(image: *const ffi::c_void) -> i32 {
 /// process is stored in the buffer pointed to by `buf`. On error, a negative
 /// error code is returned instead.
 ///
-pub fn pinfo(pid: i32, buffer: *mut ProcessInfo) -> i32 {
+pub fn pinfo(pid: Pid, buffer: *mut ProcessInfo) -> i32 {
     unsafe {
-        kcall2(KcallNumbers::ProcessInfo as u32, pid as u32, buffer as u32)
+        kcall2(KcallNumbers::ProcessInfo as u32, pid.into(), buffer as u32)
             as i32
     }
 }
 
+///
+/// **Description**
+///
+/// Gets information about a process.
+///
+/// **Parameters**
+///
+/// - `pid` - ID of the target process. Use [`Pid::SELF`] to refer to the
+///   calling process.
+///
+/// **Return**
+///
+/// Upon successful completion, information about the target process is
+/// returned. Upon failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - The kernel currently reports the process ID, the main thread ID and the
+///   virtual memory space of the target process.
+///
+pub fn process_info(pid: Pid) -> Result<ProcessInfo, KcallError> {
+    let mut info: ProcessInfo = ProcessInfo::default();
+    kcall::decode(pinfo(pid, &mut info) as u32)?;
+    Ok(info)
+}
+
 ///
 /// **Description**
 ///
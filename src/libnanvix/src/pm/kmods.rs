@@ -0,0 +1,81 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::misc::{
+    self,
+    KernelModule,
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Iterator over kernel modules.
+///
+/// **Notes**
+///
+/// - The kernel does not tell the end of the list apart from other failures,
+///   thus iteration stops at the first index that cannot be retrieved.
+///
+#[derive(Debug)]
+pub struct KernelModules {
+    /// Index of the next kernel module.
+    index: u32,
+    /// Asserted once the end of the list is reached.
+    done: bool,
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Iterator for KernelModules {
+    type Item = KernelModule;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut kmod: KernelModule = KernelModule::default();
+        if misc::kmod_get(&mut kmod, self.index) != 0 {
+            self.done = true;
+            return None;
+        }
+
+        self.index += 1;
+
+        Some(kmod)
+    }
+}
+
+impl core::iter::FusedIterator for KernelModules {}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Enumerates kernel modules.
+///
+/// **Return**
+///
+/// An iterator over all kernel modules, in the order they were loaded.
+///
+pub fn kmods() -> KernelModules {
+    KernelModules {
+        index: 0,
+        done: false,
+    }
+}
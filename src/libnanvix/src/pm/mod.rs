@@ -7,8 +7,14 @@
 // Modules
 //==============================================================================
 
+mod args;
+mod channel;
 mod constants;
 mod kcall;
+mod kmods;
+mod process;
+mod spawn;
+mod table;
 mod types;
 
 //==============================================================================
@@ -16,8 +22,14 @@ mod types;
 //==============================================================================
 
 pub use self::{
+    args::*,
+    channel::*,
     constants::*,
     kcall::*,
+    kmods::*,
+    process::*,
+    spawn::*,
+    table::*,
     types::*,
 };
 pub use core::ffi;
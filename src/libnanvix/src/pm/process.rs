@@ -0,0 +1,586 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    excp::{
+        self,
+        ExceptionInfo,
+    },
+    iam::cred::{
+        Gid,
+        Uid,
+    },
+    ipc::{
+        Sender,
+        CHANNEL_TAG_MAX,
+    },
+    kcall::KcallError,
+    pm::{
+        self,
+        channel::ArgWriter,
+        kcall::{
+            SpawnCredentials,
+            SPAWN_FORWARDS_ARGS,
+            SPAWN_SETS_CREDENTIALS,
+        },
+        spawn,
+        Pid,
+        ARG_BLOCK_SIZE,
+        ENV_VARS_MAX,
+        PROCESS_MAILBOXES_MAX,
+        SPAWN_ARGS_MAX,
+        SPAWN_ENV_ARG,
+        SPAWN_MAILBOX_ARG,
+    },
+    thread,
+};
+use core::fmt::Write;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A builder that configures a process before it first runs.
+///
+/// **Notes**
+///
+/// - Environment variables and inherited mailboxes are appended to the
+///   arguments of the process, as arguments that start with [`SPAWN_ENV_ARG`]
+///   and [`SPAWN_MAILBOX_ARG`], respectively. The child finds them with
+///   [`env_var`] and [`inherited_mailboxes`].
+/// - Inherited mailboxes are created on behalf of the child once it is
+///   spawned, and the parent gets their sending ends from the returned
+///   [`Child`]. The child takes the receiving ends with
+///   [`crate::ipc::Receiver::attach`].
+/// - Credentials follow the same rules as [`crate::pm::SpawnBuilder`].
+/// - Invalid settings are reported when the process is spawned.
+/// - The kernel currently ignores the arguments that are handed over to it,
+///   thus the child would have no means to find its environment. For this
+///   reason, processes with arguments, environment variables, inherited
+///   mailboxes or credential overrides are refused until the kernel forwards
+///   them.
+///
+/// **Example**
+///
+/// ```ignore
+/// let child: Child = ProcessBuilder::new(image)
+///     .arg("server")
+///     .env("LOG", "debug")
+///     .mailbox(32)
+///     .spawn()?;
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct ProcessBuilder<'a> {
+    /// Image of the process.
+    image: &'a [u8],
+    /// Arguments for the process.
+    args: [&'a str; SPAWN_ARGS_MAX],
+    /// Number of arguments.
+    argc: usize,
+    /// Environment variables for the process, as (key, value) pairs.
+    envs: [(&'a str, &'a str); ENV_VARS_MAX],
+    /// Number of environment variables.
+    envc: usize,
+    /// Tags of the mailboxes that the process inherits.
+    mailboxes: [u32; PROCESS_MAILBOXES_MAX],
+    /// Number of inherited mailboxes.
+    mailboxc: usize,
+    /// User ID of the process.
+    uid: Option<Uid>,
+    /// Group ID of the process.
+    gid: Option<Gid>,
+    /// First invalid setting, if any.
+    error: Option<KcallError>,
+}
+
+///
+/// **Description**
+///
+/// A handle to a process that was spawned by a [`ProcessBuilder`].
+///
+#[derive(Debug)]
+pub struct Child {
+    /// PID of the process.
+    pid: Pid,
+    /// Sending ends of the inherited mailboxes, along with their tags.
+    mailboxes: [Option<(u32, Sender)>; PROCESS_MAILBOXES_MAX],
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<'a> ProcessBuilder<'a> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a builder that spawns a process.
+    ///
+    /// **Parameters**
+    /// - `image` - Image of the process.
+    ///
+    /// **Return**
+    ///
+    /// A builder for a process that has no arguments, no environment
+    /// variables and no inherited mailboxes, and that inherits the credentials
+    /// of the calling process.
+    ///
+    pub fn new(image: &'a [u8]) -> Self {
+        Self {
+            image,
+            args: [""; SPAWN_ARGS_MAX],
+            argc: 0,
+            envs: [("", ""); ENV_VARS_MAX],
+            envc: 0,
+            mailboxes: [0; PROCESS_MAILBOXES_MAX],
+            mailboxc: 0,
+            uid: None,
+            gid: None,
+            error: None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends an argument for the process.
+    ///
+    /// **Parameters**
+    /// - `arg` - Argument for the process.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn arg(mut self, arg: &'a str) -> Self {
+        if self.argc == SPAWN_ARGS_MAX {
+            return self.fail(KcallError::InvalidArgument);
+        }
+
+        self.args[self.argc] = arg;
+        self.argc += 1;
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends arguments for the process.
+    ///
+    /// **Parameters**
+    /// - `args` - Arguments for the process.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn args(self, args: &[&'a str]) -> Self {
+        args.iter().fold(self, |builder, arg| builder.arg(arg))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends an environment variable for the process.
+    ///
+    /// **Parameters**
+    /// - `key` - Name of the variable. It must not be empty nor contain `=`.
+    /// - `value` - Value of the variable.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn env(mut self, key: &'a str, value: &'a str) -> Self {
+        if key.is_empty() || key.contains('=') || self.envc == ENV_VARS_MAX {
+            return self.fail(KcallError::InvalidArgument);
+        }
+
+        self.envs[self.envc] = (key, value);
+        self.envc += 1;
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Appends a mailbox that the process inherits.
+    ///
+    /// **Parameters**
+    /// - `tag` - Tag of the mailbox. It must not exceed [`CHANNEL_TAG_MAX`].
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    /// **Notes**
+    ///
+    /// - A mailbox that was already appended is not appended again.
+    ///
+    pub fn mailbox(mut self, tag: u32) -> Self {
+        if self.mailboxes[..self.mailboxc].contains(&tag) {
+            return self;
+        }
+        if tag > CHANNEL_TAG_MAX || self.mailboxc == PROCESS_MAILBOXES_MAX {
+            return self.fail(KcallError::InvalidArgument);
+        }
+
+        self.mailboxes[self.mailboxc] = tag;
+        self.mailboxc += 1;
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the user ID of the process.
+    ///
+    /// **Parameters**
+    /// - `uid` - User ID of the process.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn uid(mut self, uid: Uid) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the group ID of the process.
+    ///
+    /// **Parameters**
+    /// - `gid` - Group ID of the process.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn gid(mut self, gid: Gid) -> Self {
+        self.gid = Some(gid);
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Lays out the arguments that are handed over to the process.
+    ///
+    /// **Parameters**
+    /// - `buf` - Storage location for environment variables and inherited
+    ///   mailboxes.
+    /// - `argv` - Storage location for the arguments.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the number of arguments in `argv` is
+    /// returned. Upon failure, an error is returned instead. If a setting is
+    /// invalid, or the arguments do not fit in `argv` or `buf`,
+    /// [`KcallError::InvalidArgument`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - Arguments come first, followed by environment variables and then by
+    ///   inherited mailboxes.
+    ///
+    pub fn marshal<'b>(
+        &'b self,
+        buf: &'b mut [u8; ARG_BLOCK_SIZE],
+        argv: &mut [&'b str; SPAWN_ARGS_MAX],
+    ) -> Result<usize, KcallError> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        // Check for too many arguments.
+        let extra: usize = self.envc + self.mailboxc;
+        if self.argc + extra > SPAWN_ARGS_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        // Format environment variables and inherited mailboxes.
+        let mut ends: [usize; SPAWN_ARGS_MAX] = [0; SPAWN_ARGS_MAX];
+        let mut writer: ArgWriter = ArgWriter { buf, len: 0 };
+        for (i, (key, value)) in self.envs[..self.envc].iter().enumerate() {
+            write!(&mut writer, "{}{}={}", SPAWN_ENV_ARG, key, value)
+                .map_err(|_| KcallError::InvalidArgument)?;
+            ends[i] = writer.len;
+        }
+        for (i, tag) in self.mailboxes[..self.mailboxc].iter().enumerate() {
+            write!(&mut writer, "{}{}", SPAWN_MAILBOX_ARG, tag)
+                .map_err(|_| KcallError::InvalidArgument)?;
+            ends[self.envc + i] = writer.len;
+        }
+
+        let ArgWriter { buf, .. } = writer;
+        let buf: &'b [u8] = buf;
+
+        argv[..self.argc].copy_from_slice(&self.args[..self.argc]);
+        let mut start: usize = 0;
+        for (i, end) in ends[..extra].iter().enumerate() {
+            argv[self.argc + i] = core::str::from_utf8(&buf[start..*end])
+                .map_err(|_| KcallError::InvalidArgument)?;
+            start = *end;
+        }
+
+        Ok(self.argc + extra)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Spawns the process.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the spawned process is
+    /// returned. Upon failure, an error is returned instead. If a setting is
+    /// invalid, [`KcallError::InvalidArgument`] is returned, and if the
+    /// calling process may not grant the requested credentials,
+    /// [`KcallError::PermissionDenied`] is returned. If the kernel may not
+    /// hand the settings over, [`KcallError::NotSupported`] is returned. In
+    /// all cases, no process is created.
+    ///
+    /// **Notes**
+    ///
+    /// - If an inherited mailbox may not be created once the process is
+    ///   spawned, the error is returned and the process keeps running.
+    ///
+    pub fn spawn(&self) -> Result<Child, KcallError> {
+        let cred: SpawnCredentials = spawn::credentials(self.uid, self.gid)?;
+
+        let mut buf: [u8; ARG_BLOCK_SIZE] = [0; ARG_BLOCK_SIZE];
+        let mut argv: [&str; SPAWN_ARGS_MAX] = [""; SPAWN_ARGS_MAX];
+        let argc: usize = self.marshal(&mut buf, &mut argv)?;
+
+        // Check if the kernel may hand the settings over.
+        if (self.envc > 0 || self.mailboxc > 0) && !SPAWN_FORWARDS_ARGS {
+            return Err(KcallError::NotSupported);
+        }
+        if (self.uid.is_some() || self.gid.is_some()) && !SPAWN_SETS_CREDENTIALS
+        {
+            return Err(KcallError::NotSupported);
+        }
+
+        let pid: Pid =
+            pm::kcall::spawn_as(self.image, &argv[..argc], Some(&cred))?;
+
+        let mut child: Child = Child {
+            pid,
+            mailboxes: Default::default(),
+        };
+        for (tag, slot) in self.mailboxes[..self.mailboxc]
+            .iter()
+            .zip(child.mailboxes.iter_mut())
+        {
+            *slot = Some((*tag, Sender::create_for(pid, *tag)?));
+        }
+
+        Ok(child)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Records an invalid setting.
+    ///
+    /// **Parameters**
+    /// - `error` - Error to report when the process is spawned.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    fn fail(mut self, error: KcallError) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
+}
+
+impl Child {
+    ///
+    /// **Description**
+    ///
+    /// Creates a handle to a process that was spawned by other means.
+    ///
+    /// **Parameters**
+    /// - `pid` - PID of the process.
+    ///
+    /// **Return**
+    ///
+    /// A handle to the process, which inherited no mailboxes.
+    ///
+    pub fn from_pid(pid: Pid) -> Self {
+        Self {
+            pid,
+            mailboxes: Default::default(),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the PID of the target process.
+    ///
+    /// **Return**
+    ///
+    /// The PID of the target process.
+    ///
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the sending end of a mailbox that the target process inherited.
+    ///
+    /// **Parameters**
+    /// - `tag` - Tag of the mailbox.
+    ///
+    /// **Return**
+    ///
+    /// If the target process inherited a mailbox with tag `tag`, its sending
+    /// end is returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn mailbox(&self, tag: u32) -> Option<&Sender> {
+        self.mailboxes
+            .iter()
+            .flatten()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, tx)| tx)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Waits for the target process to terminate.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel does not notify processes of the termination of others.
+    ///   For this reason, the calling thread polls for the target process and
+    ///   yields the processor in between, and no exit status is reported.
+    /// - If the PID of the target process is reused before it is polled, this
+    ///   function waits for the new process instead.
+    ///
+    pub fn wait(&self) -> Result<(), KcallError> {
+        loop {
+            match pm::process_info(self.pid) {
+                Ok(_) => thread::yield_now(),
+                Err(KcallError::NotFound) => return Ok(()),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Waits for the first fault of the target process.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, information about the fault is returned, or
+    /// `None` if the target process no longer exists. Upon failure, an error
+    /// is returned instead. If the exception that is delivered was raised by
+    /// another process, [`KcallError::Busy`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel delivers an exception to the single process that handles
+    ///   it, whatever process raised it. For this reason, the parent must
+    ///   handle the exceptions that it supervises with [`excp::control`]
+    ///   before the child may raise them, and it keeps receiving faults of all
+    ///   processes until it defers them back to the kernel.
+    /// - The faulting thread stays blocked until the parent fixes the cause of
+    ///   the fault and calls [`excp::resume`] with the returned information.
+    /// - If an exception of another process is delivered, it stays pending,
+    ///   and the caller should collect it with [`excp::wait`] and resume it.
+    /// - The kernel does not notify processes of the termination of others,
+    ///   thus this function blocks if the target process exits while it
+    ///   waits.
+    ///
+    pub fn wait_fault(&self) -> Result<Option<ExceptionInfo>, KcallError> {
+        match pm::process_info(self.pid) {
+            Ok(_) => {},
+            Err(KcallError::NotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        }
+
+        let info: ExceptionInfo = excp::wait_uninterrupted()?;
+        if info.pid != self.pid {
+            return Err(KcallError::Busy);
+        }
+
+        Ok(Some(info))
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Finds an environment variable in the arguments of a process.
+///
+/// **Parameters**
+/// - `args` - Arguments of the process.
+/// - `key` - Name of the variable.
+///
+/// **Return**
+///
+/// If one of `args` holds a variable named `key`, its value is returned.
+/// Otherwise, `None` is returned instead.
+///
+pub fn env_var<'a>(args: &[&'a str], key: &str) -> Option<&'a str> {
+    args.iter().find_map(|arg| {
+        let (k, value): (&str, &str) =
+            arg.strip_prefix(SPAWN_ENV_ARG)?.split_once('=')?;
+        if k == key {
+            Some(value)
+        } else {
+            None
+        }
+    })
+}
+
+///
+/// **Description**
+///
+/// Finds the mailboxes that a process inherited in its arguments.
+///
+/// **Parameters**
+/// - `args` - Arguments of the process.
+///
+/// **Return**
+///
+/// An iterator over the tags of the inherited mailboxes.
+///
+pub fn inherited_mailboxes<'a>(
+    args: &'a [&'a str],
+) -> impl Iterator<Item = u32> + 'a {
+    args.iter().filter_map(|arg| {
+        arg.strip_prefix(SPAWN_MAILBOX_ARG)?.parse::<u32>().ok()
+    })
+}
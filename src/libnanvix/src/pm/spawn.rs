@@ -0,0 +1,219 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    iam::cred::{
+        self,
+        Gid,
+        Uid,
+    },
+    kcall::KcallError,
+    pm::{
+        kcall::{
+            self,
+            SpawnCredentials,
+            SPAWN_SETS_CREDENTIALS,
+        },
+        Pid,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// User ID of the superuser.
+const ROOT_UID: Uid = Uid::new(0);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A builder that spawns a process with given arguments and credentials.
+///
+/// **Notes**
+///
+/// - Unless overridden, the credentials of the spawned process are the real
+///   user and group IDs of the calling process.
+/// - Overriding credentials follows the same rules as [`cred::set_uid`] and
+///   [`cred::set_gid`]: a superuser may choose any IDs, and other users may
+///   only choose their own. Credentials are checked before the process is
+///   created.
+/// - The kernel currently ignores the credentials that are handed over to it,
+///   and spawned processes start with the identity of the kernel. For this
+///   reason, overriding credentials is refused until the kernel applies them.
+///
+/// **Example**
+///
+/// ```ignore
+/// let pid: Pid = SpawnBuilder::new(image)
+///     .args(&["server"])
+///     .uid(cred::get_uid())
+///     .spawn()?;
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct SpawnBuilder<'a> {
+    /// Image of the process.
+    image: &'a [u8],
+    /// Arguments for the process.
+    args: &'a [&'a str],
+    /// User ID of the process.
+    uid: Option<Uid>,
+    /// Group ID of the process.
+    gid: Option<Gid>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<'a> SpawnBuilder<'a> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a builder that spawns a process.
+    ///
+    /// **Parameters**
+    /// - `image` - Image of the process.
+    ///
+    /// **Return**
+    ///
+    /// A builder for a process that has no arguments and inherits the
+    /// credentials of the calling process.
+    ///
+    pub fn new(image: &'a [u8]) -> Self {
+        Self {
+            image,
+            args: &[],
+            uid: None,
+            gid: None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the arguments of the process.
+    ///
+    /// **Parameters**
+    /// - `args` - Arguments for the process.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn args(mut self, args: &'a [&'a str]) -> Self {
+        self.args = args;
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the user ID of the process.
+    ///
+    /// **Parameters**
+    /// - `uid` - User ID of the process.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn uid(mut self, uid: Uid) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the group ID of the process.
+    ///
+    /// **Parameters**
+    /// - `gid` - Group ID of the process.
+    ///
+    /// **Return**
+    ///
+    /// The target builder.
+    ///
+    pub fn gid(mut self, gid: Gid) -> Self {
+        self.gid = Some(gid);
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Spawns the process.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the PID of the spawned process is returned.
+    /// Upon failure, an error is returned instead. If the calling process may
+    /// not grant the requested credentials, [`KcallError::PermissionDenied`]
+    /// is returned and no process is created. If credentials are overridden,
+    /// [`KcallError::NotSupported`] is returned once they are checked, until
+    /// the kernel applies them.
+    ///
+    pub fn spawn(&self) -> Result<Pid, KcallError> {
+        let cred: SpawnCredentials = credentials(self.uid, self.gid)?;
+
+        // Check if the kernel may apply the requested credentials.
+        if (self.uid.is_some() || self.gid.is_some()) && !SPAWN_SETS_CREDENTIALS
+        {
+            return Err(KcallError::NotSupported);
+        }
+
+        kcall::spawn_as(self.image, self.args, Some(&cred))
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Checks the credentials that are requested for a spawned process.
+///
+/// **Parameters**
+/// - `uid` - User ID of the process, or `None` to inherit the real one of the
+///   calling process.
+/// - `gid` - Group ID of the process, or `None` to inherit the real one of the
+///   calling process.
+///
+/// **Return**
+///
+/// Upon successful completion, the credentials of the process are returned.
+/// If the calling process may not grant them,
+/// [`KcallError::PermissionDenied`] is returned instead.
+///
+pub(super) fn credentials(
+    uid: Option<Uid>,
+    gid: Option<Gid>,
+) -> Result<SpawnCredentials, KcallError> {
+    let (real_uid, real_gid): (Uid, Gid) = (cred::get_uid(), cred::get_gid());
+    let superuser: bool = real_uid == ROOT_UID || cred::get_euid() == ROOT_UID;
+
+    let cred: SpawnCredentials = SpawnCredentials {
+        uid: uid.unwrap_or(real_uid),
+        gid: gid.unwrap_or(real_gid),
+    };
+
+    if !superuser && (cred.uid != real_uid || cred.gid != real_gid) {
+        return Err(KcallError::PermissionDenied);
+    }
+
+    Ok(cred)
+}
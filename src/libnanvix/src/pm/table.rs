@@ -0,0 +1,96 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::pm::{
+    self,
+    Pid,
+    ProcessInfo,
+    PROCESS_MAX,
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// An iterator over the processes in the system. See [`processes`].
+///
+#[derive(Debug)]
+pub struct Processes {
+    /// Next process ID to query.
+    next: Option<i32>,
+    /// Number of consecutive process IDs that did not name a process.
+    misses: usize,
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Iterator for Processes {
+    type Item = ProcessInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.misses < PROCESS_MAX {
+            let pid: i32 = self.next?;
+            self.next = pid.checked_add(1);
+
+            match pm::process_info(Pid::new(pid)) {
+                Ok(info) => {
+                    self.misses = 0;
+                    return Some(info);
+                },
+                Err(_) => self.misses += 1,
+            }
+        }
+
+        // Stop for good, even if a process is spawned later on.
+        self.next = None;
+        None
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Scans the process table.
+///
+/// **Return**
+///
+/// An iterator that yields information about each process in the system, in
+/// ascending order of process ID.
+///
+/// **Notes**
+///
+/// - Process IDs are queried one at a time, from zero upward, and those that
+///   do not name a process are skipped. The scan ends after [`PROCESS_MAX`]
+///   consecutive misses, thus it ends on sparse process ID spaces as well.
+/// - Processes that are spawned or that exit while the scan runs may or may not
+///   be yielded.
+///
+/// **Example**
+///
+/// ```ignore
+/// for info in pm::processes() {
+///     nanvix::log!("pid={:?}, tid={:?}", info.pid, info.tid);
+/// }
+/// ```
+///
+pub fn processes() -> Processes {
+    Processes {
+        next: Some(0),
+        misses: 0,
+    }
+}
@@ -4,15 +4,24 @@
  */
 
 //==============================================================================
-// Types
+// Imports
 //==============================================================================
 
-use crate::memory::{
-    self,
+use crate::{
+    excp::ExceptionKind,
+    memory::{
+        self,
+    },
+    pm::{
+        EXIT_STATUS_FAULTED,
+        EXIT_STATUS_VALUE_MASK,
+    },
 };
+use core::fmt;
 
-/// Process ID
-pub type Pid = i32;
+//==============================================================================
+// Types
+//==============================================================================
 
 /// Thread ID
 pub type Tid = i32;
@@ -21,6 +30,29 @@ pub type Tid = i32;
 // Structures
 //==============================================================================
 
+///
+/// **Description**
+///
+/// Process ID
+///
+/// **Notes**
+///
+/// - This structure is transparent, so that it has the same layout of the
+/// `pid_t` type in the kernel space.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Pid(i32);
+
+///
+/// **Description**
+///
+/// Exit code that is reported when the system shuts down.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ExitCode(u32);
+
 ///
 /// **Description**
 ///
@@ -35,7 +67,298 @@ pub type Tid = i32;
 #[derive(Clone, Debug, Default)]
 #[repr(C)]
 pub struct ProcessInfo {
+    /// Process ID.
     pub pid: Pid,
+    /// Main thread ID.
     pub tid: Tid,
+    /// Virtual memory space.
     pub vmem: memory::VirtualMemory,
 }
+
+// Ensure that the layout of the process information matches the kernel's.
+const _: () =
+    assert!(core::mem::size_of::<ProcessInfo>() == super::PROCESS_INFO_SIZE);
+
+//==============================================================================
+// Enumerations
+//==============================================================================
+
+///
+/// **Description**
+///
+/// How a thread or process terminated.
+///
+/// **Notes**
+///
+/// - An exit status is packed in a 32-bit word. Bits 0 to 7 hold the exit code
+///   or the exception vector, and bit 8 is set if the thread was terminated by
+///   an exception. The remaining bits are reserved and ignored when decoding.
+/// - The kernel packs the status of a thread that an exception handler
+///   terminates with [`crate::excp::terminate`] the same way, as
+///   `EXCP_EXIT_FAULTED` ored with the exception vector.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitStatus {
+    /// Normal termination with an exit code.
+    Exited(u8),
+    /// Termination by an exception.
+    Faulted(ExceptionKind),
+}
+
+///
+/// **Description**
+///
+/// Reason why the system shuts down.
+///
+/// **Notes**
+///
+/// - A reason is reported as the exit code of the shutdown kernel call.
+///   Reasons other than [`ShutdownReason::Normal`] are reported by the kernel
+///   as abnormal shutdowns.
+/// - Custom codes that collide with the code of a named reason are decoded as
+///   that reason.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShutdownReason {
+    /// Normal shutdown (code 0).
+    Normal,
+    /// A panic (code 1).
+    Panic,
+    /// The system ran out of memory (code 2).
+    OutOfMemory,
+    /// A user requested the shutdown (code 3).
+    UserRequested,
+    /// Any other reason, with a custom code.
+    Custom(u32),
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Pid {
+    /// Special PID for a process to refer to itself.
+    pub const SELF: Pid = Pid(-1);
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a process ID.
+    ///
+    /// **Parameters**
+    ///
+    /// - `pid` - Raw process ID.
+    ///
+    /// **Return**
+    ///
+    /// The process ID.
+    ///
+    pub const fn new(pid: i32) -> Self {
+        Self(pid)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the process ID refers to the calling process.
+    ///
+    /// **Return**
+    ///
+    /// If the process ID is [`Pid::SELF`], `true` is returned. Otherwise,
+    /// `false` is returned instead.
+    ///
+    pub fn is_self(&self) -> bool {
+        *self == Self::SELF
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value of the process ID.
+    ///
+    /// **Return**
+    ///
+    /// The raw value of the process ID.
+    ///
+    pub const fn raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl ExitCode {
+    /// Unsuccessful termination.
+    pub const FAILURE: ExitCode = ExitCode(1);
+    /// Successful termination.
+    pub const SUCCESS: ExitCode = ExitCode(0);
+
+    ///
+    /// **Description**
+    ///
+    /// Creates an exit code.
+    ///
+    /// **Parameters**
+    ///
+    /// - `code` - Raw exit code.
+    ///
+    /// **Return**
+    ///
+    /// The exit code.
+    ///
+    pub const fn new(code: u32) -> Self {
+        Self(code)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw value of the exit code.
+    ///
+    /// **Return**
+    ///
+    /// The raw value of the exit code.
+    ///
+    pub const fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+impl ExitStatus {
+    ///
+    /// **Description**
+    ///
+    /// Decodes a packed exit status word.
+    ///
+    /// **Parameters**
+    ///
+    /// - `status` - Raw exit status word.
+    ///
+    /// **Return**
+    ///
+    /// The exit status that corresponds to `status`.
+    ///
+    pub fn from_raw(status: u32) -> Self {
+        let value: u32 = status & EXIT_STATUS_VALUE_MASK;
+        if status & EXIT_STATUS_FAULTED != 0 {
+            ExitStatus::Faulted(ExceptionKind::from_vector(value))
+        } else {
+            ExitStatus::Exited(value as u8)
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Encodes the exit status into a packed exit status word.
+    ///
+    /// **Return**
+    ///
+    /// The raw exit status word.
+    ///
+    pub fn raw(&self) -> u32 {
+        match self {
+            ExitStatus::Exited(code) => *code as u32,
+            ExitStatus::Faulted(kind) => {
+                EXIT_STATUS_FAULTED
+                    | (kind.as_vector() & EXIT_STATUS_VALUE_MASK)
+            },
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the exit status reports a successful termination.
+    ///
+    /// **Return**
+    ///
+    /// If the thread exited with code zero, `true` is returned. Otherwise,
+    /// `false` is returned instead.
+    ///
+    pub fn success(&self) -> bool {
+        *self == ExitStatus::Exited(0)
+    }
+}
+
+impl ShutdownReason {
+    ///
+    /// **Description**
+    ///
+    /// Decodes a shutdown reason from an exit code.
+    ///
+    /// **Parameters**
+    ///
+    /// - `code` - Raw exit code.
+    ///
+    /// **Return**
+    ///
+    /// The shutdown reason that corresponds to `code`.
+    ///
+    pub const fn from_u32(code: u32) -> Self {
+        match code {
+            0 => ShutdownReason::Normal,
+            1 => ShutdownReason::Panic,
+            2 => ShutdownReason::OutOfMemory,
+            3 => ShutdownReason::UserRequested,
+            code => ShutdownReason::Custom(code),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Encodes the shutdown reason into an exit code.
+    ///
+    /// **Return**
+    ///
+    /// The raw exit code of the shutdown reason.
+    ///
+    pub const fn as_u32(&self) -> u32 {
+        match self {
+            ShutdownReason::Normal => 0,
+            ShutdownReason::Panic => 1,
+            ShutdownReason::OutOfMemory => 2,
+            ShutdownReason::UserRequested => 3,
+            ShutdownReason::Custom(code) => *code,
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl From<ExitCode> for u32 {
+    fn from(code: ExitCode) -> u32 {
+        code.0
+    }
+}
+
+impl From<u32> for ExitCode {
+    fn from(code: u32) -> ExitCode {
+        ExitCode(code)
+    }
+}
+
+impl From<ShutdownReason> for u32 {
+    fn from(reason: ShutdownReason) -> u32 {
+        reason.as_u32()
+    }
+}
+
+impl From<ShutdownReason> for ExitCode {
+    fn from(reason: ShutdownReason) -> ExitCode {
+        ExitCode(reason.as_u32())
+    }
+}
+
+impl From<Pid> for u32 {
+    fn from(pid: Pid) -> u32 {
+        pid.0 as u32
+    }
+}
+
+impl fmt::Display for Pid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -7,9 +7,9 @@
 // Imports
 //==============================================================================
 
-use crate::{
-    kcall,
-    kcall::KcallNumbers,
+use crate::pm::{
+    self,
+    ShutdownReason,
 };
 
 //==============================================================================
@@ -21,10 +21,10 @@ use crate::{
 ///
 /// Shutdowns the system.
 ///
+/// **Notes**
+///
+/// - This is equivalent to [`pm::shutdown`] with [`ShutdownReason::Normal`].
+///
 pub fn shutdown() -> ! {
-    unsafe {
-        kcall::kcall0(KcallNumbers::Shutdown as u32);
-    }
-    // Never gets here.
-    loop {}
+    pm::shutdown(ShutdownReason::Normal)
 }
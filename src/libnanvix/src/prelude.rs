@@ -0,0 +1,38 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: this module re-exports items that most programs use, so that they may
+// be imported at once with `use nanvix::prelude::*`. Items are only ever added
+// to it, thus importing it never breaks code that compiled before. Note that
+// `spawn` spawns a thread: processes are spawned through the `pm` module.
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+pub use crate::{
+    io::{
+        self,
+        write,
+        Fd,
+    },
+    kcall::KcallError,
+    memory::Frame,
+    pm::{
+        self,
+        Pid,
+    },
+    print,
+    println,
+    sync::{
+        Mutex,
+        Semaphore,
+    },
+    thread::{
+        self,
+        spawn,
+        JoinHandle,
+    },
+};
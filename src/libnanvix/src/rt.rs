@@ -0,0 +1,103 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+// NOTE: this module provides the entry point of a program, thus it is the first
+// code that runs in a process. It replaces `crt0.S`, which must not be linked
+// into programs that enable the `rt` feature, because both define `_do_start`.
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::thread;
+use core::ptr;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Null-terminated table of pointers to arguments.
+///
+#[repr(transparent)]
+struct Argv([*const u8; 1]);
+
+// The table is never written.
+unsafe impl Sync for Argv {}
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Arguments handed over to the main function.
+static ARGV: Argv = Argv([ptr::null()]);
+
+//==============================================================================
+// External Functions
+//==============================================================================
+
+extern "C" {
+    /// Main function of the program.
+    fn main(argc: i32, argv: *const *const u8) -> i32;
+}
+
+//==============================================================================
+// Entry Point
+//==============================================================================
+
+// The kernel starts the root thread of a process at `_do_start`, with the stack
+// pointer at the top of an empty stack. Save a frame pointer and call into Rust
+// with the stack laid out as after any other call, so that the stack alignment
+// expected by compiled code holds.
+#[cfg(target_arch = "x86")]
+core::arch::global_asm!(
+    ".globl _do_start",
+    "_do_start:",
+    "    movl %esp, %ebp",
+    "    call _do_rt_start",
+    "1:  jmp 1b",
+    options(att_syntax)
+);
+
+///
+/// **Description**
+///
+/// Sets up the arguments of the program and runs its main function.
+///
+/// **Notes**
+///
+/// - The kernel does not place arguments on the stack of the root thread yet,
+///   thus the main function is handed an empty, null-terminated `argv`.
+///
+#[no_mangle]
+extern "C" fn _do_rt_start() -> ! {
+    // SAFETY: `main()` is provided by the program.
+    run(|argc, argv| unsafe { main(argc, argv) })
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Runs the main function of a program and exits the calling thread with the
+/// code that it returns.
+///
+/// **Parameters**
+/// - `main` - Main function.
+///
+pub fn run<F>(main: F) -> !
+where
+    F: FnOnce(i32, *const *const u8) -> i32,
+{
+    let code: i32 = main(0, ARGV.0.as_ptr());
+
+    // Do not fall off the entry point.
+    thread::exit(code)
+}
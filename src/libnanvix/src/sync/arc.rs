@@ -0,0 +1,391 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::{
+        self,
+        KcallError,
+    },
+    memory::{
+        self,
+        Frame,
+        FrameNumber,
+        PageFlags,
+        VirtualAddress,
+        VirtualMemory,
+        VmCtrlRequest,
+        PAGE_SIZE,
+    },
+    pm::{
+        self,
+        Pid,
+    },
+};
+use core::{
+    fmt,
+    mem,
+    ops::Deref,
+    ptr::{
+        self,
+        NonNull,
+    },
+    sync::atomic::{
+        self,
+        AtomicU32,
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum number of control blocks that may be alive at once.
+pub const ARC_MAX: usize = 32;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Slots of the region of control blocks that are in use (one bit per slot).
+static SLOTS: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Control block of an atomically reference-counted value.
+///
+#[repr(C)]
+struct ArcInner<T> {
+    /// Number of references to the value.
+    count: AtomicUsize,
+    /// Page frame that backs the control block.
+    frame: FrameNumber,
+    /// Slot of the control block in its region.
+    slot: usize,
+    /// Shared value.
+    data: T,
+}
+
+///
+/// **Description**
+///
+/// A thread-safe reference-counted pointer that does not rely on a global
+/// allocator.
+///
+/// **Notes**
+///
+/// - Every value lives in a control block of its own, which takes a page
+///   frame that is mapped in a region of the virtual memory space of the
+///   calling process. For this reason, values must fit in a page along with
+///   the reference count, and at most [`ARC_MAX`] values may be alive at once.
+/// - The value is dropped, and the page frame is unmapped and released, when
+///   the last reference is dropped.
+/// - The region is not owned by any other part of the library, and it must not
+///   be used by the process for anything else.
+///
+/// **Example**
+///
+/// ```ignore
+/// let shared: Arc<Mutex> = Arc::new(Mutex::new()?)?;
+/// let other: Arc<Mutex> = Arc::clone(&shared);
+/// thread::spawn(move || worker(&other))?;
+/// ```
+///
+pub struct Arc<T> {
+    /// Underlying control block.
+    ptr: NonNull<ArcInner<T>>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<T> Arc<T> {
+    ///
+    /// **Description**
+    ///
+    /// Moves a value into a new control block.
+    ///
+    /// **Parameters**
+    /// - `data` - Value to share.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the first reference to the value is
+    /// returned. Upon failure, an error is returned instead. If the value does
+    /// not fit in a page, [`KcallError::InvalidArgument`] is returned, and if
+    /// [`ARC_MAX`] values are already alive, [`KcallError::NoMemory`] is
+    /// returned.
+    ///
+    pub fn new(data: T) -> Result<Self, KcallError> {
+        if mem::size_of::<ArcInner<T>>() > PAGE_SIZE as usize
+            || mem::align_of::<ArcInner<T>>() > PAGE_SIZE as usize
+        {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let slot: usize = claim_slot()?;
+        let vaddr: VirtualAddress = slot_vaddr(slot);
+        let frame: FrameNumber = match map_slot(vaddr) {
+            Ok(frame) => frame,
+            Err(e) => {
+                release_slot(slot);
+                return Err(e);
+            },
+        };
+
+        let inner: *mut ArcInner<T> = vaddr as *mut ArcInner<T>;
+        unsafe {
+            inner.write(ArcInner {
+                count: AtomicUsize::new(1),
+                frame,
+                slot,
+                data,
+            })
+        };
+
+        Ok(Self {
+            ptr: unsafe { NonNull::new_unchecked(inner) },
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of references to the value of an `Arc`.
+    ///
+    /// **Parameters**
+    /// - `this` - Target reference.
+    ///
+    /// **Return**
+    ///
+    /// The number of references to the value.
+    ///
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().count.load(Ordering::Acquire)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the page frame that backs the control block of an `Arc`.
+    ///
+    /// **Parameters**
+    /// - `this` - Target reference.
+    ///
+    /// **Return**
+    ///
+    /// The number of the page frame that backs the control block.
+    ///
+    pub fn frame(this: &Self) -> FrameNumber {
+        this.inner().frame
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if two references point to the same value.
+    ///
+    /// **Parameters**
+    /// - `this` - First reference.
+    /// - `other` - Second reference.
+    ///
+    /// **Return**
+    ///
+    /// If both references point to the same value, `true` is returned.
+    /// Otherwise, `false` is returned instead.
+    ///
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the control block of the target reference.
+    ///
+    /// **Return**
+    ///
+    /// A reference to the control block.
+    ///
+    fn inner(&self) -> &ArcInner<T> {
+        // The control block stays mapped while there are references to it.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+unsafe impl<T: Send + Sync> Send for Arc<T> {}
+
+unsafe impl<T: Send + Sync> Sync for Arc<T> {}
+
+impl<T> Clone for Arc<T> {
+    fn clone(&self) -> Self {
+        self.inner().count.fetch_add(1, Ordering::Relaxed);
+        Self { ptr: self.ptr }
+    }
+}
+
+impl<T> Deref for Arc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner().data
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Arc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> Drop for Arc<T> {
+    fn drop(&mut self) {
+        if self.inner().count.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+
+        // Synchronize with the release of all other references.
+        atomic::fence(Ordering::Acquire);
+
+        let inner: *mut ArcInner<T> = self.ptr.as_ptr();
+        let (frame, slot): (FrameNumber, usize) =
+            unsafe { ((*inner).frame, (*inner).slot) };
+        unsafe { ptr::drop_in_place(&mut (*inner).data) };
+
+        // If the control block may not be unmapped, leak it.
+        if unmap_slot(slot_vaddr(slot)).is_err() {
+            return;
+        }
+        let _ = memory::frfree(frame);
+        release_slot(slot);
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Claims a free slot in the region of control blocks.
+///
+/// **Return**
+///
+/// Upon successful completion, the index of the slot is returned. Upon
+/// failure, an error is returned instead.
+///
+fn claim_slot() -> Result<usize, KcallError> {
+    let mut slots: u32 = SLOTS.load(Ordering::Relaxed);
+    loop {
+        let slot: usize = (!slots).trailing_zeros() as usize;
+        if slot >= ARC_MAX {
+            return Err(KcallError::NoMemory);
+        }
+
+        match SLOTS.compare_exchange_weak(
+            slots,
+            slots | (1 << slot),
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => return Ok(slot),
+            Err(current) => slots = current,
+        }
+    }
+}
+
+///
+/// **Description**
+///
+/// Releases a slot in the region of control blocks.
+///
+/// **Parameters**
+/// - `slot` - Index of the slot.
+///
+fn release_slot(slot: usize) {
+    SLOTS.fetch_and(!(1 << slot), Ordering::Release);
+}
+
+///
+/// **Description**
+///
+/// Computes the virtual address of a slot in the region of control blocks.
+///
+/// **Parameters**
+/// - `slot` - Index of the slot.
+///
+/// **Return**
+///
+/// The virtual address of the slot.
+///
+fn slot_vaddr(slot: usize) -> VirtualAddress {
+    memory::ARC_REGION_BASE_ADDRESS + (slot as u32) * PAGE_SIZE
+}
+
+///
+/// **Description**
+///
+/// Maps a page frame at a slot in the region of control blocks.
+///
+/// **Parameters**
+/// - `vaddr` - Virtual address of the slot.
+///
+/// **Return**
+///
+/// Upon successful completion, the number of the page frame is returned.
+/// Upon failure, an error is returned instead.
+///
+fn map_slot(vaddr: VirtualAddress) -> Result<FrameNumber, KcallError> {
+    let vmem: VirtualMemory = pm::process_info(Pid::SELF)?.vmem;
+
+    let frame: Frame = Frame::alloc()?;
+    kcall::decode(memory::vmmap(vmem, vaddr, frame.number()))?;
+
+    let flags: PageFlags = PageFlags::READ | PageFlags::WRITE;
+    let request: VmCtrlRequest =
+        VmCtrlRequest::ChangePermissions(vaddr, flags.into());
+    if let Err(e) = kcall::decode(memory::vmctrl(vmem, request)) {
+        let _ = memory::vmunmap(vmem, vaddr);
+        return Err(e);
+    }
+
+    // The page frame is now owned by the control block.
+    Ok(frame.leak())
+}
+
+///
+/// **Description**
+///
+/// Unmaps the page frame at a slot in the region of control blocks.
+///
+/// **Parameters**
+/// - `vaddr` - Virtual address of the slot.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+fn unmap_slot(vaddr: VirtualAddress) -> Result<(), KcallError> {
+    let vmem: VirtualMemory = pm::process_info(Pid::SELF)?.vmem;
+    kcall::decode(memory::vmunmap(vmem, vaddr))?;
+    Ok(())
+}
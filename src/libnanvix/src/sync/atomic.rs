@@ -0,0 +1,403 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    pm::Pid,
+    thread::ThreadId,
+};
+use core::{
+    fmt,
+    sync::atomic::{
+        AtomicI32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Raw thread ID that marks an empty [`AtomicThreadId`]. The kernel never
+/// hands it out.
+const TID_NONE: i32 = -1;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A process ID that may be shared between threads.
+///
+/// **Notes**
+///
+/// - [`Pid::SELF`] marks an empty value, as it does not name a process on its
+///   own. See [`AtomicPid::take`].
+///
+#[repr(transparent)]
+pub struct AtomicPid(AtomicI32);
+
+///
+/// **Description**
+///
+/// A thread identity that may be shared between threads.
+///
+/// **Notes**
+///
+/// - A raw thread ID of `-1`, which the kernel never hands out, marks an empty
+///   value. See [`AtomicThreadId::take`].
+///
+#[repr(transparent)]
+pub struct AtomicThreadId(AtomicI32);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl AtomicPid {
+    ///
+    /// **Description**
+    ///
+    /// Creates an atomic process ID.
+    ///
+    /// **Parameters**
+    /// - `pid` - Initial value.
+    ///
+    /// **Return**
+    ///
+    /// An atomic process ID that holds `pid`.
+    ///
+    pub const fn new(pid: Pid) -> Self {
+        Self(AtomicI32::new(pid.raw()))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates an empty atomic process ID.
+    ///
+    /// **Return**
+    ///
+    /// An atomic process ID that holds [`Pid::SELF`].
+    ///
+    pub const fn empty() -> Self {
+        Self::new(Pid::SELF)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Loads the process ID.
+    ///
+    /// **Parameters**
+    /// - `order` - Memory ordering of the load.
+    ///
+    /// **Return**
+    ///
+    /// The current value.
+    ///
+    pub fn load(&self, order: Ordering) -> Pid {
+        Pid::new(self.0.load(order))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stores a process ID.
+    ///
+    /// **Parameters**
+    /// - `pid` - New value.
+    /// - `order` - Memory ordering of the store.
+    ///
+    pub fn store(&self, pid: Pid, order: Ordering) {
+        self.0.store(pid.raw(), order)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stores a process ID, and gets the previous one.
+    ///
+    /// **Parameters**
+    /// - `pid` - New value.
+    /// - `order` - Memory ordering of the operation.
+    ///
+    /// **Return**
+    ///
+    /// The previous value.
+    ///
+    pub fn swap(&self, pid: Pid, order: Ordering) -> Pid {
+        Pid::new(self.0.swap(pid.raw(), order))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stores a process ID if the current one is the expected one.
+    ///
+    /// **Parameters**
+    /// - `current` - Expected value.
+    /// - `new` - New value.
+    /// - `success` - Memory ordering if the value is stored.
+    /// - `failure` - Memory ordering if the value is not stored.
+    ///
+    /// **Return**
+    ///
+    /// If the value is stored, the previous value is returned. Otherwise, the
+    /// current value is returned as an error instead.
+    ///
+    pub fn compare_exchange(
+        &self,
+        current: Pid,
+        new: Pid,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Pid, Pid> {
+        self.0
+            .compare_exchange(current.raw(), new.raw(), success, failure)
+            .map(Pid::new)
+            .map_err(Pid::new)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Takes the process ID, leaving the target atomic process ID empty.
+    ///
+    /// **Parameters**
+    /// - `order` - Memory ordering of the operation.
+    ///
+    /// **Return**
+    ///
+    /// If the target atomic process ID was not empty, its value is returned.
+    /// Otherwise, `None` is returned instead.
+    ///
+    pub fn take(&self, order: Ordering) -> Option<Pid> {
+        Some(self.swap(Pid::SELF, order)).filter(|pid| !pid.is_self())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target atomic process ID.
+    ///
+    /// **Return**
+    ///
+    /// The current value.
+    ///
+    pub fn into_inner(self) -> Pid {
+        Pid::new(self.0.into_inner())
+    }
+}
+
+impl AtomicThreadId {
+    ///
+    /// **Description**
+    ///
+    /// Creates an atomic thread identity.
+    ///
+    /// **Parameters**
+    /// - `id` - Initial value.
+    ///
+    /// **Return**
+    ///
+    /// An atomic thread identity that holds `id`.
+    ///
+    pub const fn new(id: ThreadId) -> Self {
+        Self(AtomicI32::new(id.as_raw()))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates an empty atomic thread identity.
+    ///
+    /// **Return**
+    ///
+    /// An atomic thread identity that holds no thread.
+    ///
+    pub const fn empty() -> Self {
+        Self(AtomicI32::new(TID_NONE))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Loads the thread identity.
+    ///
+    /// **Parameters**
+    /// - `order` - Memory ordering of the load.
+    ///
+    /// **Return**
+    ///
+    /// The current value, or `None` if the target atomic thread identity is
+    /// empty.
+    ///
+    pub fn load(&self, order: Ordering) -> Option<ThreadId> {
+        decode(self.0.load(order))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stores a thread identity.
+    ///
+    /// **Parameters**
+    /// - `id` - New value.
+    /// - `order` - Memory ordering of the store.
+    ///
+    pub fn store(&self, id: ThreadId, order: Ordering) {
+        self.0.store(id.as_raw(), order)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stores a thread identity, and gets the previous one.
+    ///
+    /// **Parameters**
+    /// - `id` - New value.
+    /// - `order` - Memory ordering of the operation.
+    ///
+    /// **Return**
+    ///
+    /// The previous value, or `None` if the target atomic thread identity was
+    /// empty.
+    ///
+    pub fn swap(&self, id: ThreadId, order: Ordering) -> Option<ThreadId> {
+        decode(self.0.swap(id.as_raw(), order))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stores a thread identity if the current one is the expected one.
+    ///
+    /// **Parameters**
+    /// - `current` - Expected value, or `None` to expect an empty value.
+    /// - `new` - New value.
+    /// - `success` - Memory ordering if the value is stored.
+    /// - `failure` - Memory ordering if the value is not stored.
+    ///
+    /// **Return**
+    ///
+    /// If the value is stored, the previous value is returned. Otherwise, the
+    /// current value is returned as an error instead.
+    ///
+    pub fn compare_exchange(
+        &self,
+        current: Option<ThreadId>,
+        new: ThreadId,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Option<ThreadId>, Option<ThreadId>> {
+        let current: i32 = current.map_or(TID_NONE, |id| id.as_raw());
+        self.0
+            .compare_exchange(current, new.as_raw(), success, failure)
+            .map(decode)
+            .map_err(decode)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Takes the thread identity, leaving the target atomic thread identity
+    /// empty.
+    ///
+    /// **Parameters**
+    /// - `order` - Memory ordering of the operation.
+    ///
+    /// **Return**
+    ///
+    /// If the target atomic thread identity was not empty, its value is
+    /// returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn take(&self, order: Ordering) -> Option<ThreadId> {
+        decode(self.0.swap(TID_NONE, order))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target atomic thread identity.
+    ///
+    /// **Return**
+    ///
+    /// The current value, or `None` if the target atomic thread identity is
+    /// empty.
+    ///
+    pub fn into_inner(self) -> Option<ThreadId> {
+        decode(self.0.into_inner())
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Default for AtomicPid {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl From<Pid> for AtomicPid {
+    fn from(pid: Pid) -> Self {
+        Self::new(pid)
+    }
+}
+
+impl fmt::Debug for AtomicPid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+impl Default for AtomicThreadId {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl From<ThreadId> for AtomicThreadId {
+    fn from(id: ThreadId) -> Self {
+        Self::new(id)
+    }
+}
+
+impl fmt::Debug for AtomicThreadId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Decodes a raw thread ID that is held by an [`AtomicThreadId`].
+///
+/// **Parameters**
+/// - `tid` - Raw thread ID.
+///
+/// **Return**
+///
+/// The thread identity, or `None` if `tid` marks an empty value.
+///
+fn decode(tid: i32) -> Option<ThreadId> {
+    if tid == TID_NONE {
+        None
+    } else {
+        Some(ThreadId::from_raw(tid))
+    }
+}
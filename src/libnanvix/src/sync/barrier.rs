@@ -0,0 +1,185 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallError,
+    pm,
+    sync::Semaphore,
+};
+use core::sync::atomic::{
+    AtomicU32,
+    Ordering,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Number of bits of the barrier state that hold the arrival count.
+const COUNT_BITS: u32 = 16;
+
+/// Mask of the barrier state that selects the arrival count.
+const COUNT_MASK: u32 = (1 << COUNT_BITS) - 1;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A barrier that blocks threads until a fixed number of them has arrived.
+///
+/// **Notes**
+///
+/// - The barrier is backed by a kernel semaphore and an atomic state that packs
+///   the current generation with the number of threads that have arrived.
+/// - The barrier may be reused for multiple rounds. Threads only leave once the
+///   generation they arrived at is over, thus threads from one round never slip
+///   through the barrier of an earlier round.
+///
+pub struct Barrier {
+    /// Semaphore where waiting threads are blocked.
+    sem: Semaphore,
+    /// Number of threads that must arrive to release the barrier.
+    n: u32,
+    /// Generation (upper bits) and number of arrived threads (lower bits).
+    state: AtomicU32,
+}
+
+///
+/// **Description**
+///
+/// Result of waiting on a barrier.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BarrierWaitResult {
+    /// Asserted for the thread that released the barrier.
+    leader: bool,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Barrier {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new barrier.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the underlying semaphore.
+    /// - `n` - Number of threads that must arrive to release the barrier.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the new barrier is returned. Upon failure,
+    /// an error is returned instead.
+    ///
+    pub fn new(key: u32, n: u32) -> Result<Self, KcallError> {
+        if n == 0 || n > COUNT_MASK {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        Ok(Barrier {
+            sem: Semaphore::get(key, 0)?,
+            n,
+            state: AtomicU32::new(0),
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Blocks the calling thread until all threads have arrived at the target
+    /// barrier.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the result of the wait is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn wait(&self) -> Result<BarrierWaitResult, KcallError> {
+        let n: u32 = self.n;
+
+        // Arrive, starting a new generation if this is the last thread.
+        let state: u32 = match self.state.fetch_update(
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+            |state| {
+                if (state & COUNT_MASK) + 1 == n {
+                    Some(next_generation(state) << COUNT_BITS)
+                } else {
+                    Some(state + 1)
+                }
+            },
+        ) {
+            Ok(state) | Err(state) => state,
+        };
+        let generation: u32 = state >> COUNT_BITS;
+
+        // Release the other threads.
+        if (state & COUNT_MASK) + 1 == n {
+            for _ in 1..n {
+                self.sem.up()?;
+            }
+            return Ok(BarrierWaitResult { leader: true });
+        }
+
+        // Wait for the generation to end. A wakeup that is meant for a thread
+        // of an earlier generation is handed back.
+        loop {
+            self.sem.down()?;
+            if (self.state.load(Ordering::SeqCst) >> COUNT_BITS) != generation {
+                break;
+            }
+            self.sem.up()?;
+            pm::thread_yield();
+        }
+
+        Ok(BarrierWaitResult { leader: false })
+    }
+}
+
+impl BarrierWaitResult {
+    ///
+    /// **Description**
+    ///
+    /// Checks if the calling thread released the barrier.
+    ///
+    /// **Return**
+    ///
+    /// If the calling thread was the last one to arrive, `true` is returned.
+    /// Otherwise, `false` is returned instead.
+    ///
+    pub fn is_leader(&self) -> bool {
+        self.leader
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Computes the generation that follows the one in a barrier state.
+///
+/// **Parameters**
+/// - `state` - Barrier state.
+///
+/// **Return**
+///
+/// The next generation, wrapped around to fit in the barrier state.
+///
+fn next_generation(state: u32) -> u32 {
+    ((state >> COUNT_BITS) + 1) & COUNT_MASK
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallError,
+    sync::{
+        MutexGuard,
+        Semaphore,
+    },
+};
+use core::sync::atomic::{
+    AtomicU32,
+    Ordering,
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A condition variable that blocks threads until they are notified.
+///
+/// **Notes**
+///
+/// - The condition variable is backed by a kernel semaphore and a count of
+///   threads that are waiting on it.
+/// - Wakeups are not bound to a specific waiter and may be spurious. Callers
+///   must re-check their predicate in a loop after [`Condvar::wait`] returns.
+///
+pub struct Condvar {
+    /// Semaphore where waiting threads are blocked.
+    sem: Semaphore,
+    /// Number of threads that are waiting and have not been notified.
+    waiters: AtomicU32,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Condvar {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new condition variable.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the underlying semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the new condition variable is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn new(key: u32) -> Result<Self, KcallError> {
+        Ok(Condvar {
+            sem: Semaphore::get(key, 0)?,
+            waiters: AtomicU32::new(0),
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Releases a mutex and blocks the calling thread until the target
+    /// condition variable is notified. The mutex is re-acquired before this
+    /// function returns.
+    ///
+    /// **Parameters**
+    /// - `guard` - Guard of the mutex to release.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a guard of the re-acquired mutex is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    pub fn wait<'a, T>(
+        &self,
+        guard: MutexGuard<'a, T>,
+    ) -> Result<MutexGuard<'a, T>, KcallError> {
+        let mutex = guard.mutex;
+
+        // Register as a waiter before releasing the mutex, so that notifiers
+        // holding the mutex account for this thread.
+        self.waiters.fetch_add(1, Ordering::SeqCst);
+        drop(guard);
+
+        self.sem.down()?;
+
+        mutex.lock()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Wakes up one thread that is waiting on the target condition variable.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    pub fn notify_one(&self) -> Result<(), KcallError> {
+        let notified: Result<u32, u32> = self.waiters.fetch_update(
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+            |n| n.checked_sub(1),
+        );
+
+        if notified.is_ok() {
+            self.sem.up()?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Wakes up all threads that are currently waiting on the target
+    /// condition variable.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Threads that start waiting after this function is called are not
+    ///   accounted for.
+    ///
+    pub fn notify_all(&self) -> Result<(), KcallError> {
+        let waiters: u32 = self.waiters.swap(0, Ordering::SeqCst);
+
+        for _ in 0..waiters {
+            self.sem.up()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Modules
+//==============================================================================
+
+mod arc;
+mod atomic;
+mod barrier;
+mod condvar;
+mod mutex;
+mod once;
+mod rwlock;
+mod sem;
+mod spin;
+mod ticket;
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+pub use self::{
+    arc::*,
+    atomic::*,
+    barrier::*,
+    condvar::*,
+    mutex::*,
+    once::*,
+    rwlock::*,
+    sem::*,
+    spin::*,
+    ticket::*,
+};
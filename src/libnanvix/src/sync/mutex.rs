@@ -0,0 +1,153 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallError,
+    sync::Semaphore,
+};
+use core::{
+    cell::UnsafeCell,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A mutual exclusion primitive that protects shared data.
+///
+/// **Notes**
+///
+/// - The mutex is backed by a binary kernel semaphore.
+/// - Poisoning is not supported.
+///
+pub struct Mutex<T> {
+    /// Underlying semaphore.
+    sem: Semaphore,
+    /// Protected data.
+    data: UnsafeCell<T>,
+}
+
+///
+/// **Description**
+///
+/// A guard that grants access to the data protected by a mutex and releases
+/// the mutex when it is dropped.
+///
+pub struct MutexGuard<'a, T> {
+    /// Underlying mutex.
+    pub(super) mutex: &'a Mutex<T>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<T> Mutex<T> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new mutex.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the underlying semaphore.
+    /// - `data` - Data to protect.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the new mutex is returned in the unlocked
+    /// state. Upon failure, an error is returned instead.
+    ///
+    pub fn new(key: u32, data: T) -> Result<Self, KcallError> {
+        Ok(Mutex {
+            sem: Semaphore::get(key, 1)?,
+            data: UnsafeCell::new(data),
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Acquires the target mutex, blocking the calling thread until it is
+    /// available.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a guard to the protected data is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn lock(&self) -> Result<MutexGuard<T>, KcallError> {
+        self.sem.down()?;
+        Ok(MutexGuard { mutex: self })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attempts to acquire the target mutex without blocking.
+    ///
+    /// **Return**
+    ///
+    /// If the mutex was acquired, a guard to the protected data is returned.
+    /// Otherwise, `None` is returned instead.
+    ///
+    pub fn try_lock(&self) -> Option<MutexGuard<T>> {
+        match self.sem.try_down() {
+            Ok(true) => Some(MutexGuard { mutex: self }),
+            _ => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target mutex and returns the protected data.
+    ///
+    /// **Return**
+    ///
+    /// The protected data is returned.
+    ///
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+unsafe impl<T: Send> Send for Mutex<T> {}
+
+unsafe impl<T: Send> Sync for Mutex<T> {}
+
+impl<'a, T> Deref for MutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<'a, T> DerefMut for MutexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<'a, T> Drop for MutexGuard<'a, T> {
+    fn drop(&mut self) {
+        let _ = self.mutex.sem.up();
+    }
+}
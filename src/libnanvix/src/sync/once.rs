@@ -0,0 +1,219 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::pm;
+use core::{
+    cell::{
+        Cell,
+        UnsafeCell,
+    },
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// The initialization routine did not run yet.
+const INCOMPLETE: u32 = 0;
+
+/// The initialization routine is running.
+const RUNNING: u32 = 1;
+
+/// The initialization routine has completed.
+const COMPLETE: u32 = 2;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A synchronization primitive that runs an initialization routine once.
+///
+/// **Notes**
+///
+/// - The primitive does not rely on kernel objects, and it may be used in
+///   statics. Threads that find the routine running yield the processor until
+///   it completes.
+///
+pub struct Once {
+    /// State of the initialization routine.
+    state: AtomicU32,
+}
+
+///
+/// **Description**
+///
+/// A value that is initialized on first access.
+///
+/// **Example**
+///
+/// ```ignore
+/// static TABLE: Lazy<[u32; 16]> = Lazy::new(|| [0; 16]);
+///
+/// let first: u32 = TABLE[0];
+/// ```
+///
+pub struct Lazy<T, F = fn() -> T> {
+    /// Guard of the initialization.
+    once: Once,
+    /// Initializer, taken when it runs.
+    init: Cell<Option<F>>,
+    /// Value, once initialized.
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// The initializer and the value are only accessed under the guard of `once`.
+unsafe impl<T: Send + Sync, F: Send> Sync for Lazy<T, F> {}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Once {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new once primitive.
+    ///
+    /// **Return**
+    ///
+    /// A once primitive whose initialization routine did not run yet.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU32::new(INCOMPLETE),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Runs an initialization routine, if no routine was run on the target
+    /// once primitive.
+    ///
+    /// **Parameters**
+    /// - `f` - Initialization routine.
+    ///
+    /// **Notes**
+    ///
+    /// - When this function returns, the initialization routine has completed,
+    ///   either on the calling thread or on another one.
+    ///
+    pub fn call_once<F: FnOnce()>(&self, f: F) {
+        match self.state.compare_exchange(
+            INCOMPLETE,
+            RUNNING,
+            Ordering::Acquire,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                f();
+                self.state.store(COMPLETE, Ordering::Release);
+            },
+            Err(_) => {
+                while self.state.load(Ordering::Acquire) != COMPLETE {
+                    pm::thread_yield();
+                }
+            },
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the initialization routine has completed.
+    ///
+    /// **Return**
+    ///
+    /// If the initialization routine has completed, `true` is returned.
+    /// Otherwise, `false` is returned instead.
+    ///
+    pub fn is_completed(&self) -> bool {
+        self.state.load(Ordering::Acquire) == COMPLETE
+    }
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new lazy value.
+    ///
+    /// **Parameters**
+    /// - `init` - Initializer of the value.
+    ///
+    /// **Return**
+    ///
+    /// A lazy value that is initialized by `init` on first access.
+    ///
+    pub const fn new(init: F) -> Self {
+        Self {
+            once: Once::new(),
+            init: Cell::new(Some(init)),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Forces the initialization of a lazy value.
+    ///
+    /// **Parameters**
+    /// - `this` - Target lazy value.
+    ///
+    /// **Return**
+    ///
+    /// A reference to the initialized value.
+    ///
+    pub fn force(this: &Self) -> &T {
+        this.once.call_once(|| {
+            if let Some(init) = this.init.take() {
+                unsafe { (*this.value.get()).write(init()) };
+            }
+        });
+
+        // SAFETY: the value was written before the routine completed.
+        unsafe { (*this.value.get()).assume_init_ref() }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Default for Once {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        Lazy::force(self)
+    }
+}
+
+impl<T, F> Drop for Lazy<T, F> {
+    fn drop(&mut self) {
+        if self.once.is_completed() {
+            unsafe { (*self.value.get()).assume_init_drop() };
+        }
+    }
+}
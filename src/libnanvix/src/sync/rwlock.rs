@@ -0,0 +1,221 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallError,
+    sync::Semaphore,
+};
+use core::{
+    cell::UnsafeCell,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A reader-writer lock that protects shared data.
+///
+/// **Notes**
+///
+/// - The lock is backed by two binary kernel semaphores: one that protects the
+///   number of readers, and one that is held by either a writer or the group of
+///   readers as a whole.
+/// - The lock prefers readers: while a reader holds the lock, new readers are
+///   admitted even if a writer is waiting. A steady stream of readers may thus
+///   starve writers.
+/// - Poisoning is not supported.
+///
+pub struct RwLock<T> {
+    /// Semaphore that protects the number of readers.
+    count: Semaphore,
+    /// Semaphore that is held by a writer or by readers.
+    writer: Semaphore,
+    /// Number of readers that hold the lock.
+    readers: UnsafeCell<u32>,
+    /// Protected data.
+    data: UnsafeCell<T>,
+}
+
+///
+/// **Description**
+///
+/// A guard that grants shared access to the data protected by a reader-writer
+/// lock and releases the lock when it is dropped.
+///
+pub struct RwLockReadGuard<'a, T> {
+    /// Underlying lock.
+    lock: &'a RwLock<T>,
+}
+
+///
+/// **Description**
+///
+/// A guard that grants exclusive access to the data protected by a
+/// reader-writer lock and releases the lock when it is dropped.
+///
+pub struct RwLockWriteGuard<'a, T> {
+    /// Underlying lock.
+    lock: &'a RwLock<T>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<T> RwLock<T> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new reader-writer lock.
+    ///
+    /// **Parameters**
+    /// - `count_key` - Key of the semaphore that protects the number of
+    ///   readers.
+    /// - `writer_key` - Key of the semaphore that is held by a writer or by
+    ///   readers.
+    /// - `data` - Data to protect.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the new lock is returned in the unlocked
+    /// state. Upon failure, an error is returned instead.
+    ///
+    pub fn new(
+        count_key: u32,
+        writer_key: u32,
+        data: T,
+    ) -> Result<Self, KcallError> {
+        if count_key == writer_key {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        Ok(RwLock {
+            count: Semaphore::get(count_key, 1)?,
+            writer: Semaphore::get(writer_key, 1)?,
+            readers: UnsafeCell::new(0),
+            data: UnsafeCell::new(data),
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Acquires the target lock for reading, blocking the calling thread while
+    /// a writer holds it.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a guard to the protected data is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn read(&self) -> Result<RwLockReadGuard<T>, KcallError> {
+        self.count.down()?;
+
+        // The first reader locks writers out.
+        let readers: &mut u32 = unsafe { &mut *self.readers.get() };
+        if *readers == 0 {
+            if let Err(e) = self.writer.down() {
+                let _ = self.count.up();
+                return Err(e);
+            }
+        }
+        *readers += 1;
+
+        self.count.up()?;
+
+        Ok(RwLockReadGuard { lock: self })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Acquires the target lock for writing, blocking the calling thread while
+    /// a writer or readers hold it.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a guard to the protected data is returned.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn write(&self) -> Result<RwLockWriteGuard<T>, KcallError> {
+        self.writer.down()?;
+        Ok(RwLockWriteGuard { lock: self })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target lock and returns the protected data.
+    ///
+    /// **Return**
+    ///
+    /// The protected data is returned.
+    ///
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+unsafe impl<T: Send> Send for RwLock<T> {}
+
+unsafe impl<T: Send + Sync> Sync for RwLock<T> {}
+
+impl<'a, T> Deref for RwLockReadGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<'a, T> Drop for RwLockReadGuard<'a, T> {
+    fn drop(&mut self) {
+        let _ = self.lock.count.down();
+
+        // The last reader lets writers in.
+        let readers: &mut u32 = unsafe { &mut *self.lock.readers.get() };
+        *readers -= 1;
+        if *readers == 0 {
+            let _ = self.lock.writer.up();
+        }
+
+        let _ = self.lock.count.up();
+    }
+}
+
+impl<'a, T> Deref for RwLockWriteGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<'a, T> DerefMut for RwLockWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<'a, T> Drop for RwLockWriteGuard<'a, T> {
+    fn drop(&mut self) {
+        let _ = self.lock.writer.up();
+    }
+}
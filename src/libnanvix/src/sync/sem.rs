@@ -0,0 +1,676 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::{
+        self,
+        KcallError,
+    },
+    pm::{
+        self,
+        Pid,
+    },
+    thread,
+    time::Ticks,
+};
+use core::{
+    mem,
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Increments the counter of a semaphore.
+const SEMAPHORE_UP: u32 = 0;
+
+/// Decrements the counter of a semaphore, blocking if it is zero.
+const SEMAPHORE_DOWN: u32 = 1;
+
+/// Decrements the counter of a semaphore, failing if it is zero.
+const SEMAPHORE_TRYLOCK: u32 = 2;
+
+/// Gets the counter of a semaphore.
+const SEMAPHORE_GETVALUE: u32 = 0;
+
+/// Sets the counter of a semaphore.
+const SEMAPHORE_SETVALUE: u32 = 1;
+
+/// Removes a semaphore.
+const SEMAPHORE_DELETE: u32 = 2;
+
+/// Gets the process that created a semaphore.
+const SEMAPHORE_GETOWNER: u32 = 3;
+
+/// Maximum number of semaphores in the system.
+pub const SEMAPHORE_MAX: usize = 128;
+
+/// Initial value of a word of [`HELD`].
+#[allow(clippy::declare_interior_mutable_const)]
+const HELD_NONE: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Semaphores that handles of the calling process refer to (one bit per
+/// semaphore identifier).
+static HELD: [AtomicU32; SEMAPHORE_MAX / 32] = [HELD_NONE; SEMAPHORE_MAX / 32];
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A handle to a kernel semaphore.
+///
+/// **Notes**
+///
+/// - The semaphore is removed when the handle is dropped.
+///
+#[derive(Debug)]
+pub struct Semaphore {
+    /// Semaphore identifier.
+    id: u32,
+}
+
+///
+/// **Description**
+///
+/// A guard that releases a semaphore when it is dropped.
+///
+#[derive(Debug)]
+pub struct SemaphoreGuard<'a> {
+    /// Underlying semaphore.
+    sem: &'a Semaphore,
+}
+
+//==============================================================================
+// Enumerations
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Where a semaphore that was got by key comes from.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Origin {
+    /// The kernel created the semaphore.
+    Created,
+    /// Another handle of the calling process refers to the semaphore.
+    Held,
+    /// Another process created the semaphore.
+    Foreign,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Semaphore {
+    ///
+    /// **Description**
+    ///
+    /// Gets the semaphore that is associated with a key.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the semaphore.
+    /// - `initial` - Initial value for the counter of the semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the semaphore is returned.
+    /// Upon failure, an error is returned instead. If another handle of the
+    /// calling process already refers to the semaphore,
+    /// [`KcallError::AlreadyExists`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The counter is set to `initial` only if the semaphore is created. The
+    ///   counter of a semaphore that already exists is kept.
+    /// - Each semaphore has at most one handle per process, because the handle
+    ///   removes the semaphore when it is dropped.
+    ///
+    pub fn get(key: u32, initial: u32) -> Result<Self, KcallError> {
+        let (sem, origin): (Semaphore, Origin) = Self::origin(key)?;
+        match origin {
+            Origin::Created => sem.set_value(initial)?,
+            // The other handle removes the semaphore.
+            Origin::Held => {
+                mem::forget(sem);
+                return Err(KcallError::AlreadyExists);
+            },
+            Origin::Foreign => {},
+        }
+
+        Ok(sem)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a semaphore that is associated with a key.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the semaphore.
+    /// - `initial` - Initial value for the counter of the semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the semaphore is returned.
+    /// Upon failure, an error is returned instead. If a semaphore is already
+    /// associated with `key`, [`KcallError::AlreadyExists`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - This is best-effort. The kernel gets or creates a semaphore in a
+    ///   single kernel call, and it does not report which one happened. A
+    ///   semaphore is found to exist if a handle of the calling process refers
+    ///   to it, or if it was created by another process. Semaphores that the
+    ///   calling process created and then left without a handle are not found.
+    /// - Threads of the calling process that race for the same key may both
+    ///   get it, but only one of them is told that it created it, and that one
+    ///   may not be the thread that did.
+    ///
+    pub fn create(key: u32, initial: u32) -> Result<Self, KcallError> {
+        let (sem, origin): (Semaphore, Origin) = Self::origin(key)?;
+        match origin {
+            Origin::Created => {},
+            // The other handle removes the semaphore.
+            Origin::Held => {
+                mem::forget(sem);
+                return Err(KcallError::AlreadyExists);
+            },
+            Origin::Foreign => return Err(KcallError::AlreadyExists),
+        }
+
+        sem.set_value(initial)?;
+        Ok(sem)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Opens the semaphore that is associated with a key.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the semaphore is returned.
+    /// Upon failure, an error is returned instead. If no semaphore is
+    /// associated with `key`, [`KcallError::NotFound`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The counter of the semaphore is kept.
+    /// - This is best-effort, in the same way as [`Semaphore::create`].
+    /// - The kernel creates a semaphore if none is associated with `key`. In
+    ///   that case, the semaphore is removed at once, and another process that
+    ///   gets it in between is left with a removed semaphore.
+    ///
+    pub fn open(key: u32) -> Result<Self, KcallError> {
+        let (sem, origin): (Semaphore, Origin) = Self::origin(key)?;
+        if origin == Origin::Created {
+            drop(sem);
+            return Err(KcallError::NotFound);
+        }
+
+        Ok(sem)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Opens the semaphore that is associated with a key, creating it if it
+    /// does not exist.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the semaphore.
+    /// - `initial` - Initial value for the counter of the semaphore, if it is
+    ///   created.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the semaphore is returned,
+    /// along with `true` if it was created. Upon failure, an error is returned
+    /// instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The creation flag is best-effort, in the same way as
+    ///   [`Semaphore::create`].
+    ///
+    pub fn get_or_create(
+        key: u32,
+        initial: u32,
+    ) -> Result<(Self, bool), KcallError> {
+        let (sem, origin): (Semaphore, Origin) = Self::origin(key)?;
+        let created: bool = origin == Origin::Created;
+        if created {
+            sem.set_value(initial)?;
+        }
+
+        Ok((sem, created))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the semaphore that is associated with a key, without changing its
+    /// counter.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the semaphore is returned.
+    /// Upon failure, an error is returned instead. If another handle of the
+    /// calling process already refers to the semaphore,
+    /// [`KcallError::AlreadyExists`] is returned.
+    ///
+    pub(crate) fn attach(key: u32) -> Result<Self, KcallError> {
+        let id: u32 = kcall::decode(pm::semget(key) as u32)?;
+        if hold(id) {
+            return Err(KcallError::AlreadyExists);
+        }
+
+        Ok(Semaphore { id })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the semaphore that is associated with a key, and finds out if it
+    /// was created.
+    ///
+    /// **Parameters**
+    /// - `key` - Key of the semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the semaphore is returned,
+    /// along with where it comes from. Upon failure, an error is returned
+    /// instead.
+    ///
+    /// **Notes**
+    ///
+    /// - A semaphore was not created if a handle of the calling process
+    ///   already refers to it. Otherwise, it was created if the kernel reports
+    ///   the calling process as its owner.
+    /// - Whether a handle refers to a semaphore is only known to the calling
+    ///   process, thus semaphores that it created and then left without a
+    ///   handle are mistaken for created ones.
+    ///
+    fn origin(key: u32) -> Result<(Self, Origin), KcallError> {
+        let id: u32 = kcall::decode(pm::semget(key) as u32)?;
+        if hold(id) {
+            return Ok((Semaphore { id }, Origin::Held));
+        }
+
+        let sem: Semaphore = Semaphore { id };
+        let owner: u32 =
+            kcall::decode(pm::semctl(id, SEMAPHORE_GETOWNER, 0) as u32)?;
+        if Pid::new(owner as i32) == pm::process_info(Pid::SELF)?.pid {
+            Ok((sem, Origin::Created))
+        } else {
+            Ok((sem, Origin::Foreign))
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the identifier of the target semaphore.
+    ///
+    /// **Return**
+    ///
+    /// The identifier of the target semaphore is returned.
+    ///
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Duplicates the handle to the target semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a new handle to the same semaphore is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - Handles are not [`Clone`], because each of them removes the semaphore
+    ///   when it is dropped.
+    /// - The kernel keeps a single reference to a semaphore per process, and
+    ///   removing it once drops that reference. For this reason, this function
+    ///   currently fails with [`KcallError::NotSupported`].
+    ///
+    pub fn try_clone(&self) -> Result<Self, KcallError> {
+        Err(KcallError::NotSupported)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Reads the counter of the target semaphore, without changing it.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the value of the counter is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The counter is read with the `SEMAPHORE_GETVALUE` (0) command of
+    ///   `semctl`.
+    ///
+    pub fn value(&self) -> Result<i32, KcallError> {
+        let value: u32 =
+            kcall::decode(pm::semctl(self.id, SEMAPHORE_GETVALUE, 0) as u32)?;
+        Ok(value as i32)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Overwrites the counter of the target semaphore.
+    ///
+    /// **Parameters**
+    /// - `value` - New value for the counter.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The counter is written with the `SEMAPHORE_SETVALUE` (1) command of
+    ///   `semctl`.
+    /// - The kernel lets any process that got the semaphore set its counter,
+    ///   not only the one that created it.
+    /// - The kernel forgets threads that are blocked on the semaphore, thus the
+    ///   counter should only be set while no thread waits on it.
+    ///
+    pub fn set_value(&self, value: u32) -> Result<(), KcallError> {
+        if value > i32::MAX as u32 {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        kcall::decode(pm::semctl(self.id, SEMAPHORE_SETVALUE, value) as u32)?;
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Decrements the counter of the target semaphore, blocking the calling
+    /// thread while it is zero.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    pub fn down(&self) -> Result<(), KcallError> {
+        semaphore_down(self.id)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Decrements the counter of the target semaphore, blocking the calling
+    /// thread while it is zero for at most `timeout`.
+    ///
+    /// **Parameters**
+    /// - `timeout` - Maximum number of ticks to wait.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. If the timeout expires,
+    /// [`KcallError::Again`] is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel has no timed semaphore operation. For this reason, the
+    ///   semaphore is polled with non-blocking attempts, and the calling thread
+    ///   yields the processor with [`thread::yield_now`] in between. The
+    ///   timeout is measured on the tick counter that is read with
+    ///   [`thread::ticks`], as in [`thread::sleep_ticks`].
+    ///
+    pub fn down_timeout(&self, timeout: Ticks) -> Result<(), KcallError> {
+        let mut last: u32 = thread::ticks();
+        let mut waited: Ticks = Ticks::ZERO;
+        loop {
+            if self.try_down()? {
+                return Ok(());
+            }
+            if waited >= timeout {
+                return Err(KcallError::Again);
+            }
+            thread::yield_now();
+
+            // The tick counter wraps around, thus accumulate differences.
+            let now: u32 = thread::ticks();
+            waited = waited.saturating_add(now.wrapping_sub(last).into());
+            last = now;
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attempts to decrement the counter of the target semaphore without
+    /// blocking the calling thread.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, `true` is returned if the counter was
+    /// decremented and `false` if it was zero. Upon failure, an error is
+    /// returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel reports a counter that is zero with `EADDRINUSE`, which is
+    ///   not an error here. All other errors are returned as such.
+    ///
+    pub fn try_down(&self) -> Result<bool, KcallError> {
+        match kcall::decode(pm::semop(self.id, SEMAPHORE_TRYLOCK) as u32) {
+            Ok(_) => Ok(true),
+            Err(KcallError::AddressInUse) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Increments the counter of the target semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    pub fn up(&self) -> Result<(), KcallError> {
+        semaphore_up(self.id)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Acquires the target semaphore.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a guard that releases the semaphore when
+    /// dropped is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn lock(&self) -> Result<SemaphoreGuard, KcallError> {
+        self.down()?;
+        Ok(SemaphoreGuard { sem: self })
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Drop for Semaphore {
+    fn drop(&mut self) {
+        let _ = pm::semctl(self.id, SEMAPHORE_DELETE, 0);
+        release(self.id);
+    }
+}
+
+impl<'a> Drop for SemaphoreGuard<'a> {
+    fn drop(&mut self) {
+        let _ = self.sem.up();
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Increments the counter of a semaphore that is not owned by a handle.
+///
+/// **Parameters**
+/// - `id` - Identifier of the target semaphore.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+pub(crate) fn semaphore_up(id: u32) -> Result<(), KcallError> {
+    kcall::decode(pm::semop(id, SEMAPHORE_UP) as u32)?;
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Decrements the counter of a semaphore that is not owned by a handle,
+/// blocking the calling thread while it is zero.
+///
+/// **Parameters**
+/// - `id` - Identifier of the target semaphore.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+pub(crate) fn semaphore_down(id: u32) -> Result<(), KcallError> {
+    kcall::decode(pm::semop(id, SEMAPHORE_DOWN) as u32)?;
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Attempts to decrement the counter of a semaphore that is not owned by a
+/// handle, without blocking the calling thread.
+///
+/// **Parameters**
+/// - `id` - Identifier of the target semaphore.
+///
+/// **Return**
+///
+/// Upon successful completion, `true` is returned if the counter was
+/// decremented and `false` if it was zero. Upon failure, an error is returned
+/// instead.
+///
+pub(crate) fn semaphore_try_down(id: u32) -> Result<bool, KcallError> {
+    match kcall::decode(pm::semop(id, SEMAPHORE_TRYLOCK) as u32) {
+        Ok(_) => Ok(true),
+        Err(KcallError::AddressInUse) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+///
+/// **Description**
+///
+/// Releases a semaphore that is not owned by a handle. If the calling process
+/// created the semaphore, it is removed. Otherwise, the calling process stops
+/// using it.
+///
+/// **Parameters**
+/// - `id` - Identifier of the target semaphore.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+pub(crate) fn semaphore_release(id: u32) -> Result<(), KcallError> {
+    kcall::decode(pm::semctl(id, SEMAPHORE_DELETE, 0) as u32)?;
+    Ok(())
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Records that a handle of the calling process refers to a semaphore.
+///
+/// **Parameters**
+/// - `id` - Identifier of the semaphore.
+///
+/// **Return**
+///
+/// If another handle of the calling process already referred to the
+/// semaphore, `true` is returned. Otherwise, `false` is returned instead.
+///
+fn hold(id: u32) -> bool {
+    let id: usize = id as usize;
+    if id >= SEMAPHORE_MAX {
+        return false;
+    }
+
+    let bit: u32 = 1 << (id % 32);
+    HELD[id / 32].fetch_or(bit, Ordering::AcqRel) & bit != 0
+}
+
+///
+/// **Description**
+///
+/// Records that no handle of the calling process refers to a semaphore.
+///
+/// **Parameters**
+/// - `id` - Identifier of the semaphore.
+///
+fn release(id: u32) {
+    let id: usize = id as usize;
+    if id < SEMAPHORE_MAX {
+        HELD[id / 32].fetch_and(!(1 << (id % 32)), Ordering::AcqRel);
+    }
+}
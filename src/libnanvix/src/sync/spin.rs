@@ -0,0 +1,181 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::thread;
+use core::{
+    cell::UnsafeCell,
+    hint,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A mutual exclusion primitive that spins for a bounded number of attempts
+/// and then yields the processor.
+///
+/// **Notes**
+///
+/// - The mutex does not rely on kernel objects, and it may be used in statics.
+/// - On a uniprocessor, the holder of a contended lock cannot run while
+///   another thread spins. For this reason, once the spin budget is exhausted,
+///   the waiting thread yields the processor between attempts.
+/// - Poisoning is not supported.
+///
+pub struct SpinMutex<T> {
+    /// Is the mutex locked?
+    locked: AtomicBool,
+    /// Number of attempts to acquire the mutex before yielding the processor.
+    spins: u32,
+    /// Protected data.
+    data: UnsafeCell<T>,
+}
+
+///
+/// **Description**
+///
+/// A guard that grants access to the data protected by a spin mutex and
+/// releases the mutex when it is dropped.
+///
+pub struct SpinMutexGuard<'a, T> {
+    /// Underlying mutex.
+    mutex: &'a SpinMutex<T>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<T> SpinMutex<T> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new spin mutex.
+    ///
+    /// **Parameters**
+    /// - `spins` - Number of attempts to acquire the mutex before yielding the
+    ///   processor. If zero, the processor is yielded after every failed
+    ///   attempt.
+    /// - `data` - Data to protect.
+    ///
+    /// **Return**
+    ///
+    /// The new mutex is returned in the unlocked state.
+    ///
+    pub const fn new(spins: u32, data: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            spins,
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Acquires the target mutex, spinning and then yielding the processor
+    /// until it is available.
+    ///
+    /// **Return**
+    ///
+    /// A guard to the protected data is returned.
+    ///
+    pub fn lock(&self) -> SpinMutexGuard<T> {
+        let mut spins: u32 = 0;
+        loop {
+            if let Some(guard) = self.try_lock() {
+                return guard;
+            }
+
+            // Wait for the mutex to be released before retrying.
+            while self.locked.load(Ordering::Relaxed) {
+                if spins < self.spins {
+                    spins += 1;
+                    hint::spin_loop();
+                } else {
+                    thread::yield_now();
+                }
+            }
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attempts to acquire the target mutex without waiting.
+    ///
+    /// **Return**
+    ///
+    /// If the mutex was acquired, a guard to the protected data is returned.
+    /// Otherwise, `None` is returned instead.
+    ///
+    pub fn try_lock(&self) -> Option<SpinMutexGuard<T>> {
+        match self.locked.compare_exchange(
+            false,
+            true,
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => Some(SpinMutexGuard { mutex: self }),
+            Err(_) => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target mutex and returns the protected data.
+    ///
+    /// **Return**
+    ///
+    /// The protected data is returned.
+    ///
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+unsafe impl<T: Send> Send for SpinMutex<T> {}
+
+unsafe impl<T: Send> Sync for SpinMutex<T> {}
+
+impl<'a, T> Deref for SpinMutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<'a, T> DerefMut for SpinMutexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<'a, T> Drop for SpinMutexGuard<'a, T> {
+    fn drop(&mut self) {
+        self.mutex.locked.store(false, Ordering::Release);
+    }
+}
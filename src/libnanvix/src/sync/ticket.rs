@@ -0,0 +1,200 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::thread;
+use core::{
+    cell::UnsafeCell,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A mutual exclusion primitive that grants the lock in first-come,
+/// first-served order.
+///
+/// **Notes**
+///
+/// - Every thread that attempts to acquire the mutex draws a ticket, and the
+///   mutex serves tickets in the order that they were drawn. Unlike
+///   [`crate::sync::SpinMutex`], a waiting thread thus cannot be overtaken.
+/// - The mutex does not rely on kernel objects, and it may be used in statics.
+/// - Waiting threads yield the processor between attempts.
+/// - Poisoning is not supported.
+///
+pub struct TicketMutex<T> {
+    /// Next ticket to draw.
+    next: AtomicU32,
+    /// Ticket that is being served.
+    serving: AtomicU32,
+    /// Protected data.
+    data: UnsafeCell<T>,
+}
+
+///
+/// **Description**
+///
+/// A guard that grants access to the data protected by a ticket mutex and
+/// serves the next ticket when it is dropped.
+///
+pub struct TicketMutexGuard<'a, T> {
+    /// Underlying mutex.
+    mutex: &'a TicketMutex<T>,
+    /// Ticket that was served.
+    ticket: u32,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<T> TicketMutex<T> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a new ticket mutex.
+    ///
+    /// **Parameters**
+    /// - `data` - Data to protect.
+    ///
+    /// **Return**
+    ///
+    /// The new mutex is returned in the unlocked state.
+    ///
+    pub const fn new(data: T) -> Self {
+        Self {
+            next: AtomicU32::new(0),
+            serving: AtomicU32::new(0),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Acquires the target mutex, yielding the processor until the ticket of
+    /// the calling thread is served.
+    ///
+    /// **Return**
+    ///
+    /// A guard to the protected data is returned.
+    ///
+    pub fn lock(&self) -> TicketMutexGuard<T> {
+        let ticket: u32 = self.next.fetch_add(1, Ordering::Relaxed);
+        while self.serving.load(Ordering::Acquire) != ticket {
+            thread::yield_now();
+        }
+
+        TicketMutexGuard {
+            mutex: self,
+            ticket,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attempts to acquire the target mutex without waiting.
+    ///
+    /// **Return**
+    ///
+    /// If the mutex was acquired, a guard to the protected data is returned.
+    /// Otherwise, `None` is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - A ticket is only drawn if it would be served right away, thus a
+    ///   failed attempt does not hold back other threads.
+    ///
+    pub fn try_lock(&self) -> Option<TicketMutexGuard<T>> {
+        let ticket: u32 = self.serving.load(Ordering::Acquire);
+        match self.next.compare_exchange(
+            ticket,
+            ticket.wrapping_add(1),
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => Some(TicketMutexGuard {
+                mutex: self,
+                ticket,
+            }),
+            Err(_) => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Consumes the target mutex and returns the protected data.
+    ///
+    /// **Return**
+    ///
+    /// The protected data is returned.
+    ///
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<'a, T> TicketMutexGuard<'a, T> {
+    ///
+    /// **Description**
+    ///
+    /// Gets the ticket that the target guard was served with.
+    ///
+    /// **Return**
+    ///
+    /// The ticket of the target guard. Tickets are drawn in increasing order,
+    /// and wrap around on overflow.
+    ///
+    pub fn ticket(&self) -> u32 {
+        self.ticket
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+unsafe impl<T: Send> Send for TicketMutex<T> {}
+
+unsafe impl<T: Send> Sync for TicketMutex<T> {}
+
+impl<'a, T> Deref for TicketMutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<'a, T> DerefMut for TicketMutexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<'a, T> Drop for TicketMutexGuard<'a, T> {
+    fn drop(&mut self) {
+        self.mutex
+            .serving
+            .store(self.ticket.wrapping_add(1), Ordering::Release);
+    }
+}
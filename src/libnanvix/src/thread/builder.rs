@@ -0,0 +1,327 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use super::{
+    stack::Stack,
+    JoinHandle,
+};
+use crate::{
+    kcall::KcallError,
+    pm::{
+        Tid,
+        THREADS_MAX,
+    },
+    sync::SpinMutex,
+};
+use core::{
+    convert::TryFrom,
+    fmt,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum length of the name of a thread (in bytes).
+pub const THREAD_NAME_MAX: usize = 16;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Names of threads, indexed by thread ID.
+static NAMES: SpinMutex<[Option<ThreadName>; THREADS_MAX]> =
+    SpinMutex::new(0, [None; THREADS_MAX]);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// The name of a thread.
+///
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ThreadName {
+    /// Underlying bytes.
+    buf: [u8; THREAD_NAME_MAX],
+    /// Length of the name (in bytes).
+    len: usize,
+}
+
+///
+/// **Description**
+///
+/// A factory of threads with a custom configuration.
+///
+/// **Notes**
+///
+/// - Threads that are spawned without a custom stack size run on the stack
+///   that is allocated by the kernel, as with [`crate::thread::spawn`].
+///
+/// **Example**
+///
+/// ```ignore
+/// let handle: JoinHandle = thread::Builder::new()
+///     .stack_size(8 * 1024)
+///     .name("worker")
+///     .spawn(worker)?;
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Builder<'a> {
+    /// Size of the stack (in bytes), if a custom one is requested.
+    stack_size: Option<usize>,
+    /// Name of the thread, if any.
+    name: Option<&'a str>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl ThreadName {
+    ///
+    /// **Description**
+    ///
+    /// Creates the name of a thread.
+    ///
+    /// **Parameters**
+    /// - `name` - Name of the thread.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the name is returned. If `name` is empty or
+    /// longer than [`THREAD_NAME_MAX`] bytes, [`KcallError::InvalidArgument`]
+    /// is returned instead.
+    ///
+    pub fn new(name: &str) -> Result<Self, KcallError> {
+        let bytes: &[u8] = name.as_bytes();
+        if bytes.is_empty() || bytes.len() > THREAD_NAME_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let mut buf: [u8; THREAD_NAME_MAX] = [0; THREAD_NAME_MAX];
+        buf[..bytes.len()].copy_from_slice(bytes);
+
+        Ok(Self {
+            buf,
+            len: bytes.len(),
+        })
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the target name as a string slice.
+    ///
+    /// **Return**
+    ///
+    /// The target name.
+    ///
+    pub fn as_str(&self) -> &str {
+        // The name was copied from a string slice.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+impl<'a> Builder<'a> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a factory of threads with the default configuration.
+    ///
+    /// **Return**
+    ///
+    /// A factory of unnamed threads that run on the stack that is allocated by
+    /// the kernel.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            stack_size: None,
+            name: None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the size of the stack of spawned threads.
+    ///
+    /// **Parameters**
+    /// - `size` - Size of the stack (in bytes). It is rounded up to a multiple
+    ///   of the page size, and it may be at most
+    ///   [`crate::thread::THREAD_STACK_MAX`].
+    ///
+    /// **Return**
+    ///
+    /// The target factory.
+    ///
+    pub const fn stack_size(mut self, size: usize) -> Self {
+        self.stack_size = Some(size);
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sets the name of spawned threads.
+    ///
+    /// **Parameters**
+    /// - `name` - Name of the threads. It may be at most [`THREAD_NAME_MAX`]
+    ///   bytes long.
+    ///
+    /// **Return**
+    ///
+    /// The target factory.
+    ///
+    pub const fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Spawns a new thread with the configuration of the target factory.
+    ///
+    /// **Parameters**
+    /// - `f` - Closure to run in the new thread.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, a handle to the spawned thread is returned.
+    /// Upon failure, an error is returned instead. If the stack size or the
+    /// name are invalid, [`KcallError::InvalidArgument`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The kernel always hands out a stack of its own to a new thread. If a
+    ///   stack size is set, page frames are allocated and mapped for the stack,
+    ///   and the new thread switches to it before it runs `f`.
+    /// - The stack is released when `f` returns. If the thread exits with
+    ///   [`crate::thread::exit`] instead, the stack is released when the
+    ///   thread is joined, and a detached thread leaks it.
+    ///
+    pub fn spawn<F>(self, f: F) -> Result<JoinHandle, KcallError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let name: Option<ThreadName> = match self.name {
+            Some(name) => Some(ThreadName::new(name)?),
+            None => None,
+        };
+        let stack: Option<Stack> = match self.stack_size {
+            Some(size) => Some(Stack::alloc(size)?),
+            None => None,
+        };
+
+        // If the thread may not be spawned, the stack is dropped along with
+        // the closure.
+        super::spawn(move || {
+            let tid: Tid = super::current();
+            set_name(tid, name);
+            match stack {
+                Some(stack) => stack.run(tid, f),
+                None => f(),
+            }
+            set_name(tid, None);
+        })
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl fmt::Debug for ThreadName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for ThreadName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Gets the name of a thread.
+///
+/// **Parameters**
+/// - `tid` - ID of the target thread, as reported by
+///   [`crate::kcall::KcallNumbers::ThreadGet`].
+///
+/// **Return**
+///
+/// If the target thread was spawned with a name by a [`Builder`] and it is
+/// still running, its name is returned. Otherwise, `None` is returned instead.
+///
+pub fn name_of(tid: Tid) -> Option<ThreadName> {
+    let index: usize = index(tid)?;
+    NAMES.lock()[index]
+}
+
+///
+/// **Description**
+///
+/// Gets the name of the calling thread.
+///
+/// **Return**
+///
+/// If the calling thread was spawned with a name by a [`Builder`], its name is
+/// returned. Otherwise, `None` is returned instead.
+///
+pub fn current_name() -> Option<ThreadName> {
+    name_of(super::current())
+}
+
+///
+/// **Description**
+///
+/// Sets the name of a thread.
+///
+/// **Parameters**
+/// - `tid` - ID of the target thread.
+/// - `name` - Name of the thread, if any.
+///
+pub(super) fn set_name(tid: Tid, name: Option<ThreadName>) {
+    if let Some(index) = index(tid) {
+        NAMES.lock()[index] = name;
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Converts a thread ID into an index in the table of names.
+///
+/// **Parameters**
+/// - `tid` - ID of the thread.
+///
+/// **Return**
+///
+/// If `tid` is in range, the corresponding index is returned. Otherwise,
+/// `None` is returned instead.
+///
+fn index(tid: Tid) -> Option<usize> {
+    usize::try_from(tid).ok().filter(|tid| *tid < THREADS_MAX)
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::pm::Tid;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Identity of a thread.
+///
+/// **Notes**
+///
+/// - Thread IDs are reused by the kernel once a thread is gone, thus two IDs
+///   only tell threads apart while both threads are alive.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ThreadId(Tid);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl ThreadId {
+    ///
+    /// **Description**
+    ///
+    /// Creates a thread identity from a raw thread ID.
+    ///
+    /// **Parameters**
+    /// - `tid` - Raw thread ID.
+    ///
+    /// **Return**
+    ///
+    /// The identity of the thread whose raw ID is `tid`.
+    ///
+    pub const fn from_raw(tid: Tid) -> Self {
+        Self(tid)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw thread ID of the target identity.
+    ///
+    /// **Return**
+    ///
+    /// The raw thread ID of the target identity.
+    ///
+    pub const fn as_raw(&self) -> Tid {
+        self.0
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl From<Tid> for ThreadId {
+    fn from(tid: Tid) -> Self {
+        Self(tid)
+    }
+}
+
+impl From<ThreadId> for Tid {
+    fn from(id: ThreadId) -> Self {
+        id.0
+    }
+}
+
+impl From<ThreadId> for u32 {
+    fn from(id: ThreadId) -> Self {
+        id.0 as u32
+    }
+}
@@ -0,0 +1,197 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    excp::{
+        self,
+        ExceptionInfo,
+        ExceptionKind,
+    },
+    kcall::KcallError,
+    memory::{
+        VirtualAddress,
+        PAGE_SIZE,
+        USER_BASE_ADDRESS,
+    },
+    pm::{
+        self,
+        ExitStatus,
+        Pid,
+        Tid,
+    },
+    thread::{
+        self,
+        JoinHandle,
+    },
+};
+use core::mem;
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Address that an isolated worker reads once it finishes. It lies in kernel
+/// memory, thus the read faults whatever pages the calling process maps.
+const DOORBELL: VirtualAddress = USER_BASE_ADDRESS - PAGE_SIZE;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Defers page faults back to the kernel once dropped.
+///
+struct DeferGuard;
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Drop for DeferGuard {
+    fn drop(&mut self) {
+        let _ = excp::control(ExceptionKind::PageFault, false);
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Spawns a worker thread whose page faults do not take down the calling
+/// process.
+///
+/// **Parameters**
+/// - `f` - Closure to run in the worker thread.
+///
+/// **Return**
+///
+/// Upon successful completion, a handle to the worker is returned. Upon
+/// failure, an error is returned instead. If another isolated worker runs or
+/// another process handles page faults, [`KcallError::Busy`] is returned.
+///
+/// **Notes**
+///
+/// - The calling process handles page faults while the worker runs. A
+///   supervisor thread terminates the worker on its first page fault, thus `f`
+///   never runs past a fault. Values that `f` owns are leaked, and locks that
+///   it holds stay held.
+/// - Joining the handle reports [`ExitStatus::Faulted`] with the kind of the
+///   fault, or a clean exit if `f` did not fault. See
+///   [`JoinHandle::join_status`].
+/// - The kernel wakes an exception handler only with an exception. For this
+///   reason, the worker signals that it finished by reading kernel memory.
+///   Thus, `f` should return rather than calling [`thread::exit`].
+/// - Other exceptions are not handled. Page faults of other threads are not
+///   fixed either: they would take down the system if the kernel handled
+///   them, thus the faulting threads are terminated.
+///
+/// **Example**
+///
+/// ```ignore
+/// let worker: JoinHandle = thread::spawn_isolated(|| parse(input))?;
+/// if let ExitStatus::Faulted(kind) = worker.join_status()? {
+///     nanvix::log!("worker faulted ({:?})", kind);
+/// }
+/// ```
+///
+pub fn spawn_isolated<F>(f: F) -> Result<JoinHandle, KcallError>
+where
+    F: FnOnce() + Send + 'static,
+{
+    let pid: Pid = pm::process_info(Pid::SELF)?.pid;
+    excp::control(ExceptionKind::PageFault, true)?;
+    let guard: DeferGuard = DeferGuard;
+
+    let worker: JoinHandle = thread::spawn(move || {
+        f();
+        ring_doorbell();
+    })?;
+
+    // From now on, the supervisor defers page faults once it is done.
+    mem::forget(guard);
+
+    // The worker already runs, thus it is supervised by the calling thread if
+    // the supervisor may not be spawned.
+    let tid: Tid = worker.tid();
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+        let status: ExitStatus = supervise(pid, JoinHandle { tid });
+        thread::exit(status.raw() as i32);
+    });
+    match ret {
+        Ok(supervisor) => {
+            mem::forget(worker);
+            Ok(supervisor)
+        },
+        Err(e) => {
+            supervise(pid, worker);
+            Err(e)
+        },
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Handles the page faults of an isolated worker until it finishes.
+///
+/// **Parameters**
+/// - `pid` - ID of the calling process.
+/// - `worker` - Handle to the worker.
+///
+/// **Return**
+///
+/// The exit status of the worker.
+///
+fn supervise(pid: Pid, worker: JoinHandle) -> ExitStatus {
+    let _guard: DeferGuard = DeferGuard;
+
+    loop {
+        let info: ExceptionInfo = match excp::wait_uninterrupted() {
+            Ok(info) => info,
+            Err(_) => return ExitStatus::Faulted(ExceptionKind::PageFault),
+        };
+
+        // Terminate faulting threads other than the worker.
+        if info.pid != pid || info.tid != worker.tid() {
+            let _ = excp::terminate(&info);
+            continue;
+        }
+
+        // Terminate the worker, whether it faulted or finished.
+        let finished: bool = info.address & !(PAGE_SIZE - 1) == DOORBELL;
+        if excp::terminate(&info).is_ok() {
+            let _ = worker.join();
+        }
+
+        return if finished {
+            ExitStatus::Exited(0)
+        } else {
+            ExitStatus::Faulted(info.kind)
+        };
+    }
+}
+
+///
+/// **Description**
+///
+/// Signals the supervisor of an isolated worker that the worker finished.
+///
+fn ring_doorbell() {
+    // The read faults, and the supervisor terminates the worker.
+    let _ = unsafe { core::ptr::read_volatile(DOORBELL as *const u8) };
+}
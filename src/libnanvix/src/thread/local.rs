@@ -0,0 +1,247 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::pm::{
+    self,
+    Tid,
+    THREADS_MAX,
+};
+use core::{
+    cell::{
+        Cell,
+        UnsafeCell,
+    },
+    convert::TryFrom,
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Number of threads that have been started on each thread ID.
+static GENERATIONS: [AtomicU32; THREADS_MAX] = [ZERO_GENERATION; THREADS_MAX];
+
+/// Initial generation of a thread ID.
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Value of a thread-local variable for one thread ID.
+///
+struct Slot<T> {
+    /// Generation of the thread ID when the value was initialized.
+    generation: Cell<u32>,
+    /// Value, if initialized.
+    value: UnsafeCell<Option<T>>,
+}
+
+///
+/// **Description**
+///
+/// A thread-local variable.
+///
+/// **Notes**
+///
+/// - Each thread lazily initializes its own value on first access.
+/// - Values are stored in a fixed table indexed by thread ID. When a thread ID
+///   is reused by a thread that is spawned with [`crate::thread::spawn`], the
+///   value left by the former thread is dropped and initialized again.
+/// - Values are not dropped when a thread exits.
+/// - Use the [`crate::thread_local`] macro to declare thread-local variables.
+///
+pub struct ThreadLocal<T: 'static> {
+    /// Initializer of values.
+    init: fn() -> T,
+    /// Table of values.
+    slots: [Slot<T>; THREADS_MAX],
+}
+
+// Each slot is only accessed by the thread whose ID indexes it.
+unsafe impl<T: Send> Sync for ThreadLocal<T> {}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<T> Slot<T> {
+    /// Empty slot.
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY: Slot<T> = Slot {
+        generation: Cell::new(0),
+        value: UnsafeCell::new(None),
+    };
+}
+
+impl<T: 'static> ThreadLocal<T> {
+    ///
+    /// **Description**
+    ///
+    /// Creates a thread-local variable.
+    ///
+    /// **Parameters**
+    /// - `init` - Initializer of values.
+    ///
+    /// **Return**
+    ///
+    /// A thread-local variable whose values are initialized by `init`.
+    ///
+    pub const fn new(init: fn() -> T) -> Self {
+        Self {
+            init,
+            slots: [Slot::EMPTY; THREADS_MAX],
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Accesses the value of the calling thread.
+    ///
+    /// **Parameters**
+    /// - `f` - Closure that receives the value.
+    ///
+    /// **Return**
+    ///
+    /// The value returned by `f`.
+    ///
+    /// **Notes**
+    ///
+    /// - This function panics if the ID of the calling thread is out of range.
+    ///
+    pub fn with<F, R>(&'static self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        match self.try_with(f) {
+            Some(ret) => ret,
+            None => panic!("thread id out of range"),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Attempts to access the value of the calling thread.
+    ///
+    /// **Parameters**
+    /// - `f` - Closure that receives the value.
+    ///
+    /// **Return**
+    ///
+    /// If the ID of the calling thread is in range, the value returned by `f`
+    /// is returned. Otherwise, `None` is returned instead.
+    ///
+    pub fn try_with<F, R>(&'static self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let tid: usize = index(pm::thread_getid())?;
+        let slot: &Slot<T> = &self.slots[tid];
+        let generation: u32 = GENERATIONS[tid].load(Ordering::Acquire);
+
+        // SAFETY: the slot is only accessed by the calling thread.
+        let initialized: bool = unsafe { (*slot.value.get()).is_some() };
+
+        // Initialize the value, if the calling thread did not do it yet. No
+        // reference to the value may be alive at this point, because it was
+        // either never initialized or left by a former thread.
+        if !initialized || slot.generation.get() != generation {
+            let value: T = (self.init)();
+            unsafe { *slot.value.get() = Some(value) };
+            slot.generation.set(generation);
+        }
+
+        let value: &T = unsafe { (*slot.value.get()).as_ref()? };
+
+        Some(f(value))
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Records that a thread started on a thread ID.
+///
+/// **Parameters**
+/// - `tid` - ID of the thread.
+///
+pub(super) fn start(tid: Tid) {
+    if let Some(tid) = index(tid) {
+        GENERATIONS[tid].fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Converts a thread ID into an index in the table of values.
+///
+/// **Parameters**
+/// - `tid` - ID of the thread.
+///
+/// **Return**
+///
+/// If `tid` is in range, the corresponding index is returned. Otherwise,
+/// `None` is returned instead.
+///
+fn index(tid: Tid) -> Option<usize> {
+    usize::try_from(tid).ok().filter(|tid| *tid < THREADS_MAX)
+}
+
+//==============================================================================
+// Macros
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Declares thread-local variables.
+///
+/// **Example**
+///
+/// ```ignore
+/// nanvix::thread_local! {
+///     static COUNTER: Cell<u32> = Cell::new(0);
+/// }
+///
+/// COUNTER.with(|counter| counter.set(counter.get() + 1));
+/// ```
+///
+#[macro_export]
+macro_rules! thread_local {
+    () => {};
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty = $init:expr;
+     $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis static $name: $crate::thread::ThreadLocal<$t> =
+            $crate::thread::ThreadLocal::new(|| $init);
+        $crate::thread_local!($($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty = $init:expr) => {
+        $crate::thread_local!($(#[$attr])* $vis static $name: $t = $init;);
+    };
+}
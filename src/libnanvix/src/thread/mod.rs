@@ -0,0 +1,513 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Modules
+//==============================================================================
+
+mod builder;
+mod id;
+mod isolate;
+mod local;
+mod park;
+#[cfg(feature = "alloc")]
+mod pool;
+mod registry;
+mod stack;
+
+//==============================================================================
+// Exports
+//==============================================================================
+
+#[cfg(feature = "alloc")]
+pub use self::pool::Pool;
+pub use self::{
+    builder::{
+        current_name,
+        name_of,
+        Builder,
+        ThreadName,
+        THREAD_NAME_MAX,
+    },
+    id::ThreadId,
+    isolate::spawn_isolated,
+    local::ThreadLocal,
+    park::{
+        park,
+        unpark,
+    },
+    registry::Registry,
+    stack::{
+        THREAD_STACKS_MAX,
+        THREAD_STACK_MAX,
+    },
+};
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::{
+        self,
+        KcallError,
+        KcallNumbers,
+    },
+    pm::{
+        self,
+        ffi,
+        ExitStatus,
+        Tid,
+    },
+    time::Ticks,
+};
+use core::{
+    mem::ManuallyDrop,
+    sync::atomic::{
+        AtomicBool,
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Number of times that threads of the calling process yielded the processor.
+static YIELDS: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Types
+//==============================================================================
+
+/// Entry point of a spawned thread, called with the C calling convention.
+type Entry = extern "C" fn(*mut ffi::c_void) -> *mut ffi::c_void;
+
+/// Function that the kernel runs first in a spawned thread, with the entry
+/// point and its argument.
+type Caller = extern "C" fn(Entry, *mut ffi::c_void) -> !;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Closure that is handed over from a parent thread to a spawned thread.
+///
+struct Packet<F> {
+    /// Closure to run.
+    f: ManuallyDrop<F>,
+    /// Asserted once the spawned thread has taken the closure.
+    taken: AtomicBool,
+}
+
+///
+/// **Description**
+///
+/// An owned permission to join on a thread.
+///
+/// **Notes**
+///
+/// - The thread is detached if the handle is dropped without being joined.
+///
+#[derive(Debug)]
+pub struct JoinHandle {
+    /// ID of the target thread.
+    tid: Tid,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl JoinHandle {
+    ///
+    /// **Description**
+    ///
+    /// Gets the ID of the target thread.
+    ///
+    /// **Return**
+    ///
+    /// The ID of the target thread is returned.
+    ///
+    pub fn tid(&self) -> Tid {
+        self.tid
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the identity of the target thread.
+    ///
+    /// **Return**
+    ///
+    /// The identity of the target thread is returned.
+    ///
+    pub fn id(&self) -> ThreadId {
+        ThreadId::from_raw(self.tid)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Waits for the target thread to terminate.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the exit code of the target thread is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    pub fn join(self) -> Result<i32, KcallError> {
+        let handle: ManuallyDrop<JoinHandle> = ManuallyDrop::new(self);
+        let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+        unsafe {
+            kcall::kcall2_checked(
+                KcallNumbers::ThreadJoin as u32,
+                handle.tid as u32,
+                &mut retval as *mut *mut ffi::c_void as u32,
+            )?;
+        }
+
+        // Release the stack that an exited thread left behind, if any.
+        stack::reap(handle.tid);
+
+        Ok(retval as i32)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Waits for the target thread to terminate and decodes how it terminated.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the exit status of the target thread is
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    pub fn join_status(self) -> Result<ExitStatus, KcallError> {
+        Ok(ExitStatus::from_raw(self.join()? as u32))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Detaches the target thread.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    pub fn detach(self) -> Result<(), KcallError> {
+        let handle: ManuallyDrop<JoinHandle> = ManuallyDrop::new(self);
+        detach(handle.tid)
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Drop for JoinHandle {
+    fn drop(&mut self) {
+        let _ = detach(self.tid);
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Spawns a new thread.
+///
+/// **Parameters**
+/// - `f` - Closure to run in the new thread.
+///
+/// **Return**
+///
+/// Upon successful completion, a handle to the spawned thread is returned.
+/// Upon failure, an error is returned instead.
+///
+/// **Notes**
+///
+/// - The stack of the spawned thread is allocated by the kernel.
+/// - The spawned thread exits with code zero when `f` returns. Use [`exit`]
+///   to exit with a different code.
+/// - The calling thread yields until the spawned thread has taken `f`.
+///
+pub fn spawn<F>(f: F) -> Result<JoinHandle, KcallError>
+where
+    F: FnOnce() + Send + 'static,
+{
+    let packet: Packet<F> = Packet {
+        f: ManuallyDrop::new(f),
+        taken: AtomicBool::new(false),
+    };
+
+    let ret: Result<u32, KcallError> = unsafe {
+        kcall::kcall3_checked(
+            KcallNumbers::ThreadCreate as u32,
+            trampoline::<F> as Entry as usize as u32,
+            &packet as *const Packet<F> as u32,
+            caller as Caller as usize as u32,
+        )
+    };
+
+    match ret {
+        Ok(tid) => {
+            // Wait for the spawned thread to take the closure.
+            while !packet.taken.load(Ordering::Acquire) {
+                yield_now();
+            }
+            Ok(JoinHandle { tid: tid as Tid })
+        },
+        Err(e) => {
+            drop(ManuallyDrop::into_inner(packet.f));
+            Err(e)
+        },
+    }
+}
+
+///
+/// **Description**
+///
+/// Waits for several threads to terminate.
+///
+/// **Parameters**
+/// - `handles` - Handles to the target threads.
+///
+/// **Return**
+///
+/// The results of joining the target threads, in the order of `handles`.
+///
+/// **Notes**
+///
+/// - Every thread is joined, even if joining a previous one fails.
+///
+pub fn join_all<const N: usize>(
+    handles: [JoinHandle; N],
+) -> [Result<i32, KcallError>; N] {
+    handles.map(JoinHandle::join)
+}
+
+///
+/// **Description**
+///
+/// Terminates the calling thread.
+///
+/// **Parameters**
+/// - `code` - Exit code.
+///
+/// **Notes**
+///
+/// - If the calling thread runs on a stack that was allocated by a
+///   [`Builder`], the stack is released when the thread is joined.
+///
+pub fn exit(code: i32) -> ! {
+    let tid: Tid = current();
+    builder::set_name(tid, None);
+    stack::exit(tid);
+
+    unsafe {
+        kcall::kcall1(KcallNumbers::ThreadExit as u32, code as u32);
+    }
+    // Never gets here.
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+///
+/// **Description**
+///
+/// Yields the processor to another thread.
+///
+pub fn yield_now() {
+    pm::thread_yield();
+    YIELDS.fetch_add(1, Ordering::Relaxed);
+}
+
+///
+/// **Description**
+///
+/// Yields the processor and hands the identity of a thread over to the kernel.
+///
+/// **Parameters**
+/// - `id` - Identity of a thread.
+///
+/// **Notes**
+///
+/// - This is not a directed yield. The kernel currently ignores the identity
+///   that is passed as the argument of [`KcallNumbers::ThreadYield`], thus
+///   this behaves as [`yield_now`]: the processor goes to any ready thread,
+///   and the thread named by `id` is no more likely to run next.
+/// - Callers that need a thread to run before they continue should wait on it,
+///   for instance with [`park`] and [`unpark`].
+///
+pub fn yield_to(id: ThreadId) {
+    unsafe {
+        kcall::kcall1(KcallNumbers::ThreadYield as u32, id.as_raw() as u32);
+    }
+    YIELDS.fetch_add(1, Ordering::Relaxed);
+}
+
+///
+/// **Description**
+///
+/// Reads the monotonic tick counter.
+///
+/// **Return**
+///
+/// The current value of the tick counter is returned. The counter wraps around
+/// on overflow.
+///
+/// **Notes**
+///
+/// - The kernel offers no clock to user space. For this reason, the counter is
+///   the number of times that threads of the calling process yielded the
+///   processor with [`yield_now`] or [`yield_to`]. It only advances while the
+///   calling process yields, and it is not shared with other processes.
+///
+pub fn ticks() -> u32 {
+    YIELDS.load(Ordering::Relaxed)
+}
+
+///
+/// **Description**
+///
+/// Suspends the calling thread for a span of ticks.
+///
+/// **Parameters**
+/// - `duration` - Number of ticks to sleep.
+///
+/// **Notes**
+///
+/// - The kernel has no sleep kernel call. For this reason, the calling thread
+///   yields the processor until the tick counter that is read with [`ticks`]
+///   has advanced by at least `duration`.
+/// - A tick is one yield, thus the actual time slept depends on the load of
+///   the system, and the calling thread may oversleep by as long as other
+///   threads keep the processor.
+///
+pub fn sleep_ticks(duration: Ticks) {
+    let mut last: u32 = ticks();
+    let mut elapsed: Ticks = Ticks::ZERO;
+    while elapsed < duration {
+        yield_now();
+
+        // The tick counter wraps around, thus accumulate differences.
+        let now: u32 = ticks();
+        elapsed = elapsed.saturating_add(now.wrapping_sub(last).into());
+        last = now;
+    }
+}
+
+///
+/// **Description**
+///
+/// Gets the ID of the calling thread.
+///
+/// **Return**
+///
+/// The ID of the calling thread is returned.
+///
+pub fn current() -> Tid {
+    pm::thread_getid()
+}
+
+///
+/// **Description**
+///
+/// Gets the identity of the calling thread.
+///
+/// **Return**
+///
+/// The identity of the calling thread is returned.
+///
+pub fn current_id() -> ThreadId {
+    ThreadId::from_raw(pm::thread_getid())
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Detaches a thread.
+///
+/// **Parameters**
+/// - `tid` - ID of the target thread.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+fn detach(tid: Tid) -> Result<(), KcallError> {
+    unsafe {
+        kcall::kcall1_checked(KcallNumbers::ThreadDetach as u32, tid as u32)?;
+    }
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Runs the entry point of a spawned thread and terminates the thread with the
+/// value that it returns.
+///
+/// **Parameters**
+/// - `start` - Entry point of the thread.
+/// - `arg` - Argument of the entry point.
+///
+extern "C" fn caller(start: Entry, arg: *mut ffi::c_void) -> ! {
+    let ret: *mut ffi::c_void = start(arg);
+    unsafe {
+        kcall::kcall1(KcallNumbers::ThreadExit as u32, ret as u32);
+    }
+    // Never gets here.
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+///
+/// **Description**
+///
+/// Entry point of a spawned thread.
+///
+/// **Parameters**
+/// - `arg` - Packet that holds the closure to run.
+///
+/// **Return**
+///
+/// The exit code of the thread is returned.
+///
+extern "C" fn trampoline<F>(arg: *mut ffi::c_void) -> *mut ffi::c_void
+where
+    F: FnOnce() + Send + 'static,
+{
+    let packet: &Packet<F> = unsafe { &*(arg as *const Packet<F>) };
+
+    self::local::start(current());
+    self::park::start(current());
+    self::builder::set_name(current(), None);
+
+    // Take the closure and release the parent thread.
+    let f: F = unsafe { core::ptr::read(&*packet.f) };
+    packet.taken.store(true, Ordering::Release);
+
+    f();
+
+    core::ptr::null_mut()
+}
@@ -0,0 +1,234 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    kcall::KcallError,
+    pm::{
+        self,
+        Pid,
+        Tid,
+        THREADS_MAX,
+    },
+    sync::{
+        self,
+        Semaphore,
+        SpinMutex,
+        SpinMutexGuard,
+    },
+    thread::{
+        self,
+        ThreadId,
+    },
+};
+use core::{
+    convert::TryFrom,
+    mem::ManuallyDrop,
+    sync::atomic::{
+        AtomicI32,
+        Ordering,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Base key of the semaphores that parked threads block on.
+const PARK_KEY_BASE: u32 = 0x4000_0000;
+
+/// The thread is running and holds no token.
+const EMPTY: i32 = 0;
+
+/// The thread holds a token.
+const NOTIFIED: i32 = 1;
+
+/// The thread is parked, or about to park.
+const PARKED: i32 = -1;
+
+/// Initial state of a thread ID.
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_STATE: AtomicI32 = AtomicI32::new(EMPTY);
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Park states of threads, indexed by thread ID.
+static STATES: [AtomicI32; THREADS_MAX] = [EMPTY_STATE; THREADS_MAX];
+
+/// Identifiers of the semaphores that parked threads block on, indexed by
+/// thread ID.
+static SEMAPHORES: SpinMutex<[Option<u32>; THREADS_MAX]> =
+    SpinMutex::new(0, [None; THREADS_MAX]);
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Blocks the calling thread until it is unparked.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+/// **Notes**
+///
+/// - Every thread holds at most one token. If the calling thread holds it, the
+///   token is consumed and this function returns at once. Thus, an [`unpark`]
+///   that arrives before the matching park is not lost.
+/// - The calling thread blocks on a kernel semaphore that is created when it
+///   is first needed. If no semaphore is available, the calling thread yields
+///   the processor until it is unparked instead.
+/// - This function may return without a matching [`unpark`], thus callers
+///   should check the condition that they wait for again.
+///
+/// **Example**
+///
+/// ```ignore
+/// while !ready.load(Ordering::Acquire) {
+///     thread::park()?;
+/// }
+/// ```
+///
+pub fn park() -> Result<(), KcallError> {
+    let tid: usize =
+        index(thread::current()).ok_or(KcallError::InvalidArgument)?;
+    let state: &AtomicI32 = &STATES[tid];
+
+    // Consume the token, if any.
+    if state.fetch_sub(1, Ordering::Acquire) == NOTIFIED {
+        return Ok(());
+    }
+
+    loop {
+        match semaphore(tid) {
+            Ok(id) => sync::semaphore_down(id)?,
+            Err(_) => thread::yield_now(),
+        }
+
+        if state
+            .compare_exchange(
+                NOTIFIED,
+                EMPTY,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+        {
+            return Ok(());
+        }
+    }
+}
+
+///
+/// **Description**
+///
+/// Hands a token over to a thread, waking it up if it is parked.
+///
+/// **Parameters**
+/// - `id` - Identity of the target thread.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+/// **Notes**
+///
+/// - Tokens do not accumulate, thus unparking a thread that already holds one
+///   has no effect.
+///
+pub fn unpark(id: ThreadId) -> Result<(), KcallError> {
+    let tid: usize = index(id.as_raw()).ok_or(KcallError::InvalidArgument)?;
+
+    if STATES[tid].swap(NOTIFIED, Ordering::Release) != PARKED {
+        return Ok(());
+    }
+
+    match semaphore(tid) {
+        Ok(id) => sync::semaphore_up(id),
+        // The target thread yields until it sees the token.
+        Err(_) => Ok(()),
+    }
+}
+
+///
+/// **Description**
+///
+/// Records that a thread started on a thread ID.
+///
+/// **Parameters**
+/// - `tid` - ID of the thread.
+///
+/// **Notes**
+///
+/// - Tokens that were handed over to a previous thread with the same ID are
+///   dropped.
+///
+pub(super) fn start(tid: Tid) {
+    if let Some(tid) = index(tid) {
+        STATES[tid].store(EMPTY, Ordering::Release);
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Gets the semaphore that a thread blocks on while it is parked.
+///
+/// **Parameters**
+/// - `tid` - Index of the thread.
+///
+/// **Return**
+///
+/// Upon successful completion, the identifier of the semaphore is returned.
+/// Upon failure, an error is returned instead.
+///
+fn semaphore(tid: usize) -> Result<u32, KcallError> {
+    let mut semaphores: SpinMutexGuard<[Option<u32>; THREADS_MAX]> =
+        SEMAPHORES.lock();
+    if let Some(id) = semaphores[tid] {
+        return Ok(id);
+    }
+
+    let pid: Pid = pm::process_info(Pid::SELF)?.pid;
+    let key: u32 =
+        PARK_KEY_BASE | ((u32::from(pid) & 0x7fff) << 16) | tid as u32;
+    // The semaphore outlives the thread, so that later threads with the same
+    // ID block on it as well.
+    let id: u32 = ManuallyDrop::new(Semaphore::get(key, 0)?).id();
+
+    semaphores[tid] = Some(id);
+    Ok(id)
+}
+
+///
+/// **Description**
+///
+/// Converts a thread ID into an index in the table of park states.
+///
+/// **Parameters**
+/// - `tid` - ID of the thread.
+///
+/// **Return**
+///
+/// If `tid` is in range, the corresponding index is returned. Otherwise,
+/// `None` is returned instead.
+///
+fn index(tid: Tid) -> Option<usize> {
+    usize::try_from(tid).ok().filter(|tid| *tid < THREADS_MAX)
+}
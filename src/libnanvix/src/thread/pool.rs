@@ -0,0 +1,264 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use super::JoinHandle;
+use crate::{
+    ipc::{
+        Receiver,
+        Sender,
+    },
+    kcall::KcallError,
+    pm::{
+        self,
+        Pid,
+    },
+    sync::Arc,
+    thread,
+};
+use alloc::{
+    boxed::Box,
+    vec::Vec,
+};
+use core::sync::atomic::{
+    AtomicU32,
+    Ordering,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// First tag that is handed out to the work channels of thread pools.
+const POOL_TAG_BASE: u32 = 0x7f00;
+
+/// Number of tags that are handed out to the work channels of thread pools.
+const POOL_TAGS: u32 = 0x100;
+
+/// Size of a message that carries a job (in bytes).
+const JOB_SIZE: usize = core::mem::size_of::<u32>();
+
+/// Message that asks a worker to exit.
+const STOP: u32 = 0;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Number of work channels that were handed out to thread pools.
+static NEXT_POOL: AtomicU32 = AtomicU32::new(0);
+
+//==============================================================================
+// Types
+//==============================================================================
+
+/// Job that runs on a thread pool.
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A fixed set of worker threads that run jobs.
+///
+/// **Notes**
+///
+/// - Workers take jobs from a work channel of the calling process, in the order
+///   that they were submitted. A job is a boxed closure, and only the address
+///   of the box crosses the channel.
+/// - Work channels take tags from `0x7f00` up to `0x7fff`, which should not be
+///   used by other channels of the calling process.
+/// - Dropping the pool waits for pending jobs to run and joins all workers.
+///
+/// **Example**
+///
+/// ```ignore
+/// let pool: Pool = Pool::new(4)?;
+/// for chunk in chunks {
+///     pool.execute(move || process(chunk))?;
+/// }
+/// drop(pool);
+/// ```
+///
+#[derive(Debug)]
+pub struct Pool {
+    /// Sending half of the work channel.
+    tx: Sender,
+    /// Handles to the workers.
+    workers: Vec<JoinHandle>,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Pool {
+    ///
+    /// **Description**
+    ///
+    /// Spawns a thread pool.
+    ///
+    /// **Parameters**
+    /// - `n` - Number of worker threads.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the thread pool is returned. Upon failure,
+    /// an error is returned instead. If `n` is zero,
+    /// [`KcallError::InvalidArgument`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - If a worker may not be spawned, the workers that were already spawned
+    ///   are joined.
+    ///
+    pub fn new(n: usize) -> Result<Self, KcallError> {
+        if n == 0 {
+            return Err(KcallError::InvalidArgument);
+        }
+
+        let pid: Pid = pm::process_info(Pid::SELF)?.pid;
+        let tag: u32 = POOL_TAG_BASE
+            + NEXT_POOL.fetch_add(1, Ordering::Relaxed) % POOL_TAGS;
+        let rx: Arc<Receiver> = Arc::new(Receiver::create(tag)?)?;
+        let tx: Sender = Sender::open(pid, tag)?;
+
+        let mut pool: Pool = Pool {
+            tx,
+            workers: Vec::with_capacity(n),
+        };
+        for _ in 0..n {
+            let rx: Arc<Receiver> = Arc::clone(&rx);
+            // Dropping the pool joins the workers that were already spawned.
+            pool.workers.push(thread::spawn(move || work(&rx))?);
+        }
+
+        Ok(pool)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Submits a job to the target thread pool.
+    ///
+    /// **Parameters**
+    /// - `f` - Closure to run in a worker thread.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, the job is
+    /// dropped and an error is returned instead.
+    ///
+    /// **Notes**
+    ///
+    /// - While the work channel is full, the calling thread yields the
+    ///   processor and retries.
+    ///
+    pub fn execute<F>(&self, f: F) -> Result<(), KcallError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // Box the job twice, so that it is reached through a thin pointer.
+        let job: *mut Job = Box::into_raw(Box::new(Box::new(f) as Job));
+        if let Err(e) = self.send(job as u32) {
+            drop(unsafe { Box::from_raw(job) });
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of workers of the target thread pool.
+    ///
+    /// **Return**
+    ///
+    /// The number of workers of the target thread pool is returned.
+    ///
+    pub fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Sends a message to the workers of the target thread pool.
+    ///
+    /// **Parameters**
+    /// - `msg` - Address of a job, or [`STOP`].
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, empty is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    fn send(&self, msg: u32) -> Result<(), KcallError> {
+        loop {
+            match self.tx.send(&msg.to_le_bytes()) {
+                Err(KcallError::Again) => thread::yield_now(),
+                ret => return ret,
+            }
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        // Workers take messages in order, thus they run pending jobs first.
+        let stopped: bool =
+            (0..self.workers.len()).all(|_| self.send(STOP).is_ok());
+
+        for worker in self.workers.drain(..) {
+            // A worker that is not asked to exit would never be joined.
+            if stopped {
+                let _ = worker.join();
+            }
+        }
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Runs jobs of a thread pool until asked to exit.
+///
+/// **Parameters**
+/// - `rx` - Receiving half of the work channel.
+///
+fn work(rx: &Receiver) {
+    let mut buf: [u8; JOB_SIZE] = [0; JOB_SIZE];
+
+    loop {
+        match rx.recv(&mut buf) {
+            Ok(JOB_SIZE) => {},
+            Ok(_) | Err(KcallError::Interrupted) => continue,
+            Err(_) => return,
+        }
+
+        let job: u32 = u32::from_le_bytes(buf);
+        if job == STOP {
+            return;
+        }
+
+        let job: Box<Job> = unsafe { Box::from_raw(job as *mut Job) };
+        job();
+    }
+}
@@ -0,0 +1,201 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use super::{
+    JoinHandle,
+    ThreadId,
+};
+use crate::kcall::KcallError;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A registry of detached threads that reports those that have finished.
+///
+/// **Notes**
+///
+/// - The registry holds at most `N` threads and it does not allocate memory.
+/// - Threads are detached when they are registered, thus they may no longer be
+///   joined.
+/// - The kernel has no kernel call to query the state of another thread, and
+///   [`crate::kcall::KcallNumbers::ThreadGet`] only reports the ID of the
+///   calling thread. For this reason, a thread is polled by detaching it
+///   again: this has no effect on a running thread, and it fails once the
+///   kernel has released a finished one.
+/// - The kernel may hand out the ID of a finished thread to a new thread. If
+///   that happens before the finished thread is reaped, the new thread is
+///   mistaken for it and detached.
+///
+/// **Example**
+///
+/// ```ignore
+/// let mut registry: Registry<4> = Registry::new();
+/// registry.register(thread::spawn(worker)?)?;
+/// while registry.len() > 0 {
+///     for id in registry.reap_finished() {
+///         nanvix::log!("thread {:?} finished", id);
+///     }
+///     thread::yield_now();
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct Registry<const N: usize> {
+    /// Threads that are tracked.
+    threads: [Option<ThreadId>; N],
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl<const N: usize> Registry<N> {
+    ///
+    /// **Description**
+    ///
+    /// Creates an empty registry.
+    ///
+    /// **Return**
+    ///
+    /// A registry that tracks no threads.
+    ///
+    pub const fn new() -> Self {
+        Self { threads: [None; N] }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Detaches a thread and tracks it in the target registry.
+    ///
+    /// **Parameters**
+    /// - `handle` - Handle to the target thread.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the identity of the target thread is
+    /// returned. Upon failure, an error is returned instead. If the registry
+    /// is full, [`KcallError::NoMemory`] is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - The target thread is detached even if it may not be tracked.
+    ///
+    pub fn register(
+        &mut self,
+        handle: JoinHandle,
+    ) -> Result<ThreadId, KcallError> {
+        let id: ThreadId = handle.id();
+        handle.detach()?;
+
+        match self.threads.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => {
+                *slot = Some(id);
+                Ok(id)
+            },
+            None => Err(KcallError::NoMemory),
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of threads that are tracked by the target registry.
+    ///
+    /// **Return**
+    ///
+    /// The number of threads that are tracked by the target registry.
+    ///
+    pub fn len(&self) -> usize {
+        self.threads.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Checks if the target registry tracks no threads.
+    ///
+    /// **Return**
+    ///
+    /// If the target registry tracks no threads, `true` is returned.
+    /// Otherwise, `false` is returned instead.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.threads.iter().all(|slot| slot.is_none())
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stops tracking threads that have finished and stores their identities.
+    ///
+    /// **Parameters**
+    /// - `finished` - Storage for the identities of finished threads.
+    ///
+    /// **Return**
+    ///
+    /// The number of identities that were stored in `finished` is returned.
+    ///
+    /// **Notes**
+    ///
+    /// - Once `finished` is full, remaining threads are not polled, and they
+    ///   are reported by a later call.
+    ///
+    pub fn reap_finished_into(&mut self, finished: &mut [ThreadId]) -> usize {
+        let mut count: usize = 0;
+
+        for slot in self.threads.iter_mut() {
+            if count == finished.len() {
+                break;
+            }
+
+            if let Some(id) = *slot {
+                if super::detach(id.as_raw()).is_err() {
+                    finished[count] = id;
+                    count += 1;
+                    *slot = None;
+                }
+            }
+        }
+
+        count
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Stops tracking threads that have finished.
+    ///
+    /// **Return**
+    ///
+    /// The identities of the threads that have finished are returned.
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn reap_finished(&mut self) -> Vec<ThreadId> {
+        let mut finished: [ThreadId; N] = [ThreadId::from_raw(0); N];
+        let count: usize = self.reap_finished_into(&mut finished);
+        finished[..count].to_vec()
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl<const N: usize> Default for Registry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,381 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::{
+    arch,
+    kcall::{
+        self,
+        KcallError,
+    },
+    memory::{
+        self,
+        Frame,
+        PageFlags,
+        VirtualAddress,
+        VirtualMemory,
+        VmCtrlRequest,
+        PAGE_SIZE,
+    },
+    pm::{
+        self,
+        ffi,
+        Pid,
+        Tid,
+    },
+    sync::{
+        SpinMutex,
+        SpinMutexGuard,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Maximum size of a stack that is allocated for a thread (in bytes).
+pub const THREAD_STACK_MAX: usize = 64 * 1024;
+
+/// Maximum number of stacks that may be allocated for threads at once.
+pub const THREAD_STACKS_MAX: usize = 8;
+
+/// Size of a slot in the region of stacks (in bytes). The lowest page of every
+/// slot is never mapped, so that overflowing a stack faults.
+const STACK_SLOT_SIZE: u32 = THREAD_STACK_MAX as u32 + PAGE_SIZE;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Slots of the region of stacks.
+static SLOTS: SpinMutex<[Option<Slot>; THREAD_STACKS_MAX]> =
+    SpinMutex::new(0, [None; THREAD_STACKS_MAX]);
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// State of a slot in the region of stacks that is in use.
+///
+#[derive(Clone, Copy)]
+struct Slot {
+    /// Number of pages that are mapped.
+    pages: usize,
+    /// Thread that runs on the stack, if any.
+    owner: Option<Tid>,
+    /// Has the owner exited without returning from the stack?
+    exited: bool,
+}
+
+///
+/// **Description**
+///
+/// An owned stack for a thread.
+///
+/// **Notes**
+///
+/// - The stack is unmapped and its page frames are released when it is
+///   dropped.
+///
+#[derive(Debug)]
+pub(super) struct Stack {
+    /// Slot of the stack in its region.
+    slot: usize,
+    /// Number of pages that are mapped.
+    pages: usize,
+}
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Stack {
+    ///
+    /// **Description**
+    ///
+    /// Allocates a stack.
+    ///
+    /// **Parameters**
+    /// - `size` - Size of the stack (in bytes). It is rounded up to a multiple
+    ///   of the page size.
+    ///
+    /// **Return**
+    ///
+    /// Upon successful completion, the stack is returned. Upon failure, an
+    /// error is returned instead. If `size` is zero or greater than
+    /// [`THREAD_STACK_MAX`], [`KcallError::InvalidArgument`] is returned, and
+    /// if [`THREAD_STACKS_MAX`] stacks are in use, [`KcallError::NoMemory`]
+    /// is returned.
+    ///
+    pub(super) fn alloc(size: usize) -> Result<Self, KcallError> {
+        if size == 0 || size > THREAD_STACK_MAX {
+            return Err(KcallError::InvalidArgument);
+        }
+        let pages: usize = size.div_ceil(PAGE_SIZE as usize);
+
+        let slot: usize = {
+            let mut slots: SpinMutexGuard<[Option<Slot>; THREAD_STACKS_MAX]> =
+                SLOTS.lock();
+            let slot: usize = slots
+                .iter()
+                .position(|slot| slot.is_none())
+                .ok_or(KcallError::NoMemory)?;
+            slots[slot] = Some(Slot {
+                pages,
+                owner: None,
+                exited: false,
+            });
+            slot
+        };
+
+        let mut stack: Stack = Stack { slot, pages: 0 };
+        while stack.pages < pages {
+            // Pages that were mapped so far are released on failure.
+            map_page(stack.top() - (stack.pages as u32 + 1) * PAGE_SIZE)?;
+            stack.pages += 1;
+        }
+
+        Ok(stack)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Runs a closure on the target stack.
+    ///
+    /// **Parameters**
+    /// - `tid` - ID of the calling thread.
+    /// - `f` - Closure to run.
+    ///
+    /// **Notes**
+    ///
+    /// - If the calling thread exits while it runs on the target stack, the
+    ///   stack is released once the thread is joined. See [`exit`] and
+    ///   [`reap`].
+    ///
+    pub(super) fn run<F: FnOnce()>(self, tid: Tid, f: F) {
+        set_owner(self.slot, Some(tid));
+
+        let mut f: Option<F> = Some(f);
+        unsafe {
+            arch::switch_stack(
+                self.top(),
+                entry::<F> as usize,
+                &mut f as *mut Option<F> as usize,
+            )
+        };
+
+        set_owner(self.slot, None);
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the address of the top of the target stack.
+    ///
+    /// **Return**
+    ///
+    /// The address right above the highest byte of the target stack.
+    ///
+    fn top(&self) -> VirtualAddress {
+        slot_top(self.slot)
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Drop for Stack {
+    fn drop(&mut self) {
+        release(self.slot, self.pages);
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Records that a thread exits without returning from its stack.
+///
+/// **Parameters**
+/// - `tid` - ID of the exiting thread.
+///
+/// **Notes**
+///
+/// - A thread may not release the stack that it runs on. For this reason, the
+///   stack is only marked, and it is released by [`reap`].
+///
+pub(super) fn exit(tid: Tid) {
+    let mut slots: SpinMutexGuard<[Option<Slot>; THREAD_STACKS_MAX]> =
+        SLOTS.lock();
+    for slot in slots.iter_mut().flatten() {
+        if slot.owner == Some(tid) {
+            slot.exited = true;
+        }
+    }
+}
+
+///
+/// **Description**
+///
+/// Releases the stack of a thread that exited without returning from it.
+///
+/// **Parameters**
+/// - `tid` - ID of a thread that was joined.
+///
+pub(super) fn reap(tid: Tid) {
+    let exited: Option<(usize, usize)> = {
+        let slots: SpinMutexGuard<[Option<Slot>; THREAD_STACKS_MAX]> =
+            SLOTS.lock();
+        slots.iter().enumerate().find_map(|(i, slot)| match slot {
+            Some(slot) if slot.exited && slot.owner == Some(tid) => {
+                Some((i, slot.pages))
+            },
+            _ => None,
+        })
+    };
+
+    if let Some((slot, pages)) = exited {
+        release(slot, pages);
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Sets the thread that runs on a slot in the region of stacks.
+///
+/// **Parameters**
+/// - `slot` - Index of the slot.
+/// - `owner` - Thread that runs on the slot, if any.
+///
+fn set_owner(slot: usize, owner: Option<Tid>) {
+    if let Some(slot) = SLOTS.lock()[slot].as_mut() {
+        slot.owner = owner;
+    }
+}
+
+///
+/// **Description**
+///
+/// Unmaps the pages of a slot in the region of stacks and frees the slot.
+///
+/// **Parameters**
+/// - `slot` - Index of the slot.
+/// - `pages` - Number of pages that are mapped.
+///
+/// **Notes**
+///
+/// - If a page may not be unmapped, the slot is leaked, so that it is not
+///   mapped again.
+///
+fn release(slot: usize, pages: usize) {
+    let top: VirtualAddress = slot_top(slot);
+    for i in 0..pages {
+        if unmap_page(top - (i as u32 + 1) * PAGE_SIZE).is_err() {
+            return;
+        }
+    }
+
+    SLOTS.lock()[slot] = None;
+}
+
+///
+/// **Description**
+///
+/// Computes the address of the top of a slot in the region of stacks.
+///
+/// **Parameters**
+/// - `slot` - Index of the slot.
+///
+/// **Return**
+///
+/// The address right above the highest byte of the slot.
+///
+fn slot_top(slot: usize) -> VirtualAddress {
+    memory::THREAD_STACKS_REGION_BASE_ADDRESS
+        + (slot as u32 + 1) * STACK_SLOT_SIZE
+}
+
+///
+/// **Description**
+///
+/// Maps a fresh page frame at a page of the region of stacks.
+///
+/// **Parameters**
+/// - `vaddr` - Virtual address of the page.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+fn map_page(vaddr: VirtualAddress) -> Result<(), KcallError> {
+    let vmem: VirtualMemory = pm::process_info(Pid::SELF)?.vmem;
+
+    let frame: Frame = Frame::alloc()?;
+    kcall::decode(memory::vmmap(vmem, vaddr, frame.number()))?;
+
+    let flags: PageFlags = PageFlags::READ | PageFlags::WRITE;
+    let request: VmCtrlRequest =
+        VmCtrlRequest::ChangePermissions(vaddr, flags.into());
+    if let Err(e) = kcall::decode(memory::vmctrl(vmem, request)) {
+        let _ = memory::vmunmap(vmem, vaddr);
+        return Err(e);
+    }
+
+    // The page frame is now owned by the stack.
+    frame.leak();
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Unmaps a page of the region of stacks and releases its page frame.
+///
+/// **Parameters**
+/// - `vaddr` - Virtual address of the page.
+///
+/// **Return**
+///
+/// Upon successful completion, empty is returned. Upon failure, an error is
+/// returned instead.
+///
+fn unmap_page(vaddr: VirtualAddress) -> Result<(), KcallError> {
+    let vmem: VirtualMemory = pm::process_info(Pid::SELF)?.vmem;
+    let frame: u32 = kcall::decode(memory::vmunmap(vmem, vaddr))?;
+    let _ = memory::frfree(frame);
+    Ok(())
+}
+
+///
+/// **Description**
+///
+/// Runs a closure that was handed over by [`Stack::run`].
+///
+/// **Parameters**
+/// - `arg` - Closure to run, which is taken.
+///
+extern "C" fn entry<F: FnOnce()>(arg: *mut ffi::c_void) {
+    let f: &mut Option<F> = unsafe { &mut *(arg as *mut Option<F>) };
+    if let Some(f) = f.take() {
+        f();
+    }
+}
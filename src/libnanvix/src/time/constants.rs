@@ -0,0 +1,14 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Number of ticks per second (frequency of the kernel timer).
+pub const TICKS_PER_SECOND: u64 = 100;
+
+/// Number of milliseconds per second.
+pub(crate) const MILLIS_PER_SECOND: u64 = 1000;
@@ -0,0 +1,207 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use crate::time::{
+    MILLIS_PER_SECOND,
+    TICKS_PER_SECOND,
+};
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+///
+/// **Description**
+///
+/// A span of time, measured in ticks.
+///
+/// **Notes**
+///
+/// - A tick is one period of the kernel timer, which runs at
+///   [`TICKS_PER_SECOND`]. The kernel offers no clock to user space, thus
+///   functions that wait for a number of ticks measure them on the counter
+///   that is read with [`crate::thread::ticks`] instead, which advances once
+///   per yield with [`crate::thread::yield_now`]. Conversions from and to
+///   milliseconds are therefore only approximate.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Ticks(u64);
+
+//==============================================================================
+// Associated Functions
+//==============================================================================
+
+impl Ticks {
+    /// The longest span of ticks.
+    pub const MAX: Ticks = Ticks(u64::MAX);
+    /// A span of no ticks.
+    pub const ZERO: Ticks = Ticks(0);
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a span of ticks from a raw number of ticks.
+    ///
+    /// **Parameters**
+    /// - `ticks` - Number of ticks.
+    ///
+    /// **Return**
+    ///
+    /// A span of `ticks` ticks.
+    ///
+    pub const fn from_raw(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the raw number of ticks of the target span.
+    ///
+    /// **Return**
+    ///
+    /// The number of ticks of the target span.
+    ///
+    pub const fn as_raw(&self) -> u64 {
+        self.0
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Creates a span of ticks from a number of milliseconds.
+    ///
+    /// **Parameters**
+    /// - `millis` - Number of milliseconds.
+    ///
+    /// **Return**
+    ///
+    /// The shortest span of ticks that lasts at least `millis` milliseconds,
+    /// saturated at [`Ticks::MAX`].
+    ///
+    pub const fn from_millis(millis: u64) -> Self {
+        let secs: u64 =
+            (millis / MILLIS_PER_SECOND).saturating_mul(TICKS_PER_SECOND);
+        let rest: u64 = ((millis % MILLIS_PER_SECOND) * TICKS_PER_SECOND)
+            .div_ceil(MILLIS_PER_SECOND);
+        Self(secs.saturating_add(rest))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Gets the number of milliseconds of the target span.
+    ///
+    /// **Return**
+    ///
+    /// The number of whole milliseconds of the target span, saturated at
+    /// `u64::MAX`.
+    ///
+    pub const fn as_millis(&self) -> u64 {
+        let secs: u64 =
+            (self.0 / TICKS_PER_SECOND).saturating_mul(MILLIS_PER_SECOND);
+        let rest: u64 =
+            (self.0 % TICKS_PER_SECOND) * MILLIS_PER_SECOND / TICKS_PER_SECOND;
+        secs.saturating_add(rest)
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Adds two spans of ticks, saturating at [`Ticks::MAX`].
+    ///
+    /// **Parameters**
+    /// - `rhs` - Span to add.
+    ///
+    /// **Return**
+    ///
+    /// The sum of the target span and `rhs`, or [`Ticks::MAX`] on overflow.
+    ///
+    pub const fn saturating_add(self, rhs: Ticks) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Subtracts two spans of ticks, saturating at [`Ticks::ZERO`].
+    ///
+    /// **Parameters**
+    /// - `rhs` - Span to subtract.
+    ///
+    /// **Return**
+    ///
+    /// The difference of the target span and `rhs`, or [`Ticks::ZERO`] on
+    /// underflow.
+    ///
+    pub const fn saturating_sub(self, rhs: Ticks) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Adds two spans of ticks, checking for overflow.
+    ///
+    /// **Parameters**
+    /// - `rhs` - Span to add.
+    ///
+    /// **Return**
+    ///
+    /// The sum of the target span and `rhs`, or `None` on overflow.
+    ///
+    pub const fn checked_add(self, rhs: Ticks) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(ticks) => Some(Self(ticks)),
+            None => None,
+        }
+    }
+
+    ///
+    /// **Description**
+    ///
+    /// Subtracts two spans of ticks, checking for underflow.
+    ///
+    /// **Parameters**
+    /// - `rhs` - Span to subtract.
+    ///
+    /// **Return**
+    ///
+    /// The difference of the target span and `rhs`, or `None` on underflow.
+    ///
+    pub const fn checked_sub(self, rhs: Ticks) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(ticks) => Some(Self(ticks)),
+            None => None,
+        }
+    }
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl From<u32> for Ticks {
+    fn from(ticks: u32) -> Self {
+        Self(ticks as u64)
+    }
+}
+
+impl From<u64> for Ticks {
+    fn from(ticks: u64) -> Self {
+        Self(ticks)
+    }
+}
+
+impl From<Ticks> for u64 {
+    fn from(ticks: Ticks) -> Self {
+        ticks.0
+    }
+}
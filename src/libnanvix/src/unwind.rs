@@ -3,25 +3,6 @@
  * Licensed under the MIT License.
  */
 
-use crate::log;
-
-#[panic_handler]
-#[allow(unused_variables)]
-pub fn panic_implementation(info: &::core::panic::PanicInfo) -> ! {
-    let (file, line) = match info.location() {
-        Some(loc) => (loc.file(), loc.line()),
-        None => ("", 0),
-    };
-    if let Some(m) = info.message() {
-        log!("PANIC file='{}', line={} :: {}", file, line, m);
-    } else if let Some(m) = info.payload().downcast_ref::<&str>() {
-        log!("PANIC file='{}', line={} :: {}", file, line, m);
-    } else {
-        log!("PANIC file='{}', line={} :: ?", file, line);
-    }
-    loop {}
-}
-
 #[allow(non_camel_case_types)]
 #[repr(C)]
 #[derive(Clone, Copy)]
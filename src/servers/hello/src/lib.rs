@@ -0,0 +1,66 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+#![no_std]
+#![feature(panic_info_message)]
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+extern crate nanvix;
+use nanvix::{
+    ipc::{
+        self,
+        Receiver,
+        Sender,
+    },
+    kcall::KcallError,
+    pm::Pid,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Tag of the channel through which the spawner sends its PID.
+///
+/// Must match the one in the test server.
+const HELLO_TAG: u32 = 116;
+
+/// Tag of the channel of the spawner through which the server replies.
+///
+/// Must match the one in the test server.
+const HELLO_REPLY_TAG: u32 = 117;
+
+/// Message that the server replies with.
+const HELLO_MESSAGE: &[u8] = b"hello";
+
+//==============================================================================
+// Standalone Functions
+//==============================================================================
+
+/// Replies to the first process that sends its PID.
+fn greet() -> Result<(), KcallError> {
+    let rx: Receiver = Receiver::create(HELLO_TAG)?;
+
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    let len: usize = rx.recv(&mut buf)?;
+    if len != core::mem::size_of::<i32>() {
+        return Err(KcallError::InvalidArgument);
+    }
+
+    let pid: Pid =
+        Pid::new(i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]));
+    Sender::open(pid, HELLO_REPLY_TAG)?.send(HELLO_MESSAGE)
+}
+
+#[no_mangle]
+pub fn main() {
+    nanvix::log!("Running hello server...");
+    if greet().is_err() {
+        nanvix::log!("failed to greet");
+    }
+}
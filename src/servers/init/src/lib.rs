@@ -11,13 +11,7 @@
 //==============================================================================
 
 extern crate nanvix;
-use nanvix::{
-    misc::{
-        self,
-        KernelModule,
-    },
-    pm,
-};
+use nanvix::pm;
 
 //==============================================================================
 // Standalone Functions
@@ -25,19 +19,12 @@ use nanvix::{
 
 /// Print information on all kernel modules.
 fn spawn_servers() {
-    for index in 1.. {
-        let mut kmod: KernelModule = misc::KernelModule::default();
+    // Skip the first kernel module, which is the init server itself.
+    for (index, kmod) in pm::kmods().enumerate().skip(1) {
+        nanvix::log!("Spawning Server #{}: (cmdline={})", index, kmod.name());
 
-        if misc::kmod_get(&mut kmod, index) != 0 {
-            break;
-        }
-
-        let cmdline = core::str::from_utf8(&kmod.cmdline).unwrap();
-        nanvix::log!("Spawning Server #{}: (cmdline={})", index, cmdline);
-
-        // Spawn server.
-        let pid: i32 = pm::spawn(kmod.start as *const core::ffi::c_void);
-        if pid < 0 {
+        // Spawn server. The kernel module was reported by the kernel.
+        if pm::spawn(unsafe { kmod.image() }, &[]).is_err() {
             nanvix::log!("failed to spawn server");
         }
     }
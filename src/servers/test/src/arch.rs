@@ -0,0 +1,49 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use nanvix::arch;
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Number of successive reads of the cycle counter.
+const CYCLE_READS: usize = 16;
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Checks if successive reads of the cycle counter never go backwards.
+fn cycles_monotonic() -> bool {
+    let mut last: u64 = arch::cycles();
+    for _ in 0..CYCLE_READS {
+        let now: u64 = arch::cycles();
+        if now < last {
+            nanvix::log!("cycle counter went backwards ({} < {})", now, last);
+            return false;
+        }
+        last = now;
+    }
+
+    true
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Tests the architecture-specific facility.
+///
+pub fn test() {
+    crate::test!(cycles_monotonic());
+}
@@ -0,0 +1,305 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use alloc::string::String;
+use core::{
+    fmt::Write,
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+use nanvix::{
+    excp::{
+        self,
+        ExceptionInfo,
+        ExceptionKind,
+    },
+    kcall::{
+        self,
+        KcallError,
+    },
+    memory::{
+        self,
+        Frame,
+        VirtualAddress,
+    },
+    pm::{
+        self,
+        Child,
+        Pid,
+    },
+    thread::{
+        self,
+        JoinHandle,
+    },
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Unmapped address that is accessed to trigger a page fault.
+const FAULT_ADDRESS: VirtualAddress = 0x06000000;
+
+/// Exception vector that is not supported by the kernel.
+const INVALID_EXCEPTION: u32 = 99;
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Attempts to handle an exception that is not supported.
+fn control_invalid_exception() -> bool {
+    excp::control(ExceptionKind::Other(INVALID_EXCEPTION), true)
+        == Err(KcallError::InvalidArgument)
+}
+
+/// Decodes exception vectors and formats exception kinds.
+fn decode_exception_vectors() -> bool {
+    let cases: [(u32, ExceptionKind, &str); 3] = [
+        (
+            13,
+            ExceptionKind::GeneralProtectionFault,
+            "general protection fault",
+        ),
+        (14, ExceptionKind::PageFault, "page fault"),
+        (
+            INVALID_EXCEPTION,
+            ExceptionKind::Other(INVALID_EXCEPTION),
+            "exception 99",
+        ),
+    ];
+
+    for (vector, kind, name) in cases.iter() {
+        if ExceptionKind::from_vector(*vector) != *kind
+            || kind.as_vector() != *vector
+        {
+            nanvix::log!("failed to decode exception vector {}", vector);
+            return false;
+        }
+
+        let mut text: String = String::new();
+        if write!(&mut text, "{}", kind).is_err() || text != *name {
+            nanvix::log!("unexpected name for exception vector {}", vector);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Triggers a page fault in a child thread, fixes it and resumes the thread.
+fn fault_child_thread() -> bool {
+    static RESUMED: AtomicBool = AtomicBool::new(false);
+
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+        unsafe { core::ptr::read_volatile(FAULT_ADDRESS as *const u32) };
+        RESUMED.store(true, Ordering::SeqCst);
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+
+    let info: ExceptionInfo = match excp::wait() {
+        Ok(info) => info,
+        Err(_) => {
+            nanvix::log!("failed to wait for exception");
+            return false;
+        },
+    };
+    if info.kind != ExceptionKind::PageFault || info.address != FAULT_ADDRESS {
+        nanvix::log!("unexpected exception {:?}", info);
+        return false;
+    }
+
+    // The faulting instruction should lie in the code of this program.
+    if !(memory::USER_BASE_ADDRESS..memory::USER_END_ADDRESS)
+        .contains(&info.instruction_pointer)
+    {
+        nanvix::log!("implausible instruction pointer {:?}", info);
+        return false;
+    }
+
+    fix_and_resume(&info, handle) && RESUMED.load(Ordering::SeqCst)
+}
+
+/// Fixes a page fault at [`FAULT_ADDRESS`], resumes the faulting thread and
+/// joins it.
+fn fix_and_resume(info: &ExceptionInfo, handle: JoinHandle) -> bool {
+    // Fix the page fault by mapping a page frame at the faulting address.
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate frame");
+            return false;
+        },
+    };
+    let vmem: memory::VirtualMemory = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.vmem,
+        Err(_) => {
+            nanvix::log!("failed to get process information");
+            return false;
+        },
+    };
+    if memory::vmmap(vmem, FAULT_ADDRESS, frame.number()) != 0 {
+        nanvix::log!("failed to map frame");
+        return false;
+    }
+
+    let resumed: bool = excp::resume(info).is_ok() && handle.join().is_ok();
+
+    memory::vmunmap(vmem, FAULT_ADDRESS);
+
+    resumed
+}
+
+/// Handles a page fault that is triggered in a child thread.
+fn handle_page_fault() -> bool {
+    if excp::control(ExceptionKind::PageFault, true).is_err() {
+        nanvix::log!("failed to handle page faults");
+        return false;
+    }
+
+    let result: bool = fault_child_thread();
+
+    if excp::control(ExceptionKind::PageFault, false).is_err() {
+        nanvix::log!("failed to defer page faults");
+        return false;
+    }
+
+    result
+}
+
+/// Triggers a page fault in a thread that stands in for a child process, and
+/// waits for it as the parent.
+fn wait_child_fault() -> bool {
+    static RESUMED: AtomicBool = AtomicBool::new(false);
+
+    let pid: Pid = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.pid,
+        Err(_) => {
+            nanvix::log!("failed to get process information");
+            return false;
+        },
+    };
+    let child: Child = Child::from_pid(pid);
+
+    // A null pointer lies in kernel memory, where no page frame may be mapped
+    // to resume the thread. An unmapped user address stands in for it.
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+        unsafe { core::ptr::read_volatile(FAULT_ADDRESS as *const u32) };
+        RESUMED.store(true, Ordering::SeqCst);
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+
+    let info: ExceptionInfo = match child.wait_fault() {
+        Ok(Some(info)) => info,
+        result => {
+            nanvix::log!("failed to wait for fault (result={:?})", result);
+            return false;
+        },
+    };
+    if info.pid != pid
+        || info.kind != ExceptionKind::PageFault
+        || info.address != FAULT_ADDRESS
+    {
+        nanvix::log!("unexpected exception {:?}", info);
+        return false;
+    }
+
+    fix_and_resume(&info, handle) && RESUMED.load(Ordering::SeqCst)
+}
+
+/// Forwards a page fault of a child to the parent.
+fn forward_child_fault() -> bool {
+    // A process that does not exist has no faults to wait for.
+    if Child::from_pid(Pid::new(i32::MAX)).wait_fault() != Ok(None) {
+        nanvix::log!("failed to wait for fault of an invalid process");
+        return false;
+    }
+
+    if excp::control(ExceptionKind::PageFault, true).is_err() {
+        nanvix::log!("failed to handle page faults");
+        return false;
+    }
+
+    let result: bool = wait_child_fault();
+
+    if excp::control(ExceptionKind::PageFault, false).is_err() {
+        nanvix::log!("failed to defer page faults");
+        return false;
+    }
+
+    result
+}
+
+/// Retries an exception wait that is interrupted once.
+fn wait_retries_interrupted() -> bool {
+    let expected: ExceptionInfo = ExceptionInfo {
+        pid: Pid::new(1),
+        tid: 0,
+        kind: ExceptionKind::PageFault,
+        address: FAULT_ADDRESS,
+        instruction_pointer: 0,
+        registers: None,
+    };
+
+    // Mock wait that is interrupted before it delivers an exception.
+    let mut calls: u32 = 0;
+    let result: Result<ExceptionInfo, KcallError> =
+        kcall::retry_interrupted(|| {
+            calls += 1;
+            if calls == 1 {
+                Err(KcallError::Interrupted)
+            } else {
+                Ok(expected)
+            }
+        });
+    if result != Ok(expected) || calls != 2 {
+        nanvix::log!("unexpected result after {} waits", calls);
+        return false;
+    }
+
+    // Genuine errors should not be retried.
+    let mut calls: u32 = 0;
+    let result: Result<ExceptionInfo, KcallError> =
+        kcall::retry_interrupted(|| {
+            calls += 1;
+            Err(KcallError::PermissionDenied)
+        });
+    if result != Err(KcallError::PermissionDenied) || calls != 1 {
+        nanvix::log!("retried a wait that failed");
+        return false;
+    }
+
+    true
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+pub fn test() {
+    crate::test!(control_invalid_exception());
+    crate::test!(decode_exception_vectors());
+    crate::test!(handle_page_fault());
+    crate::test!(forward_child_fault());
+    crate::test!(wait_retries_interrupted());
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use nanvix::{
+    ffi::{
+        Name,
+        NAME_MAX,
+    },
+    kcall::KcallError,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Name used in tests.
+const NAME: &str = "mailbox";
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Checks if a name is null-terminated.
+fn name_null_terminated() -> bool {
+    let name: Name = match Name::new(NAME) {
+        Ok(name) => name,
+        Err(_) => {
+            nanvix::log!("failed to create name");
+            return false;
+        },
+    };
+
+    let bytes: &[u8] = name.as_bytes_with_nul();
+    if bytes.len() != NAME.len() + 1 || &bytes[..NAME.len()] != NAME.as_bytes()
+    {
+        nanvix::log!("unexpected bytes for name");
+        return false;
+    }
+
+    unsafe { *name.as_ptr().add(NAME.len()) == 0 }
+}
+
+/// Checks if the longest name is accepted.
+fn name_max_length() -> bool {
+    let buf: [u8; NAME_MAX] = [b'a'; NAME_MAX];
+    let name: &str = core::str::from_utf8(&buf).unwrap_or("");
+
+    match Name::new(name) {
+        Ok(name) => name.len() == NAME_MAX,
+        Err(_) => false,
+    }
+}
+
+/// Attempts to create a name that is too long.
+fn name_too_long() -> bool {
+    let buf: [u8; NAME_MAX + 1] = [b'a'; NAME_MAX + 1];
+    let name: &str = core::str::from_utf8(&buf).unwrap_or("");
+
+    Name::new(name).err() == Some(KcallError::InvalidArgument)
+}
+
+/// Attempts to create a name that has an interior null byte.
+fn name_interior_nul() -> bool {
+    Name::new("mail\0box").err() == Some(KcallError::InvalidArgument)
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Tests helpers for foreign function interfaces.
+///
+pub fn test() {
+    crate::test!(name_null_terminated());
+    crate::test!(name_max_length());
+    crate::test!(name_too_long());
+    crate::test!(name_interior_nul());
+}
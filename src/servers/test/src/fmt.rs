@@ -0,0 +1,66 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::fmt;
+use nanvix::fmt as nfmt;
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Formats text that exactly fits in a buffer.
+fn format_into_exact_fit() -> bool {
+    let mut buf: [u8; 6] = [0; 6];
+    match nfmt::format_into(&mut buf, format_args!("pid={}", 42)) {
+        Ok("pid=42") => true,
+        _ => {
+            nanvix::log!("failed to format text that fits exactly");
+            false
+        },
+    }
+}
+
+/// Attempts to format text that does not fit in a buffer.
+fn format_into_overflow() -> bool {
+    let mut buf: [u8; 5] = [0; 5];
+    match nfmt::format_into(&mut buf, format_args!("pid={}", 42)) {
+        Err(fmt::Error) => true,
+        Ok(text) => {
+            nanvix::log!("succeeded to format text that overflows ({})", text);
+            false
+        },
+    }
+}
+
+/// Formats empty text, which fits in an empty buffer.
+fn format_into_empty() -> bool {
+    let mut buf: [u8; 0] = [];
+    match nfmt::format_into(&mut buf, format_args!("")) {
+        Ok("") => true,
+        _ => {
+            nanvix::log!("failed to format empty text");
+            false
+        },
+    }
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Tests the formatting facility.
+///
+pub fn test() {
+    crate::test!(format_into_exact_fit());
+    crate::test!(format_into_overflow());
+    crate::test!(format_into_empty());
+}
@@ -0,0 +1,128 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use nanvix::{
+    iam::{
+        self,
+        cred::{
+            self,
+            Gid,
+            Uid,
+        },
+    },
+    kcall::KcallError,
+};
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Reads the credentials of the calling process.
+fn read_credentials() -> bool {
+    if cred::get_uid() != Uid::new(iam::getuid()) {
+        nanvix::log!("unexpected user ID");
+        return false;
+    }
+    if cred::get_euid() != Uid::new(iam::geteuid()) {
+        nanvix::log!("unexpected effective user ID");
+        return false;
+    }
+    if cred::get_gid() != Gid::new(iam::getgid()) {
+        nanvix::log!("unexpected group ID");
+        return false;
+    }
+    if cred::get_egid() != Gid::new(iam::getegid()) {
+        nanvix::log!("unexpected effective group ID");
+        return false;
+    }
+
+    true
+}
+
+/// Sets the credentials of the calling process to their current values.
+fn set_current_credentials() -> bool {
+    let (uid, euid) = (cred::get_uid(), cred::get_euid());
+    let (gid, egid) = (cred::get_gid(), cred::get_egid());
+
+    if cred::set_uid(uid).is_err() || cred::set_euid(euid).is_err() {
+        nanvix::log!("failed to set user ID to its current value");
+        return false;
+    }
+    if cred::set_gid(gid).is_err() || cred::set_egid(egid).is_err() {
+        nanvix::log!("failed to set group ID to its current value");
+        return false;
+    }
+
+    // Check if credentials are unchanged.
+    if cred::get_uid() != uid
+        || cred::get_euid() != euid
+        || cred::get_gid() != gid
+        || cred::get_egid() != egid
+    {
+        nanvix::log!("credentials changed");
+        return false;
+    }
+
+    true
+}
+
+/// Drops privileges to the current credentials of the calling process.
+fn drop_privileges_current() -> bool {
+    let (uid, gid): (Uid, Gid) = (cred::get_uid(), cred::get_gid());
+
+    if cred::drop_privileges(uid, gid).is_err() {
+        nanvix::log!("failed to drop privileges to uid={}, gid={}", uid, gid);
+        return false;
+    }
+
+    cred::get_uid() == uid
+        && cred::get_euid() == uid
+        && cred::get_gid() == gid
+        && cred::get_egid() == gid
+}
+
+/// Attempts to drop privileges to the credentials of another user.
+fn drop_privileges_denied() -> bool {
+    let (uid, euid): (Uid, Uid) = (cred::get_uid(), cred::get_euid());
+    let (gid, egid): (Gid, Gid) = (cred::get_gid(), cred::get_egid());
+
+    // A superuser would succeed, and it would not regain its privileges.
+    if uid == Uid::new(0) || euid == Uid::new(0) {
+        return true;
+    }
+
+    let other: Uid = Uid::new(uid.raw() + 1);
+    if cred::drop_privileges(other, gid) != Err(KcallError::PermissionDenied) {
+        nanvix::log!("succeeded to drop privileges to uid={}", other);
+        return false;
+    }
+
+    // Check if credentials are unchanged.
+    if cred::get_uid() != uid
+        || cred::get_euid() != euid
+        || cred::get_gid() != gid
+        || cred::get_egid() != egid
+    {
+        nanvix::log!("credentials changed");
+        return false;
+    }
+
+    true
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+pub fn test() {
+    crate::test!(read_credentials());
+    crate::test!(set_current_credentials());
+    crate::test!(drop_privileges_current());
+    crate::test!(drop_privileges_denied());
+}
@@ -0,0 +1,390 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::fmt::Write;
+use nanvix::{
+    io::{
+        self,
+        BufWriter,
+        Fd,
+        FdAccess,
+        Stdout,
+    },
+    kcall::KcallError,
+    pm,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Number of chunks written in buffered writer tests.
+const BUF_CHUNKS: usize = 1000;
+
+/// Size of the buffer of writers in buffered writer tests.
+const BUF_SIZE: usize = 64;
+
+/// Maximum number of bytes that the mock writer accepts at once.
+const MOCK_WRITE_MAX: usize = 3;
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Attempts to write a string to the standard output.
+fn write_stdout() -> bool {
+    let msg: &str = "[test] writing to the standard output\n";
+
+    match io::write(Fd::STDOUT, msg.as_bytes()) {
+        Ok(count) if count == msg.len() => true,
+        Ok(count) => {
+            nanvix::log!(
+                "unexpected number of bytes written (count={})",
+                count
+            );
+            false
+        },
+        Err(e) => {
+            nanvix::log!("failed to write to the standard output ({:?})", e);
+            false
+        },
+    }
+}
+
+/// Checks the raw values of the standard file descriptors.
+fn standard_fds() -> bool {
+    if Fd::STDOUT.as_raw() != 1 || Fd::STDERR.as_raw() != 2 {
+        nanvix::log!("unexpected raw values of standard file descriptors");
+        return false;
+    }
+    if Fd::from_raw(1) != Fd::STDOUT || Fd::from_raw(2) != Fd::STDERR {
+        nanvix::log!("failed to construct standard file descriptors");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to write a string to the standard error.
+fn write_stderr() -> bool {
+    let msg: &[u8] = b"[test] writing to the standard error\n";
+
+    match io::write(Fd::STDERR, msg) {
+        Ok(count) if count == msg.len() => true,
+        _ => {
+            nanvix::log!("failed to write to the standard error");
+            false
+        },
+    }
+}
+
+/// Attempts to write an empty buffer.
+fn write_empty() -> bool {
+    match io::write(Fd::STDOUT, &[]) {
+        Ok(0) => true,
+        _ => {
+            nanvix::log!("failed to write an empty buffer");
+            false
+        },
+    }
+}
+
+/// Attempts to write a buffer that is longer than what the kernel accepts.
+fn write_short() -> bool {
+    let buf: [u8; io::WRITE_MAX + 1] = [b'\n'; io::WRITE_MAX + 1];
+
+    match io::write(Fd::STDOUT, &buf) {
+        Ok(count) if count == io::WRITE_MAX => true,
+        _ => {
+            nanvix::log!("failed to issue a short write");
+            false
+        },
+    }
+}
+
+/// Attempts to write to an invalid file descriptor.
+fn write_invalid_fd() -> bool {
+    if io::write(Fd::from_raw(-1), b"invalid\n").is_ok() {
+        nanvix::log!("succeeded to write to an invalid file descriptor");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if a whole buffer is written despite short writes.
+fn write_all_short() -> bool {
+    let msg: &[u8] = b"[test] all bytes eventually go out";
+    let mut out: [u8; 64] = [0; 64];
+    let mut len: usize = 0;
+    let mut calls: usize = 0;
+
+    // The mock accepts a few bytes at a time and is interrupted once.
+    let result: Result<(), KcallError> = io::write_all_with(msg, |bytes| {
+        calls += 1;
+        if calls == 2 {
+            return Err(KcallError::Interrupted);
+        }
+        let count: usize = bytes.len().min(MOCK_WRITE_MAX);
+        out[len..len + count].copy_from_slice(&bytes[..count]);
+        len += count;
+        Ok(count)
+    });
+
+    if result != Ok(()) || &out[..len] != msg {
+        nanvix::log!("failed to write all bytes ({:?})", result);
+        return false;
+    }
+
+    if calls != msg.len().div_ceil(MOCK_WRITE_MAX) + 1 {
+        nanvix::log!("unexpected number of writes {}", calls);
+        return false;
+    }
+
+    // A write that accepts nothing should not be retried forever.
+    if io::write_all_with(msg, |_| Ok(0)) != Err(KcallError::WriteZero) {
+        nanvix::log!("failed to report a zero-length write");
+        return false;
+    }
+
+    // Errors should be reported, and empty buffers should need no writes.
+    if io::write_all_with(msg, |_| Err(KcallError::BadAddress))
+        != Err(KcallError::BadAddress)
+        || io::write_all_with(b"", |_| Ok(0)) != Ok(())
+    {
+        nanvix::log!("unexpected result of write");
+        return false;
+    }
+
+    io::write_all(Fd::STDOUT, b"[test] write all\n") == Ok(())
+}
+
+/// Attempts to write an empty list of buffers.
+fn writev_empty() -> bool {
+    io::writev(Fd::STDOUT, &[]) == Ok(0)
+}
+
+/// Attempts to write a single buffer with a vectored write.
+fn writev_single() -> bool {
+    let buf: &[u8] = b"[test] vectored write\n";
+    io::writev(Fd::STDOUT, &[buf]) == Ok(buf.len())
+}
+
+/// Attempts to write several buffers, the last of which is empty.
+fn writev_several() -> bool {
+    let bufs: [&[u8]; 4] = [b"[test] ", b"vectored ", b"writes\n", b""];
+    let len: usize = bufs.iter().map(|buf| buf.len()).sum();
+    io::writev(Fd::STDOUT, &bufs) == Ok(len)
+}
+
+/// Attempts to print formatted text to the standard output.
+fn print_formatted() -> bool {
+    let pid: pm::Pid = pm::Pid::SELF;
+    nanvix::print!("[test] pid={}", pid);
+    nanvix::println!();
+    nanvix::println!("[test] pid={}", pid);
+
+    true
+}
+
+/// Attempts to print text that does not fit in a single write.
+fn print_long() -> bool {
+    let mut stdout: io::Stdout = io::Stdout::new();
+
+    // Write text that spans several chunks.
+    for i in 0..(2 * io::WRITE_MAX) {
+        if write!(&mut stdout, "{}", i % 10).is_err() {
+            nanvix::log!("failed to write to the standard output");
+            return false;
+        }
+    }
+
+    if writeln!(&mut stdout).is_err() || stdout.flush().is_err() {
+        nanvix::log!("failed to flush the standard output");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to write small chunks through a buffered writer.
+fn buf_writer_chunks() -> bool {
+    let mut writer: BufWriter<BUF_SIZE> = BufWriter::new(Fd::STDOUT);
+
+    for i in 0..BUF_CHUNKS {
+        if write!(&mut writer, "{}", i % 10).is_err() {
+            nanvix::log!("failed to write chunk {}", i);
+            return false;
+        }
+
+        // Output should only be written when the buffer fills up.
+        let written: usize = i + 1;
+        if writer.flushed() != (written - 1) / BUF_SIZE * BUF_SIZE {
+            nanvix::log!("unexpected flush after {} chunks", written);
+            return false;
+        }
+    }
+
+    // Pending bytes should be the tail of what was written.
+    let pending: usize = BUF_CHUNKS - writer.flushed();
+    for (i, byte) in writer.buffer().iter().enumerate() {
+        let chunk: usize = BUF_CHUNKS - pending + i;
+        if *byte != b'0' + (chunk % 10) as u8 {
+            nanvix::log!("unexpected pending byte {}", i);
+            return false;
+        }
+    }
+
+    if writer.buffer().len() != pending
+        || writeln!(&mut writer).is_err()
+        || writer.flush().is_err()
+    {
+        nanvix::log!("failed to flush buffered writer");
+        return false;
+    }
+
+    writer.flushed() == BUF_CHUNKS + 1 && writer.buffer().is_empty()
+}
+
+/// Checks if the error of a failed write is kept for the caller.
+fn buf_writer_take_error() -> bool {
+    let mut writer: BufWriter<BUF_SIZE> = BufWriter::new(Fd::from_raw(-1));
+
+    // Output is buffered, thus the write only fails once it is flushed.
+    if write!(&mut writer, "lost").is_err() || writer.take_error().is_some() {
+        nanvix::log!("unexpected error before flushing");
+        return false;
+    }
+
+    if writer.flush().is_ok() {
+        nanvix::log!("succeeded to write to an invalid file descriptor");
+        return false;
+    }
+
+    if writer.take_error().is_none() {
+        nanvix::log!("failed to keep write error");
+        return false;
+    }
+
+    // The error should only be taken once.
+    if writer.take_error().is_some() {
+        nanvix::log!("write error was taken twice");
+        return false;
+    }
+
+    // Successful writes should keep no error.
+    let mut stdout: Stdout = Stdout::new();
+    if writeln!(&mut stdout, "[test] no error").is_err()
+        || stdout.flush().is_err()
+    {
+        nanvix::log!("failed to write to the standard output");
+        return false;
+    }
+
+    stdout.take_error().is_none()
+}
+
+/// Attempts to write to the standard output through trait objects.
+fn write_trait_object() -> bool {
+    let mut fd: Fd = Fd::STDOUT;
+    let mut stdout: Stdout = Stdout::new();
+    let mut writer: BufWriter<BUF_SIZE> = BufWriter::new(Fd::STDOUT);
+    let mut writers: [&mut dyn io::Write; 3] =
+        [&mut fd, &mut stdout, &mut writer];
+
+    for (i, writer) in writers.iter_mut().enumerate() {
+        let msg: &[u8] = b"[test] writing through a trait object\n";
+        match writer.write(msg) {
+            Ok(count) if count > 0 && count <= msg.len() => {},
+            _ => {
+                nanvix::log!("failed to write through writer {}", i);
+                return false;
+            },
+        }
+
+        if writer.write_all(b"[test] writing all through a trait object\n")
+            != Ok(())
+            || writer.flush() != Ok(())
+        {
+            nanvix::log!("failed to write all through writer {}", i);
+            return false;
+        }
+    }
+
+    // Errors should be reported through the trait object.
+    let mut invalid: BufWriter<BUF_SIZE> = BufWriter::new(Fd::from_raw(-1));
+    let writer: &mut dyn io::Write = &mut invalid;
+    if writer.write_all(b"lost").is_err() || writer.flush().is_ok() {
+        nanvix::log!("failed to report write error through trait object");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if file descriptors are only used for the operations they allow.
+fn fd_access() -> bool {
+    if !Fd::STDOUT.is_writable() || Fd::STDOUT.is_readable() {
+        nanvix::log!("unexpected access of the standard output");
+        return false;
+    }
+
+    // Reading the standard output should be rejected.
+    let mut buf: [u8; 8] = [0; 8];
+    let mut stdout: Fd = Fd::STDOUT;
+    let reader: &mut dyn io::Read = &mut stdout;
+    if reader.read(&mut buf) != Err(KcallError::AccessDenied) {
+        nanvix::log!("succeeded to read the standard output");
+        return false;
+    }
+
+    // Writing a read-only file descriptor should be rejected.
+    let fd: Fd = Fd::with_access(Fd::STDOUT.as_raw(), FdAccess::ReadOnly);
+    if io::write(fd, b"rejected\n") != Err(KcallError::AccessDenied) {
+        nanvix::log!("succeeded to write a read-only file descriptor");
+        return false;
+    }
+
+    // There is no read kernel call yet.
+    let fd: Fd = Fd::with_access(Fd::STDOUT.as_raw(), FdAccess::ReadWrite);
+    if io::read(fd, &mut buf) != Err(KcallError::NotSupported) {
+        nanvix::log!("unexpected result of read");
+        return false;
+    }
+
+    fd != Fd::STDOUT && fd.access() == FdAccess::ReadWrite
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Tests the input/output facility.
+///
+pub fn test() {
+    crate::test!(standard_fds());
+    crate::test!(write_stdout());
+    crate::test!(write_stderr());
+    crate::test!(write_empty());
+    crate::test!(write_short());
+    crate::test!(write_invalid_fd());
+    crate::test!(write_all_short());
+    crate::test!(writev_empty());
+    crate::test!(writev_single());
+    crate::test!(writev_several());
+    crate::test!(print_formatted());
+    crate::test!(print_long());
+    crate::test!(buf_writer_chunks());
+    crate::test!(buf_writer_take_error());
+    crate::test!(write_trait_object());
+    crate::test!(fd_access());
+}
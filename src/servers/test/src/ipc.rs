@@ -7,8 +7,27 @@
 // Imports
 //==============================================================================
 
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use core::ffi;
 use nanvix::{
-    ipc,
+    io,
+    ipc::{
+        self,
+        mpsc,
+        spsc::{
+            self,
+            Consumer,
+            Producer,
+            Ring,
+        },
+        Receiver,
+        Sender,
+        SharedMailbox,
+    },
+    kcall::KcallError,
     memory::{
         self,
         PageInfo,
@@ -18,11 +37,80 @@ use nanvix::{
     misc,
     pm::{
         self,
+        Pid,
         ProcessInfo,
+        Tid,
     },
     security::AccessMode,
+    thread::{
+        self,
+        JoinHandle,
+    },
 };
 
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Tag of the channel used in tests.
+const CHANNEL_TAG: u32 = 100;
+
+/// Message sent through the channel used in tests.
+const CHANNEL_MESSAGE: &[u8] = b"hello, channel";
+
+/// Tag of the channel used in non-blocking receive tests.
+const POLL_TAG: u32 = 102;
+
+/// Tag of the first channel used in select tests.
+const SELECT_TAG_BASE: u32 = 103;
+
+/// Index of the channel that becomes ready in select tests.
+const SELECT_READY: usize = 1;
+
+/// Tag of the mailbox used in shared mailbox tests.
+const SHARED_TAG: u32 = 106;
+
+/// Number of threads that share a mailbox in tests.
+const SHARED_THREADS: usize = 3;
+
+/// Tag of the queue used in tests.
+const QUEUE_TAG: u32 = 101;
+
+/// Number of sender threads used in queue tests.
+const QUEUE_SENDERS: u8 = 3;
+
+/// Number of messages sent by each sender thread in queue tests.
+const QUEUE_MESSAGES: u8 = 8;
+
+/// Tag of the channel used in drain tests.
+const DRAIN_TAG: u32 = 107;
+
+/// Messages enqueued in drain tests.
+const DRAIN_MESSAGES: [&[u8]; 3] = [b"first", b"second", b"third"];
+
+/// Tag of the channel used in duplication tests.
+const CLONE_TAG: u32 = 108;
+
+/// Size of the region of rings used in tests (in bytes).
+const RING_REGION_SIZE: usize = 256;
+
+/// Number of bytes pushed through rings in tests.
+const RING_BYTES: usize = 4096;
+
+/// Size of the chunks pushed through rings in tests (in bytes).
+const RING_CHUNK: usize = 7;
+
+/// Offset of the tail in the header of a ring (in bytes).
+const RING_TAIL_OFFSET: usize = 128;
+
+//==============================================================================
+// Compile-Time Checks
+//==============================================================================
+
+// Handles to kernel objects must be duplicated with `try_clone()`.
+crate::assert_not_clone!(Receiver);
+crate::assert_not_clone!(Sender);
+
 //==============================================================================
 // Private Standalone Functions
 //==============================================================================
@@ -395,6 +483,717 @@ fn do_mailbox_write_invalid_size() -> bool {
 ///
 /// Tests kernel calls in the inter-process communication facility.
 ///
+/// Gets the PID of the calling process.
+fn self_pid() -> Pid {
+    let mut info: ProcessInfo = ProcessInfo::default();
+    pm::pinfo(Pid::SELF, &mut info);
+    info.pid
+}
+
+/// Sends a message between two threads through a channel.
+fn channel_round_trip() -> bool {
+    let rx: Receiver = match Receiver::create(CHANNEL_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create channel");
+            return false;
+        },
+    };
+
+    let owner: Pid = self_pid();
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+        let code: i32 = match Sender::open(owner, CHANNEL_TAG) {
+            Ok(tx) if tx.send(CHANNEL_MESSAGE).is_ok() => 0,
+            _ => 1,
+        };
+        thread::exit(code)
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn sender thread");
+            return false;
+        },
+    };
+
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    let len: usize = match rx.recv(&mut buf) {
+        Ok(len) => len,
+        Err(_) => {
+            nanvix::log!("failed to receive message");
+            return false;
+        },
+    };
+
+    if handle.join() != Ok(0) {
+        nanvix::log!("failed to send message");
+        return false;
+    }
+
+    &buf[..len] == CHANNEL_MESSAGE
+}
+
+/// Duplicates the sending half of a channel.
+fn sender_try_clone() -> bool {
+    let rx: Receiver = match Receiver::create(CLONE_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create channel");
+            return false;
+        },
+    };
+    let tx: Sender = match Sender::open(self_pid(), CLONE_TAG) {
+        Ok(tx) => tx,
+        Err(_) => {
+            nanvix::log!("failed to open channel");
+            return false;
+        },
+    };
+
+    // Dropping the duplicate should leave the original usable.
+    match tx.try_clone() {
+        Ok(clone) if clone.send(CHANNEL_MESSAGE).is_ok() => {},
+        _ => {
+            nanvix::log!("failed to send message through duplicate");
+            return false;
+        },
+    }
+    if tx.send(CHANNEL_MESSAGE).is_err() {
+        nanvix::log!("failed to send message through original");
+        return false;
+    }
+
+    for _ in 0..2 {
+        let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] =
+            [0; ipc::CHANNEL_MESSAGE_MAX];
+        match rx.recv(&mut buf) {
+            Ok(len) if &buf[..len] == CHANNEL_MESSAGE => {},
+            _ => {
+                nanvix::log!("failed to receive message");
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Receives messages through a channel that is used as a reader.
+fn receiver_read() -> bool {
+    let mut rx: Receiver = match Receiver::create(CHANNEL_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create channel");
+            return false;
+        },
+    };
+    let tx: Sender = match Sender::open(self_pid(), CHANNEL_TAG) {
+        Ok(tx) => tx,
+        Err(_) => {
+            nanvix::log!("failed to open channel");
+            return false;
+        },
+    };
+
+    if tx.send(CHANNEL_MESSAGE).is_err() || tx.send(b"").is_err() {
+        nanvix::log!("failed to send messages");
+        return false;
+    }
+
+    // Every read should receive a whole message.
+    let reader: &mut dyn io::Read = &mut rx;
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    match reader.read(&mut buf) {
+        Ok(len) if &buf[..len] == CHANNEL_MESSAGE => {},
+        _ => {
+            nanvix::log!("failed to read message");
+            return false;
+        },
+    }
+    match reader.read(&mut buf) {
+        Ok(0) => true,
+        _ => {
+            nanvix::log!("failed to read empty message");
+            false
+        },
+    }
+}
+
+/// Pushes a known sequence of bytes into a ring.
+fn ring_producer(arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    let producer: &Producer = unsafe { &*(arg as *const Producer) };
+
+    // Chunks do not divide the capacity, so that they wrap around the end.
+    let mut chunk: [u8; RING_CHUNK] = [0; RING_CHUNK];
+    let mut next: usize = 0;
+    while next < RING_BYTES {
+        let len: usize = core::cmp::min(RING_CHUNK, RING_BYTES - next);
+        for (i, byte) in chunk[..len].iter_mut().enumerate() {
+            *byte = (next + i) as u8;
+        }
+        producer.push(&chunk[..len]);
+        next += len;
+    }
+
+    core::ptr::null_mut()
+}
+
+/// Pushes and pops a known sequence of bytes through a ring shared by two
+/// threads.
+fn spsc_ring_sequence() -> bool {
+    let mut region: Vec<u8> = vec![0; RING_REGION_SIZE];
+    let mut ring: Ring = match Ring::create(&mut region) {
+        Ok(ring) => ring,
+        Err(_) => {
+            nanvix::log!("failed to create ring");
+            return false;
+        },
+    };
+    if !ring.capacity().is_power_of_two()
+        || ring.capacity() > RING_REGION_SIZE - spsc::RING_HEADER_SIZE
+    {
+        nanvix::log!("unexpected capacity {}", ring.capacity());
+        return false;
+    }
+
+    let (producer, consumer): (Producer, Consumer) = ring.split();
+    let arg: *mut ffi::c_void = &producer as *const Producer as *mut _;
+    let tid: Tid = pm::thread_create(ring_producer, arg);
+
+    let mut ok: bool = true;
+    let mut buf: [u8; RING_CHUNK + 1] = [0; RING_CHUNK + 1];
+    let mut next: usize = 0;
+    while next < RING_BYTES {
+        let len: usize = core::cmp::min(buf.len(), RING_BYTES - next);
+        consumer.pop(&mut buf[..len]);
+        for (i, byte) in buf[..len].iter().enumerate() {
+            if *byte != (next + i) as u8 {
+                ok = false;
+            }
+        }
+        next += len;
+    }
+
+    let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+    if pm::thread_join(tid, &mut retval) < 0 {
+        nanvix::log!("failed to join producer thread");
+        return false;
+    }
+
+    if !ok || !consumer.ring().is_empty() {
+        nanvix::log!("unexpected sequence popped from ring");
+        return false;
+    }
+
+    true
+}
+
+/// Attaches to rings in regions of memory.
+fn spsc_ring_attach() -> bool {
+    let mut region: Vec<u8> = vec![0; RING_REGION_SIZE];
+
+    // A region that was never initialized holds no ring.
+    if Ring::attach(&mut region).is_ok() {
+        nanvix::log!("succeeded to attach to an uninitialized ring");
+        return false;
+    }
+
+    let capacity: usize = match Ring::create(&mut region) {
+        Ok(mut ring) => {
+            let (producer, _): (Producer, Consumer) = ring.split();
+            if producer.try_push(CHANNEL_MESSAGE) != CHANNEL_MESSAGE.len() {
+                nanvix::log!("failed to push into ring");
+                return false;
+            }
+            ring.capacity()
+        },
+        Err(_) => {
+            nanvix::log!("failed to create ring");
+            return false;
+        },
+    };
+
+    // Another view should see the pending bytes.
+    let mut ring: Ring = match Ring::attach(&mut region) {
+        Ok(ring) if ring.capacity() == capacity => ring,
+        _ => {
+            nanvix::log!("failed to attach to ring");
+            return false;
+        },
+    };
+    let (_, consumer): (Producer, Consumer) = ring.split();
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    let len: usize = consumer.try_pop(&mut buf);
+    if &buf[..len] != CHANNEL_MESSAGE || consumer.try_pop(&mut buf) != 0 {
+        nanvix::log!("unexpected bytes popped from ring");
+        return false;
+    }
+
+    // Regions that may not hold any data should be rejected.
+    let mut small: [u8; spsc::RING_HEADER_SIZE] = [0; spsc::RING_HEADER_SIZE];
+    Ring::create(&mut small).is_err()
+}
+
+/// Pushes and pops through a ring whose tail is out of bounds.
+fn spsc_ring_corrupted() -> bool {
+    let mut region: Vec<u8> = vec![0; RING_REGION_SIZE];
+    let capacity: usize = match Ring::create(&mut region) {
+        Ok(ring) => ring.capacity(),
+        Err(_) => {
+            nanvix::log!("failed to create ring");
+            return false;
+        },
+    };
+
+    // Move the tail past the capacity, as a faulty peer would.
+    let offset: usize = region.as_ptr().align_offset(64) + RING_TAIL_OFFSET;
+    let tail: [u8; 4] = (4 * capacity as u32).to_ne_bytes();
+    region[offset..offset + tail.len()].copy_from_slice(&tail);
+
+    let mut ring: Ring = match Ring::attach(&mut region) {
+        Ok(ring) => ring,
+        Err(_) => {
+            nanvix::log!("failed to attach to ring");
+            return false;
+        },
+    };
+    let (producer, consumer): (Producer, Consumer) = ring.split();
+    let mut buf: [u8; RING_REGION_SIZE] = [0; RING_REGION_SIZE];
+    if consumer.try_pop(&mut buf) != 0 {
+        nanvix::log!("popped bytes from a corrupted ring");
+        return false;
+    }
+    if producer.try_push(CHANNEL_MESSAGE) != 0 {
+        nanvix::log!("pushed bytes into a corrupted ring");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to send a message that is too long through a channel.
+fn channel_send_too_long() -> bool {
+    let rx: Receiver = match Receiver::create(CHANNEL_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create channel");
+            return false;
+        },
+    };
+    let tx: Sender = match Sender::open(self_pid(), CHANNEL_TAG) {
+        Ok(tx) => tx,
+        Err(_) => {
+            nanvix::log!("failed to open channel");
+            return false;
+        },
+    };
+
+    let msg: [u8; ipc::CHANNEL_MESSAGE_MAX + 1] =
+        [0; ipc::CHANNEL_MESSAGE_MAX + 1];
+    if tx.send(&msg) != Err(KcallError::InvalidArgument) {
+        nanvix::log!("succeeded to send a message that is too long");
+        return false;
+    }
+
+    drop(tx);
+    drop(rx);
+
+    true
+}
+
+/// Attempts to receive a message from a channel without blocking.
+fn channel_try_recv() -> bool {
+    let rx: Receiver = match Receiver::create(POLL_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create channel");
+            return false;
+        },
+    };
+    let tx: Sender = match Sender::open(self_pid(), POLL_TAG) {
+        Ok(tx) => tx,
+        Err(_) => {
+            nanvix::log!("failed to open channel");
+            return false;
+        },
+    };
+
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    if rx.try_recv(&mut buf) != Ok(None) {
+        nanvix::log!("received a message from an empty channel");
+        return false;
+    }
+
+    if tx.send(CHANNEL_MESSAGE).is_err() {
+        nanvix::log!("failed to send message");
+        return false;
+    }
+
+    match rx.try_recv(&mut buf) {
+        Ok(Some(len)) if len == CHANNEL_MESSAGE.len() => {
+            &buf[..len] == CHANNEL_MESSAGE
+        },
+        _ => {
+            nanvix::log!("failed to receive pending message");
+            false
+        },
+    }
+}
+
+/// Waits for one of several channels to become ready.
+fn select_ready_channel() -> bool {
+    let receivers: [Receiver; 3] = match (
+        Receiver::create(SELECT_TAG_BASE),
+        Receiver::create(SELECT_TAG_BASE + 1),
+        Receiver::create(SELECT_TAG_BASE + 2),
+    ) {
+        (Ok(a), Ok(b), Ok(c)) => [a, b, c],
+        _ => {
+            nanvix::log!("failed to create channels");
+            return false;
+        },
+    };
+
+    // Send a message once the main thread is waiting.
+    let owner: Pid = self_pid();
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+        thread::yield_now();
+        let tag: u32 = SELECT_TAG_BASE + SELECT_READY as u32;
+        let code: i32 = match Sender::open(owner, tag) {
+            Ok(tx) if tx.send(CHANNEL_MESSAGE).is_ok() => 0,
+            _ => 1,
+        };
+        thread::exit(code)
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn sender thread");
+            return false;
+        },
+    };
+
+    let refs: [&Receiver; 3] = [&receivers[0], &receivers[1], &receivers[2]];
+    let index: Result<usize, KcallError> = ipc::select(&refs);
+
+    if handle.join() != Ok(0) {
+        nanvix::log!("failed to send message");
+        return false;
+    }
+
+    if index != Ok(SELECT_READY) {
+        nanvix::log!("unexpected ready channel {:?}", index);
+        return false;
+    }
+
+    // The message should still be pending on the ready channel.
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    match receivers[SELECT_READY].try_recv(&mut buf) {
+        Ok(Some(len)) => &buf[..len] == CHANNEL_MESSAGE,
+        _ => {
+            nanvix::log!("failed to receive message from ready channel");
+            false
+        },
+    }
+}
+
+/// Waits until one of several channels has a pending message.
+fn wait_any_ready_channel() -> bool {
+    let receivers: [Receiver; 3] = match (
+        Receiver::create(SELECT_TAG_BASE),
+        Receiver::create(SELECT_TAG_BASE + 1),
+        Receiver::create(SELECT_TAG_BASE + 2),
+    ) {
+        (Ok(a), Ok(b), Ok(c)) => [a, b, c],
+        _ => {
+            nanvix::log!("failed to create channels");
+            return false;
+        },
+    };
+
+    // Send a message once the main thread is waiting.
+    let owner: Pid = self_pid();
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+        thread::yield_now();
+        let tag: u32 = SELECT_TAG_BASE + SELECT_READY as u32;
+        let code: i32 = match Sender::open(owner, tag) {
+            Ok(tx) if tx.send(CHANNEL_MESSAGE).is_ok() => 0,
+            _ => 1,
+        };
+        thread::exit(code)
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn sender thread");
+            return false;
+        },
+    };
+
+    let refs: [&Receiver; 3] = [&receivers[0], &receivers[1], &receivers[2]];
+    let index: Result<usize, KcallError> = ipc::wait_any(&refs);
+
+    if handle.join() != Ok(0) {
+        nanvix::log!("failed to send message");
+        return false;
+    }
+
+    if index != Ok(SELECT_READY) {
+        nanvix::log!("unexpected ready channel {:?}", index);
+        return false;
+    }
+
+    // The message should still be pending on the ready channel.
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    match receivers[SELECT_READY].try_recv(&mut buf) {
+        Ok(Some(len)) if &buf[..len] == CHANNEL_MESSAGE => {},
+        _ => {
+            nanvix::log!("failed to receive message from ready channel");
+            return false;
+        },
+    }
+
+    // Attempt to wait on no channels.
+    if ipc::wait_any(&[]) != Err(KcallError::InvalidArgument) {
+        nanvix::log!("succeeded to wait on no channels");
+        return false;
+    }
+
+    true
+}
+
+/// Sends messages from several threads through a queue.
+fn mpsc_many_senders() -> bool {
+    let (tx, rx): (mpsc::Sender, mpsc::Receiver) =
+        match mpsc::channel(QUEUE_TAG) {
+            Ok(queue) => queue,
+            Err(_) => {
+                nanvix::log!("failed to create queue");
+                return false;
+            },
+        };
+
+    // Spawn senders. Each message carries the sender and a sequence number.
+    let mut handles: [Option<JoinHandle>; QUEUE_SENDERS as usize] =
+        [None, None, None];
+    for (id, handle) in handles.iter_mut().enumerate() {
+        let tx: mpsc::Sender = tx.clone();
+        let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+            for seq in 0..QUEUE_MESSAGES {
+                if tx.send(&[id as u8, seq]).is_err() {
+                    thread::exit(1);
+                }
+            }
+            thread::exit(0)
+        });
+        match ret {
+            Ok(h) => *handle = Some(h),
+            Err(_) => {
+                nanvix::log!("failed to spawn sender thread");
+                return false;
+            },
+        }
+    }
+
+    // Receive all messages, checking that each sender is seen in order.
+    let mut next: [u8; QUEUE_SENDERS as usize] = [0; QUEUE_SENDERS as usize];
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    for _ in 0..(QUEUE_SENDERS * QUEUE_MESSAGES) {
+        match rx.recv(&mut buf) {
+            Ok(2)
+                if buf[0] < QUEUE_SENDERS
+                    && buf[1] == next[buf[0] as usize] =>
+            {
+                next[buf[0] as usize] += 1;
+            },
+            _ => {
+                nanvix::log!("received unexpected message");
+                return false;
+            },
+        }
+    }
+
+    for handle in handles.iter_mut() {
+        if handle.take().map(|h| h.join()) != Some(Ok(0)) {
+            nanvix::log!("failed to send messages");
+            return false;
+        }
+    }
+
+    // Queue should be drained now.
+    if rx.try_recv(&mut buf) != Err(mpsc::TryRecvError::Empty) {
+        nanvix::log!("received a message from a drained queue");
+        return false;
+    }
+
+    true
+}
+
+/// Shares an open mailbox among several threads.
+fn shared_mailbox_clones() -> bool {
+    const MSG_SIZE: usize = ipc::MAILBOX_MESSAGE_SIZE as usize;
+
+    let owner: Pid = self_pid();
+    if ipc::mailbox_create(owner.into(), SHARED_TAG) < 0 {
+        nanvix::log!("failed to create mailbox");
+        return false;
+    }
+    let mailbox: SharedMailbox = match SharedMailbox::open(owner, SHARED_TAG) {
+        Ok(mailbox) => mailbox,
+        Err(_) => {
+            nanvix::log!("failed to open shared mailbox");
+            return false;
+        },
+    };
+    let ombxid: u32 = mailbox.ombxid();
+
+    // Each thread writes a message through its own clone and drops it.
+    let mut handles: [Option<JoinHandle>; SHARED_THREADS] = [None, None, None];
+    for (id, handle) in handles.iter_mut().enumerate() {
+        let clone: SharedMailbox = mailbox.clone();
+        let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+            let code: i32 = match clone.write(&[id as u8; MSG_SIZE]) {
+                Ok(()) => 0,
+                Err(_) => 1,
+            };
+            drop(clone);
+            thread::exit(code)
+        });
+        match ret {
+            Ok(h) => *handle = Some(h),
+            Err(_) => {
+                nanvix::log!("failed to spawn thread");
+                return false;
+            },
+        }
+    }
+    for handle in handles.iter_mut() {
+        if handle.take().map(|h| h.join()) != Some(Ok(0)) {
+            nanvix::log!("failed to write through a shared mailbox");
+            return false;
+        }
+    }
+
+    // Dropping clones should not close the mailbox.
+    if mailbox.strong_count() != 1 {
+        nanvix::log!("unexpected reference count {}", mailbox.strong_count());
+        return false;
+    }
+    let mut seen: [bool; SHARED_THREADS] = [false; SHARED_THREADS];
+    let mut buf: [u8; MSG_SIZE] = [0; MSG_SIZE];
+    for _ in 0..SHARED_THREADS {
+        match mailbox.read(&mut buf) {
+            Ok(()) if (buf[0] as usize) < SHARED_THREADS => {
+                seen[buf[0] as usize] = true
+            },
+            _ => {
+                nanvix::log!("failed to read through a shared mailbox");
+                return false;
+            },
+        }
+    }
+    if seen.contains(&false) {
+        nanvix::log!("missing messages in shared mailbox");
+        return false;
+    }
+
+    // Dropping the last handle should close the mailbox, exactly once. This
+    // releases the mailbox, because the calling process holds no other
+    // reference to it.
+    drop(mailbox);
+    if ipc::mailbox_close(ombxid) >= 0 {
+        nanvix::log!("mailbox was not closed by the last handle");
+        return false;
+    }
+    if ipc::mailbox_open(owner.into(), SHARED_TAG) >= 0 {
+        nanvix::log!("mailbox was not released");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to receive a message from an empty queue.
+fn mpsc_try_recv_empty() -> bool {
+    let (tx, rx): (mpsc::Sender, mpsc::Receiver) =
+        match mpsc::channel(QUEUE_TAG) {
+            Ok(queue) => queue,
+            Err(_) => {
+                nanvix::log!("failed to create queue");
+                return false;
+            },
+        };
+
+    let mut buf: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    if rx.try_recv(&mut buf) != Err(mpsc::TryRecvError::Empty) {
+        nanvix::log!("received a message from an empty queue");
+        return false;
+    }
+
+    if tx.send(CHANNEL_MESSAGE).is_err() {
+        nanvix::log!("failed to send message");
+        return false;
+    }
+
+    match rx.try_recv(&mut buf) {
+        Ok(len) => &buf[..len] == CHANNEL_MESSAGE,
+        Err(_) => {
+            nanvix::log!("failed to receive pending message");
+            false
+        },
+    }
+}
+
+/// Drains the pending messages of a channel.
+fn channel_drain() -> bool {
+    let mut rx: Receiver = match Receiver::create(DRAIN_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create channel");
+            return false;
+        },
+    };
+    let tx: Sender = match Sender::open(self_pid(), DRAIN_TAG) {
+        Ok(tx) => tx,
+        Err(_) => {
+            nanvix::log!("failed to open channel");
+            return false;
+        },
+    };
+
+    for msg in DRAIN_MESSAGES.iter() {
+        if tx.send(msg).is_err() {
+            nanvix::log!("failed to send message");
+            return false;
+        }
+    }
+
+    let mut count: usize = 0;
+    for msg in rx.drain() {
+        match msg {
+            Ok(msg) if count < DRAIN_MESSAGES.len() => {
+                if msg.as_slice() != DRAIN_MESSAGES[count] {
+                    nanvix::log!("unexpected message {}", count);
+                    return false;
+                }
+                count += 1;
+            },
+            _ => {
+                nanvix::log!("failed to drain message {}", count);
+                return false;
+            },
+        }
+    }
+
+    if count != DRAIN_MESSAGES.len() {
+        nanvix::log!("drained {} messages", count);
+        return false;
+    }
+
+    // A drained channel should yield no messages.
+    rx.drain().next().is_none()
+}
+
 pub fn test() {
     crate::test!(check_sizes());
     crate::test!(test_semget_call());
@@ -407,4 +1206,18 @@ pub fn test() {
     crate::test!(do_mailbox_write_invalid_pointer());
     crate::test!(do_mailbox_read_invalid_pointer());
     crate::test!(do_mailbox_write_invalid_size());
+    crate::test!(channel_round_trip());
+    crate::test!(channel_send_too_long());
+    crate::test!(channel_try_recv());
+    crate::test!(select_ready_channel());
+    crate::test!(wait_any_ready_channel());
+    crate::test!(shared_mailbox_clones());
+    crate::test!(mpsc_many_senders());
+    crate::test!(mpsc_try_recv_empty());
+    crate::test!(channel_drain());
+    crate::test!(sender_try_clone());
+    crate::test!(receiver_read());
+    crate::test!(spsc_ring_sequence());
+    crate::test!(spsc_ring_attach());
+    crate::test!(spsc_ring_corrupted());
 }
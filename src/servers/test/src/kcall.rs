@@ -7,7 +7,59 @@
 // Imports
 //==============================================================================
 
-use nanvix::kcall;
+#[cfg(feature = "kcall-log")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[cfg(feature = "trace")]
+use core::sync::atomic::{
+    AtomicU32,
+    Ordering,
+};
+use nanvix::kcall::{
+    self,
+    KcallBatch,
+    KcallError,
+    KcallNumbers,
+    KcallRet,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Encoding of the instruction that issues kernel calls.
+#[cfg(all(
+    target_arch = "x86",
+    not(any(feature = "trace", feature = "kcall-log"))
+))]
+const KCALL_INSTRUCTION: [u8; 2] = [0xcd, 0x80];
+
+/// Number of bytes scanned when looking for the kernel call instruction.
+#[cfg(all(
+    target_arch = "x86",
+    not(any(feature = "trace", feature = "kcall-log"))
+))]
+const KCALL_SCAN_MAX: usize = 64;
+
+/// Number of kernel calls issued when measuring latency.
+#[cfg(target_arch = "x86")]
+const LATENCY_ITERATIONS: u32 = 128;
+
+/// Argument of the kernel call that is traced (the kernel checks it).
+#[cfg(feature = "trace")]
+const TRACE_ARG: u32 = 1;
+
+/// Arguments of the kernel calls that are logged (the kernel checks them).
+#[cfg(feature = "kcall-log")]
+const LOG_ARGS: [u32; 3] = [1, 2, 3];
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Number of traced void1 kernel calls whose argument is [`TRACE_ARG`].
+#[cfg(feature = "trace")]
+static TRACED: AtomicU32 = AtomicU32::new(0);
 
 //==============================================================================
 // Private Standalone Functions
@@ -38,6 +90,398 @@ fn issue_void4_kcall() -> bool {
     kcall::void4(1, 2, 3, 4) == 10
 }
 
+/// Issues a void5 kernel call.
+fn issue_void5_kcall() -> bool {
+    kcall::void5(1, 2, 3, 4, 5) == 15
+}
+
+/// Issues a void5 kernel call with a sixth argument, which the kernel adds to
+/// the sum if it arrives unchanged.
+fn issue_void5_kcall_with_six_args() -> bool {
+    let result: u32 = unsafe {
+        kcall::kcall6(kcall::KcallNumbers::Void5 as u32, 1, 2, 3, 4, 5, 6)
+    };
+    if result != 21 {
+        nanvix::log!("sixth argument was not passed (result={})", result);
+        return false;
+    }
+
+    true
+}
+
+/// Checks if void2 kernel calls behave the same whether or not the kernel call
+/// stub is inlined into the caller.
+fn void2_inline_consistency() -> bool {
+    // Calls through a function pointer are never inlined.
+    let stub: unsafe fn(u32, u32, u32) -> u32 = kcall::kcall2;
+    let args: [(u32, u32); 4] = [(0, 0), (1, 2), (0x1000, 0x234), (7, 0)];
+
+    for (arg0, arg1) in args {
+        let inlined: u32 = kcall::void2(arg0, arg1);
+        let called: u32 =
+            unsafe { stub(kcall::KcallNumbers::Void2 as u32, arg0, arg1) };
+        if inlined != arg0 + arg1 || called != inlined {
+            nanvix::log!(
+                "mismatched void2 results for {:?}: {} != {}",
+                (arg0, arg1),
+                inlined,
+                called
+            );
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Issues a void1 kernel call through the checked interface.
+fn issue_void1_kcall_checked() -> bool {
+    let result: Result<u32, KcallError> =
+        unsafe { kcall::kcall1_checked(kcall::KcallNumbers::Void1 as u32, 1) };
+    result == Ok(1)
+}
+
+/// Issues a void1 kernel call and wraps its return value.
+fn issue_void1_kcall_raw() -> bool {
+    let ret: KcallRet =
+        unsafe { kcall::kcall1_raw(kcall::KcallNumbers::Void1 as u32, 1) };
+    ret.as_u32() == 1 && ret.as_i32() == 1 && !ret.is_err()
+}
+
+/// Checks if raw return values are interpreted around the sign boundary.
+fn kcall_ret_sign() -> bool {
+    let ret: KcallRet = KcallRet::from(0xffffffff);
+    if ret.as_i32() != -1 || ret.as_u32() != 0xffffffff || !ret.is_err() {
+        nanvix::log!("failed to interpret 0xffffffff");
+        return false;
+    }
+    if ret.err() != Some(KcallError::Unknown(1)) {
+        nanvix::log!("failed to decode 0xffffffff");
+        return false;
+    }
+
+    let ret: KcallRet = KcallRet::from(0x80000000);
+    if ret.as_i32() != i32::MIN || ret.as_u32() != 0x80000000 || !ret.is_err() {
+        nanvix::log!("failed to interpret 0x80000000");
+        return false;
+    }
+
+    let ret: KcallRet = KcallRet::from(0x7fffffff);
+    if ret.as_i32() != i32::MAX || ret.is_err() || ret.err().is_some() {
+        nanvix::log!("failed to interpret 0x7fffffff");
+        return false;
+    }
+
+    let ret: KcallRet = KcallRet::from((-28i32) as u32);
+    ret.err() == Some(KcallError::InvalidArgument)
+        && Result::<u32, KcallError>::from(ret)
+            == Err(KcallError::InvalidArgument)
+}
+
+/// Compares the results of batched and individual void kernel calls.
+fn batch_void_kcalls() -> bool {
+    let mut batch: KcallBatch = KcallBatch::new();
+    let pushed: bool =
+        batch.push(kcall::KcallNumbers::Void0 as u32, &[]).is_ok()
+            && batch.push(kcall::KcallNumbers::Void1 as u32, &[1]).is_ok()
+            && batch
+                .push(kcall::KcallNumbers::Void2 as u32, &[1, 2])
+                .is_ok()
+            && batch
+                .push(kcall::KcallNumbers::Void3 as u32, &[1, 2, 3])
+                .is_ok()
+            && batch
+                .push(kcall::KcallNumbers::Void4 as u32, &[1, 2, 3, 4])
+                .is_ok()
+            && batch
+                .push(kcall::KcallNumbers::Void5 as u32, &[1, 2, 3, 4, 5])
+                .is_ok();
+    if !pushed {
+        nanvix::log!("failed to push kernel calls");
+        return false;
+    }
+
+    let expected: [u32; 6] = [
+        kcall::void0(),
+        kcall::void1(1),
+        kcall::void2(1, 2),
+        kcall::void3(1, 2, 3),
+        kcall::void4(1, 2, 3, 4),
+        kcall::void5(1, 2, 3, 4, 5),
+    ];
+
+    batch.submit() == expected
+}
+
+/// Attempts to push invalid kernel calls to a batch.
+fn batch_invalid_push() -> bool {
+    let mut batch: KcallBatch = KcallBatch::new();
+
+    if batch
+        .push(kcall::KcallNumbers::Void5 as u32, &[0; 7])
+        .is_ok()
+    {
+        nanvix::log!("succeeded to push too many arguments");
+        return false;
+    }
+    for _ in 0..kcall::KCALL_BATCH_MAX {
+        if batch.push(kcall::KcallNumbers::Void0 as u32, &[]).is_err() {
+            nanvix::log!("failed to fill batch");
+            return false;
+        }
+    }
+
+    batch.push(kcall::KcallNumbers::Void0 as u32, &[])
+        == Err(KcallError::InvalidArgument)
+}
+
+/// Checks if non-negative return values are decoded as success.
+fn decode_success() -> bool {
+    if kcall::decode(0) != Ok(0) {
+        nanvix::log!("failed to decode zero");
+        return false;
+    }
+    if kcall::decode(i32::MAX as u32) != Ok(i32::MAX as u32) {
+        nanvix::log!("failed to decode largest non-negative value");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if error codes round-trip through decode.
+fn decode_errors() -> bool {
+    let errors: [(i32, KcallError); 10] = [
+        (2, KcallError::AccessDenied),
+        (3, KcallError::AddressInUse),
+        (6, KcallError::Again),
+        (10, KcallError::Busy),
+        (21, KcallError::BadAddress),
+        (28, KcallError::InvalidArgument),
+        (44, KcallError::NotFound),
+        (48, KcallError::NoMemory),
+        (64, KcallError::PermissionDenied),
+        (1, KcallError::Unknown(1)),
+    ];
+
+    for (errno, error) in errors {
+        if kcall::decode(errno.wrapping_neg() as u32) != Err(error) {
+            nanvix::log!("failed to decode error code {}", errno);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Checks if kernel call errors round-trip through error codes.
+fn errno_round_trip() -> bool {
+    for (error, errno) in kcall::ERRNO_TABLE.iter() {
+        if error.as_errno() != *errno
+            || KcallError::from_errno(error.as_errno()) != *error
+        {
+            nanvix::log!("failed to round-trip error code {}", errno);
+            return false;
+        }
+    }
+
+    // Short writes are only reported by the library, and they are not I/O
+    // errors of the kernel.
+    if KcallError::WriteZero.as_errno() != KcallError::Io.as_errno()
+        || KcallError::from_errno(KcallError::Io.as_errno()) != KcallError::Io
+    {
+        nanvix::log!("failed to tell short writes from I/O errors");
+        return false;
+    }
+
+    // Unknown error codes should be preserved.
+    let unknown: KcallError = KcallError::from_errno(1);
+    unknown == KcallError::Unknown(1) && unknown.as_errno() == 1
+}
+
+/// Checks if kernel calls are issued with `int 0x80`.
+#[cfg(all(
+    target_arch = "x86",
+    not(any(feature = "trace", feature = "kcall-log"))
+))]
+fn kcall_instruction() -> bool {
+    let code: &[u8] = unsafe {
+        core::slice::from_raw_parts(kcall::kcall0 as *const u8, KCALL_SCAN_MAX)
+    };
+
+    code.windows(KCALL_INSTRUCTION.len())
+        .any(|bytes| bytes == KCALL_INSTRUCTION)
+}
+
+/// Measures the latency of void kernel calls.
+#[cfg(target_arch = "x86")]
+fn measure_kcall_latency() -> bool {
+    let latency: u64 = kcall::measure_latency(LATENCY_ITERATIONS);
+    nanvix::log!("kcall latency: {} cycles", latency);
+
+    latency > 0 && kcall::measure_latency(0) == 0
+}
+
+/// Captures traced void1 kernel calls, issuing a traced kernel call itself.
+#[cfg(feature = "trace")]
+fn capture_trace(kcall_nr: u32, args: &[u32]) {
+    kcall::default_trace_hook(kcall_nr, args);
+    if kcall_nr == kcall::KcallNumbers::Void1 as u32 && args == [TRACE_ARG] {
+        TRACED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Traces a void1 kernel call.
+#[cfg(feature = "trace")]
+fn trace_void1_kcall() -> bool {
+    kcall::set_trace_hook(capture_trace);
+    let ret: u32 = kcall::void1(TRACE_ARG);
+    kcall::set_trace_hook(kcall::default_trace_hook);
+
+    ret == TRACE_ARG && TRACED.load(Ordering::SeqCst) == 1
+}
+
+/// Checks if the last kernel calls are logged in order.
+#[cfg(feature = "kcall-log")]
+fn log_last_kcalls() -> bool {
+    let [a, b, c]: [u32; 3] = LOG_ARGS;
+    kcall::void1(a);
+    kcall::void2(a, b);
+    kcall::void3(a, b, c);
+
+    let records: Vec<kcall::KcallRecord> = kcall::last_calls().collect();
+    if records.len() < 3 {
+        nanvix::log!("too few kernel calls were logged");
+        return false;
+    }
+
+    let expected: [(kcall::KcallNumbers, &[u32]); 3] = [
+        (kcall::KcallNumbers::Void1, &[a]),
+        (kcall::KcallNumbers::Void2, &[a, b]),
+        (kcall::KcallNumbers::Void3, &[a, b, c]),
+    ];
+    let last: &[kcall::KcallRecord] = &records[records.len() - 3..];
+    for (record, (kcall_nr, args)) in last.iter().zip(expected.iter()) {
+        if record.kcall() != Some(*kcall_nr)
+            || record.args() != *args
+            || record.ret() != args.iter().sum::<u32>()
+        {
+            nanvix::log!("unexpected log of {}", kcall_nr.name());
+            return false;
+        }
+    }
+
+    // The log should keep a bounded number of kernel calls.
+    for _ in 0..kcall::KCALL_LOG_MAX {
+        kcall::void0();
+    }
+    kcall::last_calls().count() == kcall::KCALL_LOG_MAX
+}
+
+/// Checks if kernel call numbers convert to and from raw numbers.
+fn kcall_numbers_try_from() -> bool {
+    for kcall_nr in KcallNumbers::ALL.iter() {
+        let n: u32 = u32::from(*kcall_nr);
+        if n != *kcall_nr as u32 || KcallNumbers::try_from(n) != Ok(*kcall_nr) {
+            nanvix::log!("failed to convert kernel call {}", kcall_nr.name());
+            return false;
+        }
+    }
+
+    // Number 5 is used, as in the kernel.
+    if KcallNumbers::try_from(5) != Ok(KcallNumbers::Void5) {
+        nanvix::log!("failed to convert kernel call number 5");
+        return false;
+    }
+
+    // The first unused number is the one past the last kernel call.
+    let unused: u32 = KcallNumbers::ALL.len() as u32;
+    KcallNumbers::try_from(unused) == Err(KcallError::InvalidArgument)
+        && KcallNumbers::try_from(u32::MAX) == Err(KcallError::InvalidArgument)
+}
+
+/// Checks if buffers are packed into pointer and length arguments.
+fn pack_ptr_len() -> bool {
+    let buf: [u8; 8] = [0; 8];
+    if kcall::ptr_len(&buf) != Ok((buf.as_ptr() as u32, buf.len() as u32)) {
+        nanvix::log!("failed to pack a buffer");
+        return false;
+    }
+
+    let mut buf: [u8; 8] = [0; 8];
+    let expected: (u32, u32) = (buf.as_mut_ptr() as u32, buf.len() as u32);
+    if kcall::ptr_len_mut(&mut buf[..]) != Ok(expected) {
+        nanvix::log!("failed to pack a mutable buffer");
+        return false;
+    }
+
+    // Empty buffers are packed the same way, wherever they point to.
+    if kcall::ptr_len(&[]) != Ok((0, 0))
+        || kcall::ptr_len(&buf[4..4]) != Ok((0, 0))
+        || kcall::ptr_len_mut(&mut []) != Ok((0, 0))
+    {
+        nanvix::log!("failed to pack an empty buffer");
+        return false;
+    }
+
+    // Buffers that are longer than a kernel call argument are rejected.
+    #[cfg(target_pointer_width = "64")]
+    {
+        let len: usize = u32::MAX as usize + 1;
+        // The buffer is never read.
+        let huge: &[u8] =
+            unsafe { core::slice::from_raw_parts(buf.as_ptr(), len) };
+        if kcall::ptr_len(huge) != Err(KcallError::InvalidArgument) {
+            nanvix::log!("failed to reject a buffer that is too long");
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Checks if kernel call numbers round-trip through raw numbers.
+fn kcall_numbers_round_trip() -> bool {
+    for kcall_nr in kcall::KcallNumbers::ALL.iter() {
+        if kcall::KcallNumbers::from_u32(*kcall_nr as u32) != Some(*kcall_nr) {
+            nanvix::log!(
+                "failed to round-trip kernel call {}",
+                kcall_nr.name()
+            );
+            return false;
+        }
+    }
+
+    if kcall::KcallNumbers::MailboxRead.name() != "MailboxRead" {
+        nanvix::log!("unexpected name for MailboxRead");
+        return false;
+    }
+
+    // Number 5 is used, as in the kernel.
+    if kcall::KcallNumbers::from_u32(5) != Some(kcall::KcallNumbers::Void5) {
+        nanvix::log!("failed to convert kernel call number 5");
+        return false;
+    }
+
+    let last: u32 = kcall::KcallNumbers::ALL.len() as u32;
+    kcall::KcallNumbers::from_u32(last).is_none()
+}
+
+/// Checks if both names of the mailbox read kernel call resolve to 44.
+#[allow(deprecated)]
+fn mailbox_read_number() -> bool {
+    if kcall::KcallNumbers::MailboxRead as u32 != 44 {
+        nanvix::log!("unexpected number for MailboxRead");
+        return false;
+    }
+    if kcall::KcallNumbers::Mailboxread as u32 != 44 {
+        nanvix::log!("unexpected number for Mailboxread");
+        return false;
+    }
+
+    kcall::KcallNumbers::Mailboxread == kcall::KcallNumbers::MailboxRead
+}
+
 //==============================================================================
 // Public Standalone Functions
 //==============================================================================
@@ -53,4 +497,30 @@ pub fn test() {
     crate::test!(issue_void2_kcall());
     crate::test!(issue_void3_kcall());
     crate::test!(issue_void4_kcall());
+    crate::test!(issue_void5_kcall());
+    crate::test!(issue_void5_kcall_with_six_args());
+    crate::test!(void2_inline_consistency());
+    crate::test!(issue_void1_kcall_checked());
+    crate::test!(issue_void1_kcall_raw());
+    crate::test!(kcall_ret_sign());
+    crate::test!(batch_void_kcalls());
+    crate::test!(batch_invalid_push());
+    crate::test!(decode_success());
+    crate::test!(decode_errors());
+    crate::test!(errno_round_trip());
+    crate::test!(kcall_numbers_round_trip());
+    crate::test!(kcall_numbers_try_from());
+    crate::test!(pack_ptr_len());
+    crate::test!(mailbox_read_number());
+    #[cfg(all(
+        target_arch = "x86",
+        not(any(feature = "trace", feature = "kcall-log"))
+    ))]
+    crate::test!(kcall_instruction());
+    #[cfg(target_arch = "x86")]
+    crate::test!(measure_kcall_latency());
+    #[cfg(feature = "trace")]
+    crate::test!(trace_void1_kcall());
+    #[cfg(feature = "kcall-log")]
+    crate::test!(log_last_kcalls());
 }
@@ -10,19 +10,53 @@
 // Modules
 //==============================================================================
 
+pub mod arch;
+pub mod excp;
+pub mod ffi;
+pub mod fmt;
+pub mod iam;
+pub mod io;
 pub mod ipc;
 pub mod kcall;
 pub mod misc;
 pub mod mm;
+pub mod panic;
 pub mod pm;
+pub mod prelude;
+pub mod sync;
+pub mod thread;
+pub mod time;
 
 //==============================================================================
 // Imports
 //==============================================================================
 
+extern crate alloc;
 extern crate nanvix;
 
-use nanvix::power;
+use nanvix::{
+    memory::FrameBackedAllocator,
+    power,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Start address of the heap.
+const HEAP_START: u32 = 0x05000000;
+
+/// End address of the heap.
+const HEAP_END: u32 = 0x06000000;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Global allocator.
+#[global_allocator]
+static ALLOCATOR: FrameBackedAllocator =
+    FrameBackedAllocator::new(HEAP_START, HEAP_END);
 
 //==============================================================================
 // Macros
@@ -45,6 +79,31 @@ macro_rules! test {
     }};
 }
 
+///
+/// **Description**
+///
+/// Fails to build if a type implements [`Clone`].
+///
+/// **Notes**
+///
+/// - If the type implements [`Clone`], the item of a helper trait is
+///   implemented twice for it, and the reference to it is ambiguous.
+///
+#[macro_export]
+macro_rules! assert_not_clone {
+    ($ty:ty) => {
+        const _: fn() = || {
+            trait AmbiguousIfClone<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfClone<()> for T {}
+            struct Invalid;
+            impl<T: ?Sized + Clone> AmbiguousIfClone<Invalid> for T {}
+            let _ = <$ty as AmbiguousIfClone<_>>::some_item;
+        };
+    };
+}
+
 //==============================================================================
 // Standalone Functions
 //==============================================================================
@@ -53,9 +112,20 @@ macro_rules! test {
 pub fn main() {
     nanvix::log!("Running test server...");
     kcall::test();
+    arch::test();
+    io::test();
+    panic::test();
     pm::test();
     mm::test();
     misc::test();
+    ffi::test();
+    fmt::test();
+    iam::test();
     ipc::test();
+    sync::test();
+    thread::test();
+    time::test();
+    excp::test();
+    prelude::test();
     power::shutdown();
 }
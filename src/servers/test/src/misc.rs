@@ -7,7 +7,10 @@
 // Imports
 //==============================================================================
 
-use nanvix::misc;
+use nanvix::{
+    misc,
+    pm,
+};
 
 //==============================================================================
 // Private Standalone Functions
@@ -53,6 +56,25 @@ fn get_invalid_kmod_info() -> bool {
     true
 }
 
+/// Checks if enumerating kernel modules yields the same modules twice.
+fn enumerate_kmods() -> bool {
+    let count: usize = pm::kmods().count();
+
+    // There is at least the init server.
+    if count == 0 {
+        nanvix::log!("no kernel modules were enumerated");
+        return false;
+    }
+
+    if pm::kmods().count() != count {
+        nanvix::log!("kernel module count is not stable");
+        return false;
+    }
+
+    // Kernel modules were reported by the kernel.
+    pm::kmods().all(|kmod| unsafe { kmod.image() }.len() == kmod.size())
+}
+
 //==============================================================================
 // Public Standalone Functions
 //==============================================================================
@@ -66,4 +88,5 @@ pub fn test() {
     crate::test!(check_sizes());
     crate::test!(get_kmod_info());
     crate::test!(get_invalid_kmod_info());
+    crate::test!(enumerate_kmods());
 }
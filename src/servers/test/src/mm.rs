@@ -7,18 +7,73 @@
 // Imports
 //==============================================================================
 
+use alloc::{
+    boxed::Box,
+    string::String,
+    vec::Vec,
+};
+use core::alloc::{
+    GlobalAlloc,
+    Layout,
+};
 use nanvix::{
+    kcall::{
+        KcallError,
+        KcallNumbers,
+    },
     memory::{
         self,
+        Frame,
+        FrameBackedAllocator,
         FrameNumber,
+        FrameRange,
+        Mapping,
+        PageFlags,
         PageInfo,
+        PageSize,
+        PhysAddr,
+        VirtAddr,
         VirtualAddress,
         VirtualMemory,
         VmCtrlRequest,
+        Vmem,
+        VmemBuilder,
+        VmemCommand,
+        VmemInfo,
+    },
+    pm::{
+        self,
+        Pid,
     },
     security::AccessMode,
 };
 
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Number of page frames allocated in stress tests.
+const NFRAMES: usize = 1024;
+
+/// Number of elements pushed in allocation tests.
+const NELEMENTS: usize = 2048;
+
+/// Address where page frames are mapped to inspect their contents.
+const INSPECT_ADDRESS: VirtualAddress = 0x06100000;
+
+/// Base address of virtual memory spaces built in tests.
+const BUILDER_BASE: VirtualAddress = 0x05000000;
+
+/// Byte that is left behind in released page frames.
+const STALE_BYTE: u8 = 0xa5;
+
+//==============================================================================
+// Compile-Time Checks
+//==============================================================================
+
+// Handles to kernel objects must be duplicated with `try_clone()`.
+crate::assert_not_clone!(Vmem);
+
 //==============================================================================
 // Private Standalone Functions
 //==============================================================================
@@ -143,6 +198,232 @@ fn double_free_frame() -> bool {
     true
 }
 
+/// Allocates and releases many page frames through the RAII wrapper.
+fn alloc_drop_frames() -> bool {
+    for _ in 0..NFRAMES {
+        let frame: Frame = match Frame::alloc() {
+            Ok(frame) => frame,
+            Err(_) => {
+                nanvix::log!("failed to allocate a page frame");
+                return false;
+            },
+        };
+
+        // Check if the page frame lies on a valid range.
+        if frame.addr().as_raw() < memory::USER_BASE_ADDRESS {
+            nanvix::log!("succeded to allocate an invalid page frame");
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Checks if a page frame is released when it is dropped.
+fn drop_frame() -> bool {
+    let frame: FrameNumber = match Frame::alloc() {
+        Ok(frame) => frame.number(),
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+
+    // Check if we succeeded to release the dropped page frame again.
+    if memory::frfree(frame) == 0 {
+        nanvix::log!("page frame was not released on drop");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if a leaked page frame is not released.
+fn leak_frame() -> bool {
+    let frame: FrameNumber = match Frame::alloc() {
+        Ok(frame) => frame.leak(),
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+
+    // Check if we failed to release the leaked page frame.
+    if memory::frfree(frame) != 0 {
+        nanvix::log!("leaked page frame was released");
+        return false;
+    }
+
+    true
+}
+
+/// Maps a page frame into the calling process and runs a closure on its
+/// contents.
+fn with_page<F: FnOnce(&mut [u8]) -> bool>(frame: &Frame, f: F) -> bool {
+    let vmem: VirtualMemory = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.vmem,
+        Err(_) => {
+            nanvix::log!("failed to get information on process");
+            return false;
+        },
+    };
+
+    if memory::vmmap(vmem, INSPECT_ADDRESS, frame.number()) != 0 {
+        nanvix::log!("failed to map page frame");
+        return false;
+    }
+
+    let mode: AccessMode = AccessMode::new(true, true, false);
+    let request: VmCtrlRequest =
+        VmCtrlRequest::ChangePermissions(INSPECT_ADDRESS, mode);
+    let result: bool = memory::vmctrl(vmem, request) == 0 && {
+        let page: &mut [u8] = unsafe {
+            core::slice::from_raw_parts_mut(
+                INSPECT_ADDRESS as *mut u8,
+                memory::PAGE_SIZE as usize,
+            )
+        };
+        f(page)
+    };
+
+    memory::vmunmap(vmem, INSPECT_ADDRESS);
+    result
+}
+
+/// Checks if a zeroed page frame holds no stale data.
+fn alloc_zeroed_frame() -> bool {
+    // Leave stale data behind in a released page frame.
+    let stale: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let written: bool = with_page(&stale, |page| {
+        page.fill(STALE_BYTE);
+        true
+    });
+    if !written {
+        nanvix::log!("failed to write to page frame");
+        return false;
+    }
+    let number: FrameNumber = stale.number();
+    drop(stale);
+
+    let frame: Frame = match Frame::alloc_zeroed() {
+        Ok(frame) => frame,
+        Err(e) => {
+            nanvix::log!("failed to allocate a zeroed page frame ({:?})", e);
+            return false;
+        },
+    };
+    if frame.number() != number {
+        nanvix::log!("released page frame was not reused, checking zeros only");
+    }
+
+    with_page(&frame, |page| page.iter().all(|byte| *byte == 0))
+}
+
+/// Allocates contiguous page frames that are aligned.
+fn alloc_contiguous_frames() -> bool {
+    const COUNT: usize = 4;
+    const ALIGN: usize = 16 * 1024;
+
+    let range: FrameRange = match FrameRange::alloc_contiguous(COUNT, ALIGN) {
+        Ok(range) => range,
+        Err(_) => {
+            nanvix::log!("failed to allocate contiguous page frames");
+            return false;
+        },
+    };
+
+    if range.len() != COUNT || !range.addr().is_aligned(ALIGN as u32) {
+        nanvix::log!("unexpected range {:?}", range);
+        return false;
+    }
+
+    // Frames of the range should not be handed out again.
+    match Frame::alloc() {
+        Ok(frame) => {
+            let end: FrameNumber = range.start() + COUNT as FrameNumber;
+            if (range.start()..end).contains(&frame.number()) {
+                nanvix::log!("page frame allocated twice");
+                return false;
+            }
+        },
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    }
+
+    match Frame::alloc_aligned(ALIGN) {
+        Ok(frame) if frame.addr().is_aligned(ALIGN as u32) => {},
+        _ => {
+            nanvix::log!("failed to allocate an aligned page frame");
+            return false;
+        },
+    }
+
+    FrameRange::alloc_contiguous(COUNT, ALIGN + 1).err()
+        == Some(KcallError::InvalidArgument)
+}
+
+/// Checks alignment arithmetic of addresses at page boundaries.
+fn align_addresses() -> bool {
+    const PAGE: u32 = memory::PAGE_SIZE;
+
+    // Rounds (address, up, down) of virtual addresses around page boundaries.
+    let cases: [(u32, Option<u32>, u32); 6] = [
+        (0, Some(0), 0),
+        (1, Some(PAGE), 0),
+        (PAGE - 1, Some(PAGE), 0),
+        (PAGE, Some(PAGE), PAGE),
+        (PAGE + 1, Some(2 * PAGE), PAGE),
+        (u32::MAX, None, u32::MAX - (PAGE - 1)),
+    ];
+    for (addr, up, down) in cases {
+        let vaddr: VirtAddr = VirtAddr::new(addr);
+        if vaddr.align_up(PAGE).map(|a| a.as_raw()) != up
+            || vaddr.align_down(PAGE).as_raw() != down
+            || vaddr.is_aligned(PAGE) != (addr == down)
+        {
+            nanvix::log!("unexpected alignment of {}", vaddr);
+            return false;
+        }
+    }
+
+    // Physical addresses round the same way.
+    let paddr: PhysAddr = PhysAddr::new(3 * PAGE + 1);
+    if paddr.align_up(PAGE) != Some(PhysAddr::new(4 * PAGE))
+        || paddr.align_down(PAGE) != PhysAddr::new(3 * PAGE)
+        || paddr.page_number() != 3
+        || paddr.page_offset() != 1
+    {
+        nanvix::log!("unexpected alignment of {}", paddr);
+        return false;
+    }
+    if PhysAddr::from_frame(3) != Some(PhysAddr::new(3 * PAGE))
+        || PhysAddr::from_frame(u32::MAX).is_some()
+    {
+        nanvix::log!("unexpected address of page frame");
+        return false;
+    }
+
+    // Offsets stop at the end of the address space.
+    let last: VirtAddr = VirtAddr::new(u32::MAX - (PAGE - 1));
+    if last.offset(PAGE - 1) != Some(VirtAddr::new(u32::MAX))
+        || last.offset(PAGE).is_some()
+        || last.page_number() != u32::MAX / PAGE
+    {
+        nanvix::log!("unexpected offset of {}", last);
+        return false;
+    }
+
+    true
+}
+
 /// Attempts to create and release a virtual memory space.
 fn create_remove_vmem() -> bool {
     // Attempt to create a virtual memory space.
@@ -239,6 +520,809 @@ fn map_unmap_vmem() -> bool {
     true
 }
 
+/// Maps and unmaps a page frame through the virtual memory space wrapper.
+fn map_unmap_vmem_handle() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    // Attempt to map the page frame.
+    let flags: PageFlags = PageFlags::READ | PageFlags::WRITE | PageFlags::USER;
+    let mapping: Mapping = match vmem.map(vaddr, &frame, flags) {
+        Ok(mapping) => mapping,
+        Err(_) => {
+            nanvix::log!(
+                "failed to map a page frame to a virtual memory space"
+            );
+            return false;
+        },
+    };
+
+    // Check information on the virtual memory space.
+    let info: VmemInfo = vmem.info();
+    if info.resident_pages != 1 || vaddr < info.start || vaddr >= info.end {
+        nanvix::log!("unexpected virtual memory space info {:?}", info);
+        return false;
+    }
+
+    // Check information on the mapped page.
+    match vmem.page_info(vaddr) {
+        Ok(page) if page.frame == frame.number() && page.mode.write() => {},
+        _ => {
+            nanvix::log!("unexpected page info");
+            return false;
+        },
+    }
+
+    // Attempt to unmap the page frame.
+    if mapping.unmap() != Ok(frame.number()) {
+        nanvix::log!(
+            "failed to unmap a page frame from a virtual memory space"
+        );
+        return false;
+    }
+
+    if vmem.info().resident_pages != 0 {
+        nanvix::log!("unexpected number of resident pages");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to map a page frame at an unaligned virtual address.
+fn map_unaligned_vmem_handle() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS + 1;
+    if vmem.map(vaddr, &frame, PageFlags::READ).err()
+        != Some(KcallError::InvalidArgument)
+    {
+        nanvix::log!("succeeded to map a page frame at an unaligned address");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to build virtual memory spaces with invalid regions.
+fn vmem_builder_invalid() -> bool {
+    let page: usize = memory::PAGE_SIZE as usize;
+    let builders: [VmemBuilder; 5] = [
+        VmemBuilder::new().size(page + 1),
+        VmemBuilder::new().size(0),
+        VmemBuilder::new().base(BUILDER_BASE + 1),
+        VmemBuilder::new().base(memory::KERNEL_BASE_ADDRESS),
+        VmemBuilder::new()
+            .base(memory::USER_END_ADDRESS - memory::PAGE_SIZE)
+            .size(2 * page),
+    ];
+
+    for builder in builders.iter() {
+        if builder.create().err() != Some(KcallError::InvalidArgument) {
+            nanvix::log!("succeeded to build {:?}", builder);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Builds a virtual memory space with a specific base address.
+fn vmem_builder_base() -> bool {
+    let size: usize = 2 * memory::PAGE_SIZE as usize;
+    let vmem: Vmem = match VmemBuilder::new()
+        .base(BUILDER_BASE)
+        .size(size)
+        .flags(PageFlags::READ | PageFlags::WRITE)
+        .create()
+    {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to build a virtual memory space");
+            return false;
+        },
+    };
+    let info: VmemInfo = vmem.info();
+    if info.start != BUILDER_BASE || info.end != BUILDER_BASE + size as u32 {
+        nanvix::log!("unexpected region {:#x}..{:#x}", info.start, info.end);
+        return false;
+    }
+
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+
+    // Pages outside of the region and permissions outside of the set fail.
+    let below: VirtualAddress = BUILDER_BASE - memory::PAGE_SIZE;
+    if vmem.map(below, &frame, PageFlags::READ).err()
+        != Some(KcallError::InvalidArgument)
+    {
+        nanvix::log!("succeeded to map a page below the region");
+        return false;
+    }
+    if vmem.map(BUILDER_BASE, &frame, PageFlags::EXEC).err()
+        != Some(KcallError::AccessDenied)
+    {
+        nanvix::log!("succeeded to map an executable page");
+        return false;
+    }
+
+    let mapping: Mapping =
+        match vmem.map(BUILDER_BASE, &frame, PageFlags::WRITE) {
+            Ok(mapping) => mapping,
+            Err(_) => {
+                nanvix::log!("failed to map a page at the base address");
+                return false;
+            },
+        };
+
+    mapping.vaddr() == BUILDER_BASE
+}
+
+/// Checks if a failed map reports the kernel call that failed.
+fn map_failure_context() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    let _mapping: Mapping = match vmem.map(vaddr, &frame, PageFlags::READ) {
+        Ok(mapping) => mapping,
+        Err(_) => {
+            nanvix::log!("failed to map a page frame");
+            return false;
+        },
+    };
+
+    // Attempt to map the page again.
+    match vmem.map_with_context(vaddr, &frame, PageFlags::READ) {
+        Err(e) if e.kcall() == KcallNumbers::VmemMap => {
+            if KcallError::from(e) != e.source() {
+                nanvix::log!("unexpected raw error");
+                return false;
+            }
+        },
+        Err(e) => {
+            nanvix::log!("unexpected kernel call in error ({})", e);
+            return false;
+        },
+        Ok(_) => {
+            nanvix::log!("succeeded to map a page twice");
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Writes a pattern to a mapping and reads it back through its byte view.
+fn mapping_bytes() -> bool {
+    let vmem: Vmem = match Vmem::current() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to get the virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+
+    let flags: PageFlags = PageFlags::READ | PageFlags::WRITE;
+    let mut mapping: Mapping = match vmem.map(INSPECT_ADDRESS, &frame, flags) {
+        Ok(mapping) => mapping,
+        Err(_) => {
+            nanvix::log!("failed to map a page frame");
+            return false;
+        },
+    };
+
+    // Write a pattern.
+    match mapping.bytes_mut() {
+        Ok(bytes) if bytes.len() == memory::PAGE_SIZE as usize => {
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = i as u8;
+            }
+        },
+        _ => {
+            nanvix::log!("failed to write to the mapping");
+            return false;
+        },
+    }
+
+    // Read the pattern back.
+    match mapping.bytes() {
+        Ok(bytes) if bytes.iter().enumerate().all(|(i, b)| *b == i as u8) => {},
+        _ => {
+            nanvix::log!("unexpected contents of the mapping");
+            return false;
+        },
+    }
+
+    // Check bounds.
+    let len: usize = mapping.len();
+    if mapping.get(len - 1) != Some(&((len - 1) as u8))
+        || mapping.get(len).is_some()
+        || mapping.get(len - 1..len + 1).is_some()
+    {
+        nanvix::log!("unexpected bounds of the mapping");
+        return false;
+    }
+    match mapping.get_mut(1..3) {
+        Some(bytes) => bytes.copy_from_slice(&[0xff, 0xfe]),
+        None => {
+            nanvix::log!("failed to get a range of the mapping");
+            return false;
+        },
+    }
+    if mapping.get(..4) != Some(&[0, 0xff, 0xfe, 3][..]) {
+        nanvix::log!("unexpected contents of the mapping");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to access a mapping in another virtual memory space.
+fn mapping_bytes_foreign() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+
+    let flags: PageFlags = PageFlags::READ | PageFlags::WRITE;
+    let mapping: Mapping = match vmem.map(INSPECT_ADDRESS, &frame, flags) {
+        Ok(mapping) => mapping,
+        Err(_) => {
+            nanvix::log!("failed to map a page frame");
+            return false;
+        },
+    };
+
+    if mapping.bytes().err() != Some(KcallError::BadAddress)
+        || mapping.get(0).is_some()
+    {
+        nanvix::log!("succeeded to access a mapping in another address space");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if dropping a mapping unmaps the page exactly once.
+fn drop_mapping() -> bool {
+    let mut vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    match vmem.map(vaddr, &frame, PageFlags::READ) {
+        Ok(mapping) => {
+            if mapping.vaddr() != vaddr
+                || mapping.len() != memory::PAGE_SIZE as usize
+            {
+                nanvix::log!("unexpected mapping bounds");
+                return false;
+            }
+            drop(mapping);
+        },
+        Err(_) => {
+            nanvix::log!("failed to map a page frame");
+            return false;
+        },
+    }
+
+    if vmem.info().resident_pages != 0 {
+        nanvix::log!("unexpected number of resident pages");
+        return false;
+    }
+
+    // The page should no longer be mapped.
+    if vmem.unmap(vaddr).is_ok() {
+        nanvix::log!("page was still mapped after dropping its mapping");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if information on a virtual memory space tracks mapped pages.
+fn vmem_info_summary() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let (data, code): (Frame, Frame) = match (Frame::alloc(), Frame::alloc()) {
+        (Ok(data), Ok(code)) => (data, code),
+        _ => {
+            nanvix::log!("failed to allocate page frames");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    let before: VmemInfo = vmem.info();
+    let data_mapping: Mapping =
+        match vmem.map(vaddr, &data, PageFlags::READ | PageFlags::WRITE) {
+            Ok(mapping) => mapping,
+            Err(_) => {
+                nanvix::log!("failed to map data page");
+                return false;
+            },
+        };
+    let code_mapping: Mapping = match vmem.map(
+        vaddr + memory::PAGE_SIZE,
+        &code,
+        PageFlags::READ | PageFlags::EXEC,
+    ) {
+        Ok(mapping) => mapping,
+        Err(_) => {
+            nanvix::log!("failed to map code page");
+            return false;
+        },
+    };
+
+    let info: VmemInfo = vmem.info();
+    if info.resident_pages != before.resident_pages + 2
+        || !info
+            .flags
+            .contains(PageFlags::READ | PageFlags::WRITE | PageFlags::EXEC)
+    {
+        nanvix::log!("unexpected virtual memory space info {:?}", info);
+        return false;
+    }
+
+    // Permissions of unmapped pages should no longer be accounted for.
+    drop(code_mapping);
+    let info: VmemInfo = vmem.info();
+    if info.resident_pages != before.resident_pages + 1
+        || info.flags.contains(PageFlags::EXEC)
+        || !info.flags.contains(PageFlags::WRITE)
+    {
+        nanvix::log!("unexpected virtual memory space info {:?}", info);
+        return false;
+    }
+
+    drop(data_mapping);
+
+    vmem.info() == before
+}
+
+/// Checks the encoding of virtual memory control commands.
+fn vmem_command_encoding() -> bool {
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+    let cases: [(PageFlags, AccessMode); 3] = [
+        (PageFlags::READ, AccessMode::new(true, false, false)),
+        (
+            PageFlags::READ | PageFlags::WRITE,
+            AccessMode::new(true, true, false),
+        ),
+        (
+            PageFlags::READ | PageFlags::EXEC,
+            AccessMode::new(true, false, true),
+        ),
+    ];
+
+    for (flags, mode) in cases {
+        let expected: (u32, VirtualAddress, u32) = (0, vaddr, mode.into());
+        let encoded: (u32, VirtualAddress, u32) =
+            VmemCommand::SetFlags(vaddr, flags).encode();
+        if encoded != expected {
+            nanvix::log!("unexpected encoding {:?} for {:?}", encoded, flags);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Attempts to change the access permissions of a mapped page.
+fn vmem_control_set_flags() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    let mapping: Mapping =
+        match vmem.map(vaddr, &frame, PageFlags::READ | PageFlags::WRITE) {
+            Ok(mapping) => mapping,
+            Err(_) => {
+                nanvix::log!("failed to map page");
+                return false;
+            },
+        };
+
+    if vmem
+        .control(VmemCommand::SetFlags(vaddr, PageFlags::READ))
+        .is_err()
+    {
+        nanvix::log!("failed to change access permissions");
+        return false;
+    }
+
+    match vmem.page_info(vaddr) {
+        Ok(info) if info.mode.read() && !info.mode.write() => {},
+        _ => {
+            nanvix::log!("access permissions were not changed");
+            return false;
+        },
+    }
+    if vmem.info().flags.contains(PageFlags::WRITE) {
+        nanvix::log!("stale access permissions in virtual memory space info");
+        return false;
+    }
+
+    // Addresses outside user memory should be rejected.
+    if vmem
+        .control(VmemCommand::SetFlags(0, PageFlags::READ))
+        .is_ok()
+    {
+        nanvix::log!("succeeded to control a page outside user memory");
+        return false;
+    }
+
+    drop(mapping);
+
+    true
+}
+
+/// Attempts to map page frames with pages of different sizes.
+fn map_page_sizes() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    if PageSize::Size2M.small_pages() != 512
+        || PageSize::Size4K.bytes() != memory::PAGE_SIZE as usize
+    {
+        nanvix::log!("unexpected page sizes");
+        return false;
+    }
+
+    // Huge pages should be rejected without mapping small ones.
+    match vmem.map_sized(vaddr, &frame, PageFlags::READ, PageSize::Size2M) {
+        Err(KcallError::NotSupported) => {},
+        _ => {
+            nanvix::log!("unexpected result of mapping a huge page");
+            return false;
+        },
+    }
+    if vmem.info().resident_pages != 0 || vmem.page_info(vaddr).is_ok() {
+        nanvix::log!("rejected huge page was mapped");
+        return false;
+    }
+
+    // Small pages should consume a single mapping.
+    let mapping: Mapping = match vmem.map_sized(
+        vaddr,
+        &frame,
+        PageFlags::READ,
+        PageSize::Size4K,
+    ) {
+        Ok(mapping) => mapping,
+        Err(_) => {
+            nanvix::log!("failed to map a small page");
+            return false;
+        },
+    };
+    if vmem.info().resident_pages != 1
+        || mapping.len() != PageSize::Size4K.bytes()
+    {
+        nanvix::log!("unexpected size of small page");
+        return false;
+    }
+    drop(mapping);
+
+    true
+}
+
+/// Maps fresh page frames into a virtual memory space.
+fn map_anonymous_pages() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+    let len: usize = 2 * memory::PAGE_SIZE as usize + 1;
+
+    let mapping: Mapping =
+        match memory::map_anonymous(&vmem, vaddr, len, PageFlags::READ) {
+            Ok(mapping) => mapping,
+            Err(_) => {
+                nanvix::log!("failed to map anonymous pages");
+                return false;
+            },
+        };
+    if mapping.len() != 3 * memory::PAGE_SIZE as usize
+        || vmem.info().resident_pages != 3
+    {
+        nanvix::log!("unexpected size of anonymous mapping");
+        return false;
+    }
+
+    drop(mapping);
+    if vmem.info().resident_pages != 0 || vmem.page_info(vaddr).is_ok() {
+        nanvix::log!("anonymous pages were not unmapped");
+        return false;
+    }
+
+    // Empty regions should be rejected.
+    if memory::map_anonymous(&vmem, vaddr, 0, PageFlags::READ).is_ok() {
+        nanvix::log!("succeeded to map an empty region");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if mapping fresh page frames is rolled back when a page fails.
+fn map_anonymous_rollback() -> bool {
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    // Map the third page beforehand, so that mapping it again fails.
+    let blocker: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let third: VirtualAddress = vaddr + 2 * memory::PAGE_SIZE;
+    let _blocked: Mapping = match vmem.map(third, &blocker, PageFlags::READ) {
+        Ok(mapping) => mapping,
+        Err(_) => {
+            nanvix::log!("failed to map third page");
+            return false;
+        },
+    };
+
+    // Page frames are allocated first-fit, thus look up the ones that will be
+    // handed out next.
+    let expected: [FrameNumber; 3] =
+        match (Frame::alloc(), Frame::alloc(), Frame::alloc()) {
+            (Ok(a), Ok(b), Ok(c)) => [a.number(), b.number(), c.number()],
+            _ => {
+                nanvix::log!("failed to allocate page frames");
+                return false;
+            },
+        };
+
+    let len: usize = 3 * memory::PAGE_SIZE as usize;
+    if memory::map_anonymous(&vmem, vaddr, len, PageFlags::READ).is_ok() {
+        nanvix::log!("succeeded to map over a mapped page");
+        return false;
+    }
+
+    // Only the third page should be left mapped.
+    if vmem.info().resident_pages != 1
+        || vmem.page_info(vaddr).is_ok()
+        || vmem.page_info(vaddr + memory::PAGE_SIZE).is_ok()
+    {
+        nanvix::log!("residual mappings after a failed anonymous mapping");
+        return false;
+    }
+
+    // All page frames should have been released.
+    match (Frame::alloc(), Frame::alloc(), Frame::alloc()) {
+        (Ok(a), Ok(b), Ok(c)) => {
+            if [a.number(), b.number(), c.number()] != expected {
+                nanvix::log!(
+                    "leaked page frames in a failed anonymous mapping"
+                );
+                return false;
+            }
+        },
+        _ => {
+            nanvix::log!("failed to allocate page frames");
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Checks if forgetting a mapping leaves the page mapped.
+fn forget_mapping() -> bool {
+    let mut vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    match vmem.map(vaddr, &frame, PageFlags::READ) {
+        Ok(mapping) => {
+            if mapping.forget() != vaddr {
+                nanvix::log!("unexpected address of forgotten mapping");
+                return false;
+            }
+        },
+        Err(_) => {
+            nanvix::log!("failed to map a page frame");
+            return false;
+        },
+    }
+
+    // The page should still be mapped, and it is unmapped once.
+    if vmem.unmap(vaddr) != Ok(frame.number()) {
+        nanvix::log!("page was not mapped after forgetting its mapping");
+        return false;
+    }
+    if vmem.unmap(vaddr).is_ok() {
+        nanvix::log!("succeeded to unmap a page twice");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if a virtual memory space that is in use is not removed.
+fn remove_vmem_in_use() -> bool {
+    let frame: Frame = match Frame::alloc() {
+        Ok(frame) => frame,
+        Err(_) => {
+            nanvix::log!("failed to allocate a page frame");
+            return false;
+        },
+    };
+    let vmem: Vmem = match Vmem::create() {
+        Ok(vmem) => vmem,
+        Err(_) => {
+            nanvix::log!("failed to create a virtual memory space");
+            return false;
+        },
+    };
+    let vaddr: VirtualAddress = memory::USER_BASE_ADDRESS;
+
+    match vmem.map(vaddr, &frame, PageFlags::READ) {
+        Ok(mapping) => {
+            mapping.forget();
+        },
+        Err(_) => {
+            nanvix::log!("failed to map a page frame");
+            return false;
+        },
+    }
+
+    // The forgotten page keeps the virtual memory space in use.
+    let mut vmem: Vmem = match vmem.remove() {
+        Ok(()) => {
+            nanvix::log!("removed a virtual memory space that is in use");
+            return false;
+        },
+        Err((vmem, KcallError::Again)) if vmem.is_in_use() => vmem,
+        Err((mut vmem, e)) => {
+            nanvix::log!("unexpected error {:?}", e);
+            let _ = vmem.unmap(vaddr);
+            return false;
+        },
+    };
+
+    if vmem.unmap(vaddr).is_err() || vmem.is_in_use() {
+        nanvix::log!("failed to unmap forgotten page");
+        return false;
+    }
+
+    match vmem.remove() {
+        Ok(()) => true,
+        Err((_, e)) => {
+            nanvix::log!("failed to remove virtual memory space: {:?}", e);
+            false
+        },
+    }
+}
+
 /// Attempts to change access permissions on page.
 fn change_page_permissions() -> bool {
     // Attempt to create a virtual memory space.
@@ -345,6 +1429,44 @@ fn change_page_permissions() -> bool {
     true
 }
 
+/// Allocates a boxed value.
+fn alloc_box() -> bool {
+    let boxed: Box<u32> = Box::new(0xdeadbeef);
+    *boxed == 0xdeadbeef
+}
+
+/// Grows a vector across several pages.
+fn alloc_vec() -> bool {
+    let mut vec: Vec<usize> = Vec::new();
+    for i in 0..NELEMENTS {
+        vec.push(i);
+    }
+
+    vec.iter().enumerate().all(|(i, x)| i == *x)
+}
+
+/// Builds a string.
+fn alloc_string() -> bool {
+    let mut string: String = String::from("hello");
+    string.push_str(", allocator");
+    string == "hello, allocator"
+}
+
+/// Checks if an exhausted allocator returns a null pointer.
+fn alloc_exhausted() -> bool {
+    let size: usize = memory::PAGE_SIZE as usize;
+    let start: VirtualAddress = 0x06100000;
+    let allocator: FrameBackedAllocator =
+        FrameBackedAllocator::new(start, start + memory::PAGE_SIZE);
+
+    let layout: Layout = match Layout::from_size_align(2 * size, 4) {
+        Ok(layout) => layout,
+        Err(_) => return false,
+    };
+
+    unsafe { allocator.alloc(layout).is_null() }
+}
+
 //==============================================================================
 // Public Standalone Functions
 //==============================================================================
@@ -360,8 +1482,34 @@ pub fn test() {
     crate::test!(free_null_frame());
     crate::test!(free_invalid_frame());
     crate::test!(double_free_frame());
+    crate::test!(alloc_drop_frames());
+    crate::test!(drop_frame());
+    crate::test!(leak_frame());
+    crate::test!(alloc_zeroed_frame());
+    crate::test!(alloc_contiguous_frames());
+    crate::test!(align_addresses());
     crate::test!(create_remove_vmem());
     crate::test!(remove_null_vmem());
     crate::test!(map_unmap_vmem());
     crate::test!(change_page_permissions());
+    crate::test!(map_unmap_vmem_handle());
+    crate::test!(map_unaligned_vmem_handle());
+    crate::test!(map_failure_context());
+    crate::test!(vmem_builder_invalid());
+    crate::test!(vmem_builder_base());
+    crate::test!(mapping_bytes());
+    crate::test!(mapping_bytes_foreign());
+    crate::test!(drop_mapping());
+    crate::test!(forget_mapping());
+    crate::test!(remove_vmem_in_use());
+    crate::test!(vmem_info_summary());
+    crate::test!(vmem_command_encoding());
+    crate::test!(vmem_control_set_flags());
+    crate::test!(map_page_sizes());
+    crate::test!(map_anonymous_pages());
+    crate::test!(map_anonymous_rollback());
+    crate::test!(alloc_box());
+    crate::test!(alloc_vec());
+    crate::test!(alloc_string());
+    crate::test!(alloc_exhausted());
 }
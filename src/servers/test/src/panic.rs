@@ -0,0 +1,95 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::fmt::{
+    self,
+    Write,
+};
+use nanvix::panic;
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+/// A writer that stores digits of a number in a buffer.
+struct Digits {
+    /// Underlying buffer.
+    buf: [u8; 10],
+    /// Number of bytes written.
+    len: usize,
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl fmt::Write for Digits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let n: usize = s.len();
+        if self.len + n > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..self.len + n].copy_from_slice(s.as_bytes());
+        self.len += n;
+        Ok(())
+    }
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Checks if a panic report contains the file and line of the panic.
+fn report_location() -> bool {
+    let mut buf: [u8; panic::PANIC_REPORT_MAX] = [0; panic::PANIC_REPORT_MAX];
+    let line: u32 = line!();
+    let report: &str = panic::format_report(&mut buf, file!(), line, &"boom");
+
+    let mut digits: Digits = Digits {
+        buf: [0; 10],
+        len: 0,
+    };
+    if write!(&mut digits, "line={}", line).is_err() {
+        nanvix::log!("failed to format line number");
+        return false;
+    }
+    let line: &str =
+        core::str::from_utf8(&digits.buf[..digits.len]).unwrap_or("");
+
+    if !report.contains(file!()) || !report.contains(line) {
+        nanvix::log!("panic report lacks location");
+        return false;
+    }
+    if !report.contains("boom") {
+        nanvix::log!("panic report lacks message");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if a long panic report is truncated.
+fn report_truncated() -> bool {
+    let mut buf: [u8; panic::PANIC_REPORT_MAX] = [0; panic::PANIC_REPORT_MAX];
+    let msg: [u8; panic::PANIC_REPORT_MAX] = [b'x'; panic::PANIC_REPORT_MAX];
+    let msg: &str = core::str::from_utf8(&msg).unwrap_or("");
+
+    let report: &str = panic::format_report(&mut buf, file!(), line!(), &msg);
+
+    report.len() == panic::PANIC_REPORT_MAX
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+pub fn test() {
+    crate::test!(report_location());
+    crate::test!(report_truncated());
+}
@@ -9,11 +9,47 @@
 
 use core::ffi;
 
-use nanvix::pm::{
-    self,
-    Pid,
-    ProcessInfo,
-    Tid,
+use nanvix::{
+    excp::{
+        self,
+        ExceptionInfo,
+        ExceptionKind,
+    },
+    iam::cred::{
+        self,
+        Uid,
+    },
+    io::{
+        self,
+        Fd,
+    },
+    ipc::{
+        self,
+        Receiver,
+        Sender,
+    },
+    kcall::{
+        KcallError,
+        KcallNumbers,
+    },
+    misc::KernelModule,
+    pm::{
+        self,
+        ArgBlock,
+        ExitCode,
+        ExitStatus,
+        Pid,
+        ProcessBuilder,
+        ProcessInfo,
+        ShutdownReason,
+        SpawnBuilder,
+        SpawnChannel,
+        Tid,
+    },
+    thread::{
+        self,
+        JoinHandle,
+    },
 };
 
 //==============================================================================
@@ -23,6 +59,53 @@ use nanvix::pm::{
 const THREAD_ARG_VAL: u32 = 0xdab;
 const THREAD_RET_VAL: u32 = 0x86;
 
+/// Unmapped address that threads read to fault in tests.
+const FAULT_ADDRESS: u32 = 0x06000000;
+
+/// Tag of the spawn channel used in tests.
+const SPAWN_CHANNEL_TAG: u32 = 110;
+
+/// Message sent through the spawn channel used in tests.
+const SPAWN_CHANNEL_MESSAGE: &[u8] = b"hello, child";
+
+/// Tag of the mailbox through which environment variables are read back.
+const PROCESS_ENV_TAG: u32 = 112;
+
+/// Tag of the mailbox inherited by processes built in tests.
+const PROCESS_MAILBOX_TAG: u32 = 113;
+
+/// Tag of the mailbox of the daemon spawned in tests.
+const DAEMON_TAG: u32 = 114;
+
+/// Tag of the mailbox through which the daemon spawned in tests replies.
+const DAEMON_REPLY_TAG: u32 = 115;
+
+/// Message that the daemon spawned in tests echoes.
+const DAEMON_MESSAGE: &[u8] = b"hello, daemon";
+
+/// Name of the trivial module that is spawned in tests.
+const HELLO_MODULE: &str = "hello";
+
+/// Tag of the channel through which the trivial module receives the PID of
+/// its spawner. Must match the one in the hello server.
+const HELLO_TAG: u32 = 116;
+
+/// Tag of the channel through which the trivial module replies. Must match the
+/// one in the hello server.
+const HELLO_REPLY_TAG: u32 = 117;
+
+/// Message that the trivial module replies with.
+const HELLO_MESSAGE: &[u8] = b"hello";
+
+/// Number of times that the channel of the trivial module is looked up.
+const HELLO_OPEN_RETRIES: usize = 1024;
+
+/// Image of processes built in tests.
+const PROCESS_IMAGE: &[u8] = &[0];
+
+/// Environment variables of processes built in tests.
+const PROCESS_ENV: [(&str, &str); 2] = [("HOME", "/root"), ("TERM", "vt100")];
+
 //==============================================================================
 // Private Standalone Functions
 //==============================================================================
@@ -45,7 +128,7 @@ fn check_sizes() -> bool {
 fn get_process_info() -> bool {
     // Attempt to get information on the calling process.
     let mut pinfo: ProcessInfo = ProcessInfo::default();
-    let result: i32 = pm::pinfo(pm::PID_SELF, &mut pinfo);
+    let result: i32 = pm::pinfo(Pid::SELF, &mut pinfo);
 
     // Check if we failed to get information on the calling process.
     if result != 0 {
@@ -54,7 +137,7 @@ fn get_process_info() -> bool {
     }
 
     // Assert process information.
-    if pinfo.pid == 1 {
+    if pinfo.pid == Pid::new(1) {
         nanvix::log!("unexpected PID");
         return false;
     }
@@ -70,11 +153,79 @@ fn get_process_info() -> bool {
     true
 }
 
+/// Gets information on the calling process through the checked interface.
+fn process_info_self() -> bool {
+    let info: ProcessInfo = match pm::process_info(Pid::SELF) {
+        Ok(info) => info,
+        Err(_) => {
+            nanvix::log!("failed to get information on the calling process");
+            return false;
+        },
+    };
+
+    // Check if the information matches the one of the raw interface.
+    let mut pinfo: ProcessInfo = ProcessInfo::default();
+    if pm::pinfo(Pid::SELF, &mut pinfo) != 0 {
+        nanvix::log!("failed to get information on the calling process");
+        return false;
+    }
+    if info.pid.is_self() || info.pid != pinfo.pid || info.tid != pinfo.tid {
+        nanvix::log!("unexpected process information");
+        return false;
+    }
+
+    // Query the calling process by its PID.
+    match pm::process_info(info.pid) {
+        Ok(by_pid) if by_pid.pid == info.pid && by_pid.vmem == info.vmem => {},
+        _ => {
+            nanvix::log!("failed to get information on the calling process");
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Scans the process table for the calling process.
+fn processes_include_self() -> bool {
+    let pid: Pid = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.pid,
+        Err(_) => {
+            nanvix::log!("failed to get information on the calling process");
+            return false;
+        },
+    };
+
+    // Check if the scan finds the calling process exactly once.
+    let count: usize = pm::processes().filter(|info| info.pid == pid).count();
+    if count != 1 {
+        nanvix::log!(
+            "unexpected number of matches in the process table (count={})",
+            count
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to get information on an invalid process through the checked
+/// interface.
+fn process_info_invalid_pid() -> bool {
+    match pm::process_info(Pid::new(i32::MAX)) {
+        Err(KcallError::NotFound) => true,
+        _ => {
+            nanvix::log!("succeded to get information on an invalid process");
+            false
+        },
+    }
+}
+
 /// Attempts to get information on an invalid process.
 fn get_process_info_invalid_pid() -> bool {
     // Attempt to get information on an invalid process.
     let mut pinfo: ProcessInfo = ProcessInfo::default();
-    let result: i32 = pm::pinfo(i32::MAX, &mut pinfo);
+    let result: i32 = pm::pinfo(Pid::new(i32::MAX), &mut pinfo);
 
     // Check if we have succeeded.
     if result == 0 {
@@ -89,7 +240,7 @@ fn get_process_info_invalid_pid() -> bool {
 /// Attempts to get information on an invalid process, but with an invalid buffer.
 fn get_process_info_invalid_buf() -> bool {
     // Attempt to get information on an valid process, but with an invalid buffer.
-    let result: i32 = pm::pinfo(pm::PID_SELF, core::ptr::null_mut());
+    let result: i32 = pm::pinfo(Pid::SELF, core::ptr::null_mut());
 
     // Check if we have succeeded.
     if result == 0 {
@@ -108,7 +259,7 @@ fn get_process_info_invalid_buf() -> bool {
 fn get_process_info_bad_buf() -> bool {
     // Attempt to get information on an valid process, but with a bad buffer.
     let pinfo: *mut ProcessInfo = 0x02000000 as *mut ProcessInfo;
-    let result: i32 = pm::pinfo(pm::PID_SELF, pinfo);
+    let result: i32 = pm::pinfo(Pid::SELF, pinfo);
 
     // Check if we have succeeded.
     if result == 0 {
@@ -122,6 +273,734 @@ fn get_process_info_bad_buf() -> bool {
     true
 }
 
+/// Checks if the special PID refers to the calling process.
+fn check_pid_self() -> bool {
+    if !Pid::SELF.is_self() {
+        nanvix::log!("special PID does not refer to the calling process");
+        return false;
+    }
+    if Pid::new(1).is_self() {
+        nanvix::log!("PID 1 refers to the calling process");
+        return false;
+    }
+    if Pid::SELF.raw() != -1 {
+        nanvix::log!("unexpected raw value for the special PID");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if PIDs convert to and from raw values.
+fn check_pid_raw() -> bool {
+    let pid: Pid = Pid::new(3);
+
+    if pid.raw() != 3 {
+        nanvix::log!("unexpected raw value for PID");
+        return false;
+    }
+    if u32::from(pid) != 3 {
+        nanvix::log!("unexpected kernel call argument for PID");
+        return false;
+    }
+    if u32::from(Pid::SELF) != u32::MAX {
+        nanvix::log!("unexpected kernel call argument for the special PID");
+        return false;
+    }
+
+    true
+}
+
+/// Decodes the exit status of a thread that exited normally.
+fn decode_exit_status() -> bool {
+    let status: ExitStatus = ExitStatus::from_raw(7);
+    if status != ExitStatus::Exited(7) || status.success() {
+        nanvix::log!("unexpected exit status {:?}", status);
+        return false;
+    }
+
+    // Reserved bits should be ignored.
+    let status: ExitStatus = ExitStatus::from_raw(0xffff_0000);
+    if status != ExitStatus::Exited(0) || !status.success() {
+        nanvix::log!("unexpected exit status {:?}", status);
+        return false;
+    }
+
+    ExitStatus::Exited(255).raw() == 255
+}
+
+/// Decodes the exit status of a thread that was terminated by an exception.
+fn decode_faulted_status() -> bool {
+    let status: ExitStatus = ExitStatus::from_raw(pm::EXIT_STATUS_FAULTED | 14);
+    if status != ExitStatus::Faulted(ExceptionKind::PageFault) {
+        nanvix::log!("unexpected exit status {:?}", status);
+        return false;
+    }
+    if status.raw() != (pm::EXIT_STATUS_FAULTED | 14) || status.success() {
+        nanvix::log!("failed to encode exit status {:?}", status);
+        return false;
+    }
+
+    // Faults and exits with the same value should be told apart.
+    ExitStatus::from_raw(pm::EXIT_STATUS_FAULTED)
+        == ExitStatus::Faulted(ExceptionKind::DivideByZero)
+        && ExitStatus::from_raw(0) == ExitStatus::Exited(0)
+}
+
+/// Decodes the exit status that the kernel reports for a terminated thread.
+fn join_faulted_thread() -> bool {
+    if excp::control(ExceptionKind::PageFault, true).is_err() {
+        nanvix::log!("failed to handle page faults");
+        return false;
+    }
+
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+        unsafe { core::ptr::read_volatile(FAULT_ADDRESS as *const u32) };
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            let _ = excp::control(ExceptionKind::PageFault, false);
+            return false;
+        },
+    };
+
+    let info: ExceptionInfo = match excp::wait_uninterrupted() {
+        Ok(info) => info,
+        Err(_) => {
+            nanvix::log!("failed to wait for exception");
+            let _ = excp::control(ExceptionKind::PageFault, false);
+            return false;
+        },
+    };
+    let terminated: bool =
+        info.tid == handle.tid() && excp::terminate(&info).is_ok();
+    let _ = excp::control(ExceptionKind::PageFault, false);
+    if !terminated {
+        nanvix::log!("failed to terminate faulting thread {:?}", info);
+        return false;
+    }
+
+    match handle.join_status() {
+        Ok(ExitStatus::Faulted(ExceptionKind::PageFault)) => true,
+        result => {
+            nanvix::log!("unexpected exit status (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Attempts to spawn a process with an empty image.
+fn spawn_empty_image() -> bool {
+    if pm::spawn(&[], &[]) != Err(KcallError::InvalidArgument) {
+        nanvix::log!("succeeded to spawn a process with an empty image");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to spawn a process with too many arguments.
+fn spawn_too_many_args() -> bool {
+    let image: [u8; 1] = [0];
+    let args: [&str; pm::SPAWN_ARGS_MAX + 1] = ["arg"; pm::SPAWN_ARGS_MAX + 1];
+
+    if pm::spawn(&image, &args) != Err(KcallError::InvalidArgument) {
+        nanvix::log!("succeeded to spawn a process with too many arguments");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to spawn a process with arguments that the kernel would drop.
+fn spawn_args_unsupported() -> bool {
+    let image: [u8; 1] = [0];
+
+    if pm::spawn(&image, &["arg"]) != Err(KcallError::NotSupported) {
+        nanvix::log!("succeeded to spawn a process with dropped arguments");
+        return false;
+    }
+
+    true
+}
+
+/// Spawns a trivial module and joins it.
+fn spawn_module_join() -> bool {
+    let me: Pid = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.pid,
+        Err(_) => {
+            nanvix::log!("failed to get information on the calling process");
+            return false;
+        },
+    };
+    let kmod: KernelModule =
+        match pm::kmods().find(|k| k.name() == HELLO_MODULE) {
+            Some(kmod) => kmod,
+            None => {
+                nanvix::log!("failed to find module {}", HELLO_MODULE);
+                return false;
+            },
+        };
+    let rx: Receiver = match Receiver::create(HELLO_REPLY_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create receiving end for replies");
+            return false;
+        },
+    };
+
+    // The kernel module was reported by the kernel.
+    let pid: Pid = match pm::spawn(unsafe { kmod.image() }, &[]) {
+        Ok(pid) => pid,
+        Err(_) => {
+            nanvix::log!("failed to spawn module {}", HELLO_MODULE);
+            return false;
+        },
+    };
+
+    // Wait for the spawned process to create its channel.
+    let mut tx: Option<Sender> = None;
+    for _ in 0..HELLO_OPEN_RETRIES {
+        if let Ok(sender) = Sender::open(pid, HELLO_TAG) {
+            tx = Some(sender);
+            break;
+        }
+        thread::yield_now();
+    }
+    let tx: Sender = match tx {
+        Some(tx) => tx,
+        None => {
+            nanvix::log!("failed to open channel of spawned process");
+            return false;
+        },
+    };
+
+    // Processes never terminate, thus join on the reply of the spawned
+    // process instead.
+    if tx.send(&me.raw().to_le_bytes()).is_err() {
+        nanvix::log!("failed to send PID to spawned process");
+        return false;
+    }
+    let mut msg: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    let len: usize = match rx.recv(&mut msg) {
+        Ok(len) => len,
+        Err(_) => {
+            nanvix::log!("failed to receive reply of spawned process");
+            return false;
+        },
+    };
+
+    &msg[..len] == HELLO_MESSAGE
+}
+
+/// Checks credentials of a spawned process before refusing to override them.
+fn spawn_with_credentials() -> bool {
+    let uid: Uid = cred::get_uid();
+
+    // The kernel does not apply credentials, thus overriding them is refused.
+    if SpawnBuilder::new(&[]).uid(uid).spawn() != Err(KcallError::NotSupported)
+    {
+        nanvix::log!("succeeded to override the user ID of a process");
+        return false;
+    }
+
+    // Only a superuser may spawn a process on behalf of another user.
+    let superuser: bool = uid == Uid::new(0) || cred::get_euid() == Uid::new(0);
+    let expected: KcallError = if superuser {
+        KcallError::NotSupported
+    } else {
+        KcallError::PermissionDenied
+    };
+    let other: Uid = Uid::new(uid.raw() + 1);
+    if SpawnBuilder::new(&[]).uid(other).spawn() != Err(expected) {
+        nanvix::log!("unexpected result for spawning with user ID {}", other);
+        return false;
+    }
+
+    // Without overrides, the image is checked.
+    if SpawnBuilder::new(&[]).spawn() != Err(KcallError::InvalidArgument) {
+        nanvix::log!("succeeded to spawn a process with an empty image");
+        return false;
+    }
+
+    true
+}
+
+/// Encodes and decodes the argument that names a spawn channel.
+fn spawn_channel_arg() -> bool {
+    let channel: SpawnChannel =
+        match SpawnChannel::new(Pid::new(3), SPAWN_CHANNEL_TAG) {
+            Ok(channel) => channel,
+            Err(_) => {
+                nanvix::log!("failed to name spawn channel");
+                return false;
+            },
+        };
+
+    let mut buf: [u8; pm::SPAWN_CHANNEL_ARG_MAX] =
+        [0; pm::SPAWN_CHANNEL_ARG_MAX];
+    let arg: &str = channel.encode(&mut buf);
+    if arg != "nanvix.channel=3:110" {
+        nanvix::log!("unexpected spawn channel argument {}", arg);
+        return false;
+    }
+
+    if SpawnChannel::find(&["worker", arg]) != Some(channel) {
+        nanvix::log!("failed to find spawn channel argument");
+        return false;
+    }
+
+    SpawnChannel::parse("nanvix.channel=3").is_none()
+        && SpawnChannel::parse("nanvix.channel=3:65535").is_none()
+}
+
+/// Round-trips a message through a spawn channel with an echo thread.
+fn spawn_channel_echo_thread() -> bool {
+    let parent: Pid = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.pid,
+        Err(_) => {
+            nanvix::log!("failed to get information on the calling process");
+            return false;
+        },
+    };
+    let channel: SpawnChannel =
+        match SpawnChannel::new(parent, SPAWN_CHANNEL_TAG) {
+            Ok(channel) => channel,
+            Err(_) => {
+                nanvix::log!("failed to name spawn channel");
+                return false;
+            },
+        };
+
+    let rx: Receiver = match channel.listen() {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create receiving end of the parent");
+            return false;
+        },
+    };
+
+    // The kernel ignores spawn arguments, thus the worker is a thread of the
+    // calling process that finds its ends the way a child would. This checks
+    // the naming convention, not that two processes are connected.
+    let mut buf: [u8; pm::SPAWN_CHANNEL_ARG_MAX] =
+        [0; pm::SPAWN_CHANNEL_ARG_MAX];
+    let len: usize = channel.encode(&mut buf).len();
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+        let arg: &str = core::str::from_utf8(&buf[..len]).unwrap_or("");
+        let code: i32 = match SpawnChannel::find(&[arg]).map(|c| c.connect()) {
+            Some(Ok((tx, rx))) => {
+                let mut msg: [u8; ipc::CHANNEL_MESSAGE_MAX] =
+                    [0; ipc::CHANNEL_MESSAGE_MAX];
+                match rx.recv(&mut msg) {
+                    Ok(len) if tx.send(&msg[..len]).is_ok() => 0,
+                    _ => 1,
+                }
+            },
+            _ => 1,
+        };
+        thread::exit(code)
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn echo worker");
+            return false;
+        },
+    };
+
+    // Send the message before the worker may have connected.
+    let tx: Sender = match channel.accept(parent) {
+        Ok(tx) => tx,
+        Err(_) => {
+            nanvix::log!("failed to open sending end of the parent");
+            return false;
+        },
+    };
+    if tx.send(SPAWN_CHANNEL_MESSAGE).is_err() {
+        nanvix::log!("failed to send message to echo worker");
+        return false;
+    }
+
+    let mut msg: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    let len: usize = match rx.recv(&mut msg) {
+        Ok(len) => len,
+        Err(_) => {
+            nanvix::log!("failed to receive echoed message");
+            return false;
+        },
+    };
+
+    if handle.join() != Ok(0) {
+        nanvix::log!("echo worker failed");
+        return false;
+    }
+
+    &msg[..len] == SPAWN_CHANNEL_MESSAGE
+}
+
+/// Reaches a daemon thread through its mailbox after the thread that spawned it
+/// exits.
+fn daemon_thread_outlives_spawner() -> bool {
+    let pid: Pid = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.pid,
+        Err(_) => {
+            nanvix::log!("failed to get information on the calling process");
+            return false;
+        },
+    };
+    let rx: Receiver = match Receiver::create(DAEMON_REPLY_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create receiving end for replies");
+            return false;
+        },
+    };
+
+    // The kernel may not spawn processes with arguments, thus the daemon and
+    // its spawner are threads of the calling process. This checks that the
+    // daemon stays reachable after the spawner exits, not that a process is
+    // detached.
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+        let ret: Result<JoinHandle, KcallError> =
+            thread::spawn(move || thread::exit(serve_daemon(pid)));
+        thread::exit(if ret.is_ok() { 0 } else { 1 })
+    });
+    let spawner: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn spawner");
+            return false;
+        },
+    };
+    if spawner.join() != Ok(0) {
+        nanvix::log!("failed to spawn daemon");
+        return false;
+    }
+
+    // Wait for the daemon to create its mailbox.
+    let mut msg: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    if rx.recv(&mut msg).is_err() {
+        nanvix::log!("failed to receive readiness of daemon");
+        return false;
+    }
+
+    let tx: Sender = match Sender::open(pid, DAEMON_TAG) {
+        Ok(tx) => tx,
+        Err(_) => {
+            nanvix::log!("failed to open mailbox of daemon");
+            return false;
+        },
+    };
+    if tx.send(DAEMON_MESSAGE).is_err() {
+        nanvix::log!("failed to send message to daemon");
+        return false;
+    }
+    let len: usize = match rx.recv(&mut msg) {
+        Ok(len) => len,
+        Err(_) => {
+            nanvix::log!("failed to receive echoed message");
+            return false;
+        },
+    };
+    if &msg[..len] != DAEMON_MESSAGE {
+        nanvix::log!("unexpected message echoed by daemon");
+        return false;
+    }
+
+    // Writes to the standard output are discarded, but still succeed.
+    let buf: &[u8] = b"[test] discarded by daemon\n";
+    io::is_stdio_discarded() && io::write(Fd::STDOUT, buf) == Ok(buf.len())
+}
+
+/// Attempts to spawn a process with a channel and an invalid image.
+fn spawn_with_channel_invalid() -> bool {
+    if pm::spawn_with_channel(&[], &[]).is_ok() {
+        nanvix::log!("succeeded to spawn a process with an empty image");
+        return false;
+    }
+
+    let image: [u8; 1] = [0];
+    let args: [&str; pm::SPAWN_ARGS_MAX] = ["arg"; pm::SPAWN_ARGS_MAX];
+    match pm::spawn_with_channel(&image, &args) {
+        Err(KcallError::InvalidArgument) => true,
+        _ => {
+            nanvix::log!(
+                "succeeded to spawn a process with too many arguments"
+            );
+            false
+        },
+    }
+}
+
+/// Attempts to spawn a process with a channel while the kernel drops arguments.
+fn spawn_with_channel_unsupported() -> bool {
+    let image: [u8; 1] = [0];
+    if pm::spawn_with_channel(&image, &[]).err()
+        != Some(KcallError::NotSupported)
+    {
+        nanvix::log!("spawned a process that may not find its channel");
+        return false;
+    }
+
+    true
+}
+
+/// Builds a process with environment variables and reads them back.
+fn process_builder_env() -> bool {
+    let parent: Pid = match pm::process_info(Pid::SELF) {
+        Ok(info) => info.pid,
+        Err(_) => {
+            nanvix::log!("failed to get information on the calling process");
+            return false;
+        },
+    };
+    let rx: Receiver = match Receiver::create(PROCESS_ENV_TAG) {
+        Ok(rx) => rx,
+        Err(_) => {
+            nanvix::log!("failed to create receiving end of the parent");
+            return false;
+        },
+    };
+
+    let builder: ProcessBuilder = ProcessBuilder::new(PROCESS_IMAGE)
+        .arg("child")
+        .env(PROCESS_ENV[0].0, PROCESS_ENV[0].1)
+        .env(PROCESS_ENV[1].0, PROCESS_ENV[1].1)
+        .mailbox(PROCESS_MAILBOX_TAG);
+
+    // The worker stands in for a child process, and it reads the arguments
+    // that are handed over to it.
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+        let mut buf: [u8; pm::ARG_BLOCK_SIZE] = [0; pm::ARG_BLOCK_SIZE];
+        let mut argv: [&str; pm::SPAWN_ARGS_MAX] = [""; pm::SPAWN_ARGS_MAX];
+        let argc: usize = match builder.marshal(&mut buf, &mut argv) {
+            Ok(argc) => argc,
+            Err(_) => thread::exit(1),
+        };
+        let args: &[&str] = &argv[..argc];
+
+        let mailbox: Option<u32> = pm::inherited_mailboxes(args).next();
+        let mailboxes: usize = pm::inherited_mailboxes(args).count();
+        if mailbox != Some(PROCESS_MAILBOX_TAG) || mailboxes != 1 {
+            thread::exit(2);
+        }
+
+        let tx: Sender = match Sender::open(parent, PROCESS_ENV_TAG) {
+            Ok(tx) => tx,
+            Err(_) => thread::exit(3),
+        };
+        for (key, _) in PROCESS_ENV.iter() {
+            match pm::env_var(args, key) {
+                Some(value) if tx.send(value.as_bytes()).is_ok() => {},
+                _ => thread::exit(4),
+            }
+        }
+        thread::exit(0)
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn child worker");
+            return false;
+        },
+    };
+
+    for (key, value) in PROCESS_ENV.iter() {
+        let mut msg: [u8; ipc::CHANNEL_MESSAGE_MAX] =
+            [0; ipc::CHANNEL_MESSAGE_MAX];
+        match rx.recv(&mut msg) {
+            Ok(len) if &msg[..len] == value.as_bytes() => {},
+            _ => {
+                nanvix::log!(
+                    "failed to read back environment variable {}",
+                    key
+                );
+                return false;
+            },
+        }
+    }
+
+    match handle.join() {
+        Ok(0) => true,
+        result => {
+            nanvix::log!("child worker failed (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Attempts to build processes with invalid settings.
+fn process_builder_invalid() -> bool {
+    let image: [u8; 1] = [0];
+
+    if ProcessBuilder::new(&image).env("", "value").spawn().is_ok() {
+        nanvix::log!("succeeded to build a process with an empty key");
+        return false;
+    }
+    if ProcessBuilder::new(&image)
+        .env("A=B", "value")
+        .spawn()
+        .is_ok()
+    {
+        nanvix::log!("succeeded to build a process with an invalid key");
+        return false;
+    }
+
+    let args: [&str; pm::SPAWN_ARGS_MAX] = ["arg"; pm::SPAWN_ARGS_MAX];
+    match ProcessBuilder::new(&image)
+        .args(&args)
+        .env("KEY", "value")
+        .spawn()
+    {
+        Err(KcallError::InvalidArgument) => true,
+        _ => {
+            nanvix::log!(
+                "succeeded to build a process with too many arguments"
+            );
+            false
+        },
+    }
+}
+
+/// Attempts to build processes with settings that the kernel would drop.
+fn process_builder_unsupported() -> bool {
+    let image: [u8; 1] = [0];
+
+    if ProcessBuilder::new(&image)
+        .env("KEY", "value")
+        .spawn()
+        .err()
+        != Some(KcallError::NotSupported)
+    {
+        nanvix::log!("succeeded to build a process with a dropped environment");
+        return false;
+    }
+    if ProcessBuilder::new(&image)
+        .mailbox(PROCESS_MAILBOX_TAG)
+        .spawn()
+        .err()
+        != Some(KcallError::NotSupported)
+    {
+        nanvix::log!("succeeded to build a process with dropped mailboxes");
+        return false;
+    }
+
+    true
+}
+
+/// Reads the null-terminated string at an address.
+fn c_str_at(addr: u32) -> &'static [u8] {
+    let ptr: *const u8 = addr as *const u8;
+    let mut len: usize = 0;
+    while unsafe { *ptr.add(len) } != 0 {
+        len += 1;
+    }
+    unsafe { core::slice::from_raw_parts(ptr, len + 1) }
+}
+
+/// Checks if an argument block follows the C layout of `argv` and `envp`.
+fn arg_block_layout() -> bool {
+    let mut block: ArgBlock = ArgBlock::new();
+    if block
+        .arg("init")
+        .and_then(|block| block.arg("-v"))
+        .and_then(|block| block.env("HOME", "/"))
+        .is_err()
+    {
+        nanvix::log!("failed to build argument block");
+        return false;
+    }
+    if block.argc() != 2 || block.envc() != 1 {
+        nanvix::log!("unexpected number of entries");
+        return false;
+    }
+
+    let bytes: &[u8] = block.pack();
+    let table: &[u32] =
+        unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const u32, 5) };
+
+    // Check if pointers point into the block.
+    let base: u32 = bytes.as_ptr() as u32;
+    let end: u32 = base + bytes.len() as u32;
+    if [table[0], table[1], table[3]]
+        .iter()
+        .any(|ptr| *ptr < base || *ptr >= end)
+    {
+        nanvix::log!("pointer out of the argument block");
+        return false;
+    }
+
+    c_str_at(table[0]) == b"init\0"
+        && c_str_at(table[1]) == b"-v\0"
+        && table[2] == 0
+        && c_str_at(table[3]) == b"HOME=/\0"
+        && table[4] == 0
+}
+
+/// Checks if strings with interior null bytes are rejected.
+fn arg_block_interior_nul() -> bool {
+    let mut block: ArgBlock = ArgBlock::new();
+
+    block.arg("a\0b").is_err()
+        && block.env("KEY", "a\0b").is_err()
+        && block.env("KEY=", "value").is_err()
+        && block.argc() == 0
+        && block.envc() == 0
+}
+
+/// Checks if exit codes are encoded as expected for the shutdown kernel call.
+fn check_exit_code() -> bool {
+    if KcallNumbers::Shutdown as u32 != 6 {
+        nanvix::log!("unexpected number for Shutdown");
+        return false;
+    }
+    if u32::from(ExitCode::SUCCESS) != 0 || u32::from(ExitCode::FAILURE) != 1 {
+        nanvix::log!("unexpected value for common exit codes");
+        return false;
+    }
+    if ExitCode::from(42).raw() != 42 || ExitCode::new(42) != ExitCode::from(42)
+    {
+        nanvix::log!("failed to convert exit code");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if shutdown reasons round-trip through exit codes.
+fn shutdown_reason_round_trip() -> bool {
+    let reasons: [ShutdownReason; 5] = [
+        ShutdownReason::Normal,
+        ShutdownReason::Panic,
+        ShutdownReason::OutOfMemory,
+        ShutdownReason::UserRequested,
+        ShutdownReason::Custom(42),
+    ];
+
+    for reason in reasons.iter() {
+        if ShutdownReason::from_u32(reason.as_u32()) != *reason {
+            nanvix::log!("failed to round-trip shutdown reason {:?}", reason);
+            return false;
+        }
+    }
+
+    // A normal shutdown should be reported with a successful exit code.
+    if u32::from(ShutdownReason::Normal) != 0
+        || ExitCode::from(ShutdownReason::Normal) != ExitCode::SUCCESS
+        || ExitCode::from(ShutdownReason::Panic) != ExitCode::FAILURE
+    {
+        nanvix::log!("unexpected exit code for shutdown reason");
+        return false;
+    }
+
+    // Custom codes that collide with named reasons should decode to them.
+    ShutdownReason::Custom(0).as_u32() == 0
+        && ShutdownReason::from_u32(0) == ShutdownReason::Normal
+}
+
 fn test_thread_getid() -> bool {
     let result: Tid = pm::thread_getid();
     if result < 0 {
@@ -182,6 +1061,29 @@ fn test_thread_create() -> bool {
     true
 }
 
+/// Runs the daemon spawned in tests, which echoes one message.
+fn serve_daemon(pid: Pid) -> i32 {
+    io::discard_stdio();
+
+    let rx: Receiver = match Receiver::create(DAEMON_TAG) {
+        Ok(rx) => rx,
+        Err(_) => return 1,
+    };
+    let tx: Sender = match Sender::open(pid, DAEMON_REPLY_TAG) {
+        Ok(tx) => tx,
+        Err(_) => return 1,
+    };
+    if tx.send(b"ready").is_err() {
+        return 1;
+    }
+
+    let mut msg: [u8; ipc::CHANNEL_MESSAGE_MAX] = [0; ipc::CHANNEL_MESSAGE_MAX];
+    match rx.recv(&mut msg) {
+        Ok(len) if tx.send(&msg[..len]).is_ok() => 0,
+        _ => 1,
+    }
+}
+
 //==============================================================================
 // Public Standalone Functions
 //==============================================================================
@@ -193,10 +1095,35 @@ fn test_thread_create() -> bool {
 ///
 pub fn test() {
     crate::test!(check_sizes());
+    crate::test!(check_pid_self());
+    crate::test!(check_pid_raw());
+    crate::test!(decode_exit_status());
+    crate::test!(decode_faulted_status());
+    crate::test!(join_faulted_thread());
     crate::test!(get_process_info());
     crate::test!(get_process_info_invalid_pid());
+    crate::test!(process_info_self());
+    crate::test!(process_info_invalid_pid());
+    crate::test!(processes_include_self());
     crate::test!(get_process_info_invalid_buf());
     crate::test!(get_process_info_bad_buf());
+    crate::test!(spawn_empty_image());
+    crate::test!(spawn_too_many_args());
+    crate::test!(spawn_args_unsupported());
+    crate::test!(spawn_module_join());
+    crate::test!(spawn_with_credentials());
+    crate::test!(spawn_channel_arg());
+    crate::test!(spawn_channel_echo_thread());
+    crate::test!(daemon_thread_outlives_spawner());
+    crate::test!(spawn_with_channel_invalid());
+    crate::test!(spawn_with_channel_unsupported());
+    crate::test!(process_builder_env());
+    crate::test!(process_builder_invalid());
+    crate::test!(process_builder_unsupported());
+    crate::test!(arg_block_layout());
+    crate::test!(arg_block_interior_nul());
+    crate::test!(check_exit_code());
+    crate::test!(shutdown_reason_round_trip());
     crate::test!(test_thread_getid());
     crate::test!(test_thread_create());
 }
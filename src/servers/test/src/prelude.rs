@@ -0,0 +1,53 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use nanvix::prelude::*;
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Checks if common items are reachable through the prelude.
+fn use_prelude() -> bool {
+    let handle: JoinHandle =
+        match spawn(|| println!("[test] pid={}", Pid::SELF)) {
+            Ok(handle) => handle,
+            Err(_) => {
+                nanvix::log!("failed to spawn thread");
+                return false;
+            },
+        };
+    if handle.join().is_err() {
+        nanvix::log!("failed to join thread");
+        return false;
+    }
+
+    let result: Result<usize, KcallError> =
+        write(Fd::STDOUT, b"[test] writing through the prelude\n");
+    if result.is_err() {
+        nanvix::log!("failed to write to the standard output");
+        return false;
+    }
+
+    // Modules should be reachable as well.
+    thread::current() == pm::thread_getid()
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Tests the prelude.
+///
+pub fn test() {
+    crate::test!(use_prelude());
+}
@@ -0,0 +1,1116 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use core::sync::atomic::{
+    AtomicBool,
+    AtomicU32,
+    Ordering,
+};
+use nanvix::{
+    kcall::{
+        self,
+        KcallError,
+    },
+    memory::{
+        self,
+        FrameNumber,
+    },
+    pm::{
+        self,
+        ffi,
+        Pid,
+        Tid,
+    },
+    sync::{
+        Arc,
+        AtomicPid,
+        AtomicThreadId,
+        Barrier,
+        Condvar,
+        Lazy,
+        Mutex,
+        Once,
+        RwLock,
+        Semaphore,
+        SpinMutex,
+        SpinMutexGuard,
+        TicketMutex,
+        TicketMutexGuard,
+    },
+    thread::{
+        self,
+        JoinHandle,
+        ThreadId,
+    },
+    time::Ticks,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Base key for semaphores used in tests.
+const SEMAPHORE_KEY_BASE: u32 = 3000;
+
+/// Command to get the value of a semaphore.
+const SEMAPHORE_GETVALUE: u32 = 0;
+
+/// Number of increments performed by each thread.
+const MUTEX_INCREMENTS: u32 = 64;
+
+/// Number of items exchanged by producer and consumer.
+const CONDVAR_ITEMS: u32 = 16;
+
+/// Number of threads that synchronize on a barrier.
+const BARRIER_THREADS: u32 = 4;
+
+/// Number of rounds of barrier synchronization.
+const BARRIER_ROUNDS: usize = 3;
+
+/// Number of ticks waited on a semaphore that is never raised.
+const TIMEOUT_TICKS: Ticks = Ticks::from_raw(8);
+
+/// Number of threads that race to initialize a lazy value.
+const LAZY_THREADS: usize = 4;
+
+/// Value of the lazy value used in tests.
+const LAZY_MAGIC: u32 = 0xdeadbeef;
+
+/// Number of times that the initializer of the lazy value yields.
+const LAZY_YIELDS: u32 = 4;
+
+/// Number of reader threads used in reader-writer lock tests.
+const RWLOCK_READERS: usize = 3;
+
+/// Number of times that each thread acquires the reader-writer lock.
+const RWLOCK_ROUNDS: u32 = 8;
+
+/// Number of threads that contend for a spin mutex.
+const SPIN_THREADS: usize = 3;
+
+/// Number of increments that each thread does under a spin mutex.
+const SPIN_INCREMENTS: u32 = 16;
+
+/// Number of attempts to acquire a spin mutex before yielding the processor.
+const SPIN_BUDGET: u32 = 64;
+
+/// Number of threads that contend for a ticket mutex.
+const TICKET_THREADS: usize = 3;
+
+/// Number of times that each thread acquires a ticket mutex.
+const TICKET_ROUNDS: usize = 4;
+
+/// Number of acquisitions of a ticket mutex.
+const TICKET_TOTAL: usize = TICKET_THREADS * TICKET_ROUNDS;
+
+/// Number of threads that share an atomically reference-counted value.
+const ARC_THREADS: usize = 3;
+
+/// Value shared through an atomically reference-counted pointer.
+const ARC_MAGIC: u32 = 0xcafe;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+/// Number of times that the value shared through an `Arc` was dropped.
+static ARC_DROPS: AtomicU32 = AtomicU32::new(0);
+
+/// Number of times that the lazy value was initialized.
+static LAZY_INITS: AtomicU32 = AtomicU32::new(0);
+
+/// Asserted if a thread observed an uninitialized lazy value.
+static LAZY_FAILED: AtomicBool = AtomicBool::new(false);
+
+/// Lazy value that threads race to initialize.
+static LAZY_VALUE: Lazy<u32> = Lazy::new(init_lazy_value);
+
+/// Counter that is protected by a spin mutex.
+static SPIN_COUNTER: SpinMutex<u32> = SpinMutex::new(SPIN_BUDGET, 0);
+
+/// Asserted while a thread holds the spin mutex.
+static SPIN_HELD: AtomicBool = AtomicBool::new(false);
+
+/// Asserted if two threads held the spin mutex at the same time.
+static SPIN_FAILED: AtomicBool = AtomicBool::new(false);
+
+/// Tickets in the order that a ticket mutex served them, and their number.
+static TICKET_LOG: TicketMutex<([u32; TICKET_TOTAL], usize)> =
+    TicketMutex::new(([0; TICKET_TOTAL], 0));
+
+//==============================================================================
+// Structures
+//==============================================================================
+
+/// A single-slot buffer shared by a producer and a consumer.
+struct Slot {
+    /// Item in the slot.
+    item: Mutex<Option<u32>>,
+    /// Signaled whenever the slot changes.
+    changed: Condvar,
+}
+
+/// State shared by readers and writers of a reader-writer lock.
+struct Shared {
+    /// Pair of values that the writer keeps equal.
+    pair: RwLock<[u32; 2]>,
+    /// Number of readers that hold the lock.
+    readers: AtomicU32,
+    /// Number of writers that hold the lock.
+    writers: AtomicU32,
+    /// Asserted if exclusive access was violated.
+    failed: AtomicBool,
+}
+
+/// State shared by threads that progress in lockstep.
+struct Lockstep {
+    /// Barrier that is crossed at the end of each round.
+    barrier: Barrier,
+    /// Number of threads that have arrived at each round.
+    arrived: [AtomicU32; BARRIER_ROUNDS],
+    /// Asserted if a thread crossed a barrier too early.
+    failed: AtomicBool,
+}
+
+/// A value that counts how many times it is dropped.
+struct Tracked {
+    /// Payload of the value.
+    magic: u32,
+}
+
+//==============================================================================
+// Trait Implementations
+//==============================================================================
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        ARC_DROPS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+//==============================================================================
+// Compile-Time Checks
+//==============================================================================
+
+// Handles to kernel objects must be duplicated with `try_clone()`.
+crate::assert_not_clone!(Semaphore);
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Checks if waiting on a semaphore that stays at zero times out.
+fn semaphore_down_timeout() -> bool {
+    let sem: Semaphore = match Semaphore::get(SEMAPHORE_KEY_BASE + 7, 0) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to get semaphore");
+            return false;
+        },
+    };
+
+    let start: u32 = thread::ticks();
+    if sem.down_timeout(TIMEOUT_TICKS) != Err(KcallError::Again) {
+        nanvix::log!("wait on semaphore did not time out");
+        return false;
+    }
+
+    // The timeout is measured on the same tick counter as thread::sleep_ticks().
+    let elapsed: u32 = thread::ticks().wrapping_sub(start);
+    if u64::from(elapsed) < TIMEOUT_TICKS.as_raw() {
+        nanvix::log!("wait on semaphore timed out early (elapsed={})", elapsed);
+        return false;
+    }
+
+    // A raised semaphore should be acquired without waiting.
+    if sem.up().is_err() || sem.down_timeout(Ticks::ZERO).is_err() {
+        nanvix::log!("failed to acquire raised semaphore");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if decrementing a drained semaphore without blocking fails.
+fn semaphore_try_down() -> bool {
+    let count: u32 = 3;
+    let sem: Semaphore = match Semaphore::get(SEMAPHORE_KEY_BASE + 11, count) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to get semaphore");
+            return false;
+        },
+    };
+
+    for i in 0..count {
+        if sem.try_down() != Ok(true) {
+            nanvix::log!("failed to decrement semaphore at {}", i);
+            return false;
+        }
+    }
+
+    // The semaphore should now be drained.
+    if sem.try_down() != Ok(false) {
+        nanvix::log!("decremented a drained semaphore");
+        return false;
+    }
+
+    sem.up().is_ok() && sem.try_down() == Ok(true)
+}
+
+/// Acquires and releases a semaphore twice.
+fn semaphore_lock_unlock() -> bool {
+    let sem: Semaphore = match Semaphore::get(SEMAPHORE_KEY_BASE, 1) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to get semaphore");
+            return false;
+        },
+    };
+
+    for _ in 0..2 {
+        match sem.lock() {
+            Ok(_guard) => {},
+            Err(_) => {
+                nanvix::log!("failed to lock semaphore");
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Checks if a semaphore is removed when its handle is dropped.
+fn semaphore_drop() -> bool {
+    let id: u32 = match Semaphore::get(SEMAPHORE_KEY_BASE + 1, 0) {
+        Ok(sem) => sem.id(),
+        Err(_) => {
+            nanvix::log!("failed to get semaphore");
+            return false;
+        },
+    };
+
+    // The semaphore should no longer exist.
+    let ret: u32 = pm::semctl(id, SEMAPHORE_GETVALUE, 0) as u32;
+    if kcall::decode(ret) != Err(KcallError::NotFound) {
+        nanvix::log!("semaphore was not removed");
+        return false;
+    }
+
+    // A new semaphore with the same key should be created and removed once.
+    match Semaphore::get(SEMAPHORE_KEY_BASE + 1, 0) {
+        Ok(_) => {},
+        Err(_) => {
+            nanvix::log!("failed to get semaphore again");
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Attempts to get a semaphore that a handle already refers to.
+fn semaphore_get_held() -> bool {
+    let sem: Semaphore = match Semaphore::get(SEMAPHORE_KEY_BASE + 16, 3) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to get semaphore");
+            return false;
+        },
+    };
+    if sem.down().is_err() {
+        nanvix::log!("failed to decrement semaphore");
+        return false;
+    }
+
+    // A second handle would remove the semaphore under the first one.
+    match Semaphore::get(SEMAPHORE_KEY_BASE + 16, 9) {
+        Err(KcallError::AlreadyExists) => {},
+        _ => {
+            nanvix::log!("succeeded to get a held semaphore");
+            return false;
+        },
+    }
+
+    // The semaphore should be neither reset nor removed.
+    match sem.value() {
+        Ok(2) => true,
+        value => {
+            nanvix::log!("held semaphore was changed (value={:?})", value);
+            false
+        },
+    }
+}
+
+/// Attempts to duplicate a handle to a semaphore.
+fn semaphore_try_clone() -> bool {
+    let sem: Semaphore = match Semaphore::get(SEMAPHORE_KEY_BASE + 12, 1) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to get semaphore");
+            return false;
+        },
+    };
+
+    if sem.try_clone().err() != Some(KcallError::NotSupported) {
+        nanvix::log!("unexpected result for duplicating a semaphore");
+        return false;
+    }
+
+    // The semaphore should still exist.
+    sem.value() == Ok(1)
+}
+
+/// Reads and overwrites the counter of a semaphore.
+fn semaphore_value() -> bool {
+    let sem: Semaphore = match Semaphore::get(SEMAPHORE_KEY_BASE + 10, 3) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to get semaphore");
+            return false;
+        },
+    };
+
+    if sem.value() != Ok(3) {
+        nanvix::log!("unexpected initial value");
+        return false;
+    }
+
+    if sem.set_value(0).is_err() {
+        nanvix::log!("failed to set value");
+        return false;
+    }
+
+    if sem.value() != Ok(0) {
+        nanvix::log!("unexpected value after setting it");
+        return false;
+    }
+
+    sem.set_value(u32::MAX) == Err(KcallError::InvalidArgument)
+}
+
+/// Creates a semaphore exclusively.
+fn semaphore_create() -> bool {
+    let sem: Semaphore = match Semaphore::create(SEMAPHORE_KEY_BASE + 13, 2) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to create semaphore");
+            return false;
+        },
+    };
+
+    // Attempt to create the semaphore again.
+    match Semaphore::create(SEMAPHORE_KEY_BASE + 13, 0) {
+        Err(KcallError::AlreadyExists) => {},
+        _ => {
+            nanvix::log!("succeeded to create an existing semaphore");
+            return false;
+        },
+    }
+
+    // The semaphore should be left untouched.
+    sem.value() == Ok(2)
+}
+
+/// Opens a semaphore that may or may not exist.
+fn semaphore_open() -> bool {
+    // Attempt to open a semaphore that does not exist.
+    match Semaphore::open(SEMAPHORE_KEY_BASE + 14) {
+        Err(KcallError::NotFound) => {},
+        _ => {
+            nanvix::log!("succeeded to open a missing semaphore");
+            return false;
+        },
+    }
+
+    // The failed attempt should not leave a semaphore behind.
+    let sem: Semaphore = match Semaphore::create(SEMAPHORE_KEY_BASE + 14, 5) {
+        Ok(sem) => sem,
+        Err(_) => {
+            nanvix::log!("failed to create semaphore");
+            return false;
+        },
+    };
+
+    // Open the semaphore, keeping its counter.
+    match Semaphore::open(SEMAPHORE_KEY_BASE + 14) {
+        Ok(opened) if opened.id() == sem.id() && opened.value() == Ok(5) => {
+            // The other handle removes the semaphore.
+            core::mem::forget(opened);
+        },
+        _ => {
+            nanvix::log!("failed to open an existing semaphore");
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Gets a semaphore, creating it only if it does not exist.
+fn semaphore_get_or_create() -> bool {
+    let sem: Semaphore =
+        match Semaphore::get_or_create(SEMAPHORE_KEY_BASE + 15, 4) {
+            Ok((sem, true)) => sem,
+            _ => {
+                nanvix::log!("failed to create semaphore");
+                return false;
+            },
+        };
+
+    // Get the semaphore again, keeping its counter.
+    match Semaphore::get_or_create(SEMAPHORE_KEY_BASE + 15, 9) {
+        Ok((other, false)) if other.id() == sem.id() => {
+            // The other handle removes the semaphore.
+            core::mem::forget(other);
+        },
+        _ => {
+            nanvix::log!("failed to get an existing semaphore");
+            return false;
+        },
+    }
+
+    sem.value() == Ok(4)
+}
+
+/// Increments a counter that is protected by a mutex.
+fn mutex_increment(arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    let counter: &Mutex<u32> = unsafe { &*(arg as *const Mutex<u32>) };
+
+    for _ in 0..MUTEX_INCREMENTS {
+        match counter.lock() {
+            Ok(mut value) => *value += 1,
+            Err(_) => return core::ptr::null_mut(),
+        }
+        pm::thread_yield();
+    }
+
+    core::ptr::null_mut()
+}
+
+/// Attempts to lock a mutex that is already locked.
+fn mutex_try_lock() -> bool {
+    let mutex: Mutex<u32> = match Mutex::new(SEMAPHORE_KEY_BASE + 2, 0) {
+        Ok(mutex) => mutex,
+        Err(_) => {
+            nanvix::log!("failed to create mutex");
+            return false;
+        },
+    };
+
+    let guard = match mutex.try_lock() {
+        Some(guard) => guard,
+        None => {
+            nanvix::log!("failed to lock unlocked mutex");
+            return false;
+        },
+    };
+
+    if mutex.try_lock().is_some() {
+        nanvix::log!("succeeded to lock locked mutex");
+        return false;
+    }
+
+    drop(guard);
+
+    if mutex.try_lock().is_none() {
+        nanvix::log!("failed to lock mutex after unlocking it");
+        return false;
+    }
+
+    true
+}
+
+/// Increments a shared counter from two threads.
+fn mutex_shared_counter() -> bool {
+    let counter: Mutex<u32> = match Mutex::new(SEMAPHORE_KEY_BASE + 3, 0) {
+        Ok(mutex) => mutex,
+        Err(_) => {
+            nanvix::log!("failed to create mutex");
+            return false;
+        },
+    };
+
+    let arg: *mut ffi::c_void = &counter as *const Mutex<u32> as *mut _;
+    let tids: [Tid; 2] = [
+        pm::thread_create(mutex_increment, arg),
+        pm::thread_create(mutex_increment, arg),
+    ];
+
+    for tid in tids.iter() {
+        let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+        if pm::thread_join(*tid, &mut retval) < 0 {
+            nanvix::log!("failed to join thread {}", tid);
+            return false;
+        }
+    }
+
+    counter.into_inner() == 2 * MUTEX_INCREMENTS
+}
+
+/// Produces items into a shared slot.
+fn condvar_producer(arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    let slot: &Slot = unsafe { &*(arg as *const Slot) };
+
+    for i in 1..=CONDVAR_ITEMS {
+        let mut item = match slot.item.lock() {
+            Ok(item) => item,
+            Err(_) => return core::ptr::null_mut(),
+        };
+        while item.is_some() {
+            item = match slot.changed.wait(item) {
+                Ok(item) => item,
+                Err(_) => return core::ptr::null_mut(),
+            };
+        }
+        *item = Some(i);
+        let _ = slot.changed.notify_all();
+    }
+
+    core::ptr::null_mut()
+}
+
+/// Exchanges items between a producer and a consumer.
+fn condvar_producer_consumer() -> bool {
+    let slot: Slot = match (
+        Mutex::new(SEMAPHORE_KEY_BASE + 4, None),
+        Condvar::new(SEMAPHORE_KEY_BASE + 5),
+    ) {
+        (Ok(item), Ok(changed)) => Slot { item, changed },
+        _ => {
+            nanvix::log!("failed to create slot");
+            return false;
+        },
+    };
+
+    let arg: *mut ffi::c_void = &slot as *const Slot as *mut _;
+    let tid: Tid = pm::thread_create(condvar_producer, arg);
+
+    // Consume items.
+    let mut sum: u32 = 0;
+    for _ in 0..CONDVAR_ITEMS {
+        let mut item = match slot.item.lock() {
+            Ok(item) => item,
+            Err(_) => {
+                nanvix::log!("failed to lock slot");
+                return false;
+            },
+        };
+        while item.is_none() {
+            item = match slot.changed.wait(item) {
+                Ok(item) => item,
+                Err(_) => {
+                    nanvix::log!("failed to wait on slot");
+                    return false;
+                },
+            };
+        }
+        sum += item.take().unwrap_or(0);
+        if slot.changed.notify_all().is_err() {
+            nanvix::log!("failed to notify producer");
+            return false;
+        }
+    }
+
+    let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+    if pm::thread_join(tid, &mut retval) < 0 {
+        nanvix::log!("failed to join producer");
+        return false;
+    }
+
+    sum == CONDVAR_ITEMS * (CONDVAR_ITEMS + 1) / 2
+}
+
+/// Runs all rounds of a lockstep computation.
+fn barrier_rounds(lockstep: &Lockstep) {
+    for arrived in lockstep.arrived.iter() {
+        arrived.fetch_add(1, Ordering::SeqCst);
+        if lockstep.barrier.wait().is_err()
+            || arrived.load(Ordering::SeqCst) != BARRIER_THREADS
+        {
+            lockstep.failed.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Runs all rounds of a lockstep computation in a spawned thread.
+fn barrier_worker(arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    barrier_rounds(unsafe { &*(arg as *const Lockstep) });
+    core::ptr::null_mut()
+}
+
+/// Runs several threads in lockstep through multiple barrier rounds.
+fn barrier_lockstep() -> bool {
+    let lockstep: Lockstep =
+        match Barrier::new(SEMAPHORE_KEY_BASE + 6, BARRIER_THREADS) {
+            Ok(barrier) => Lockstep {
+                barrier,
+                arrived: Default::default(),
+                failed: AtomicBool::new(false),
+            },
+            Err(_) => {
+                nanvix::log!("failed to create barrier");
+                return false;
+            },
+        };
+
+    // The calling thread is one of the participants.
+    let arg: *mut ffi::c_void = &lockstep as *const Lockstep as *mut _;
+    let mut tids: [Tid; BARRIER_THREADS as usize - 1] = Default::default();
+    for tid in tids.iter_mut() {
+        *tid = pm::thread_create(barrier_worker, arg);
+    }
+
+    barrier_rounds(&lockstep);
+
+    for tid in tids.iter() {
+        let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+        if pm::thread_join(*tid, &mut retval) < 0 {
+            nanvix::log!("failed to join thread");
+            return false;
+        }
+    }
+
+    !lockstep.failed.load(Ordering::SeqCst)
+}
+
+/// Reads the shared pair, checking that no writer is active.
+fn rwlock_reader(arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    let shared: &Shared = unsafe { &*(arg as *const Shared) };
+    for _ in 0..RWLOCK_ROUNDS {
+        match shared.pair.read() {
+            Ok(pair) => {
+                shared.readers.fetch_add(1, Ordering::SeqCst);
+                pm::thread_yield();
+                if shared.writers.load(Ordering::SeqCst) != 0
+                    || pair[0] != pair[1]
+                {
+                    shared.failed.store(true, Ordering::SeqCst);
+                }
+                shared.readers.fetch_sub(1, Ordering::SeqCst);
+            },
+            Err(_) => shared.failed.store(true, Ordering::SeqCst),
+        }
+        pm::thread_yield();
+    }
+    core::ptr::null_mut()
+}
+
+/// Updates the shared pair, checking that no other thread is active.
+fn rwlock_writer(arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    let shared: &Shared = unsafe { &*(arg as *const Shared) };
+    for _ in 0..RWLOCK_ROUNDS {
+        match shared.pair.write() {
+            Ok(mut pair) => {
+                if shared.writers.fetch_add(1, Ordering::SeqCst) != 0
+                    || shared.readers.load(Ordering::SeqCst) != 0
+                {
+                    shared.failed.store(true, Ordering::SeqCst);
+                }
+                pair[0] += 1;
+                pm::thread_yield();
+                pair[1] += 1;
+                shared.writers.fetch_sub(1, Ordering::SeqCst);
+            },
+            Err(_) => shared.failed.store(true, Ordering::SeqCst),
+        }
+        pm::thread_yield();
+    }
+    core::ptr::null_mut()
+}
+
+/// Runs several readers and one writer on a reader-writer lock.
+fn rwlock_readers_writer() -> bool {
+    let shared: Shared = match RwLock::new(
+        SEMAPHORE_KEY_BASE + 8,
+        SEMAPHORE_KEY_BASE + 9,
+        [0, 0],
+    ) {
+        Ok(pair) => Shared {
+            pair,
+            readers: AtomicU32::new(0),
+            writers: AtomicU32::new(0),
+            failed: AtomicBool::new(false),
+        },
+        Err(_) => {
+            nanvix::log!("failed to create reader-writer lock");
+            return false;
+        },
+    };
+
+    let arg: *mut ffi::c_void = &shared as *const Shared as *mut _;
+    let mut tids: [Tid; RWLOCK_READERS + 1] = Default::default();
+    tids[0] = pm::thread_create(rwlock_writer, arg);
+    for tid in tids[1..].iter_mut() {
+        *tid = pm::thread_create(rwlock_reader, arg);
+    }
+
+    for tid in tids.iter() {
+        let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+        if pm::thread_join(*tid, &mut retval) < 0 {
+            nanvix::log!("failed to join thread {}", tid);
+            return false;
+        }
+    }
+
+    if shared.failed.load(Ordering::SeqCst) {
+        nanvix::log!("exclusive access was violated");
+        return false;
+    }
+
+    shared.pair.into_inner() == [RWLOCK_ROUNDS, RWLOCK_ROUNDS]
+}
+
+/// Initializes the lazy value, yielding to let other threads contend.
+fn init_lazy_value() -> u32 {
+    LAZY_INITS.fetch_add(1, Ordering::SeqCst);
+    for _ in 0..LAZY_YIELDS {
+        pm::thread_yield();
+    }
+    LAZY_MAGIC
+}
+
+/// Reads the lazy value in a spawned thread.
+fn lazy_reader(_arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    if *LAZY_VALUE != LAZY_MAGIC {
+        LAZY_FAILED.store(true, Ordering::SeqCst);
+    }
+    core::ptr::null_mut()
+}
+
+/// Races several threads to initialize a lazy value.
+fn lazy_race() -> bool {
+    let mut tids: [Tid; LAZY_THREADS] = Default::default();
+    for tid in tids.iter_mut() {
+        *tid = pm::thread_create(lazy_reader, core::ptr::null_mut());
+    }
+
+    for tid in tids.iter() {
+        let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+        if pm::thread_join(*tid, &mut retval) < 0 {
+            nanvix::log!("failed to join thread");
+            return false;
+        }
+    }
+
+    if LAZY_FAILED.load(Ordering::SeqCst) {
+        nanvix::log!("observed uninitialized lazy value");
+        return false;
+    }
+
+    LAZY_INITS.load(Ordering::SeqCst) == 1 && *LAZY_VALUE == LAZY_MAGIC
+}
+
+/// Checks if a once primitive runs a single routine.
+fn once_call_once() -> bool {
+    let once: Once = Once::new();
+    let mut count: u32 = 0;
+
+    once.call_once(|| count += 1);
+    once.call_once(|| count += 1);
+
+    once.is_completed() && count == 1
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+/// Increments a counter that is protected by a spin mutex.
+fn spin_increment(_arg: *mut ffi::c_void) -> *mut ffi::c_void {
+    for _ in 0..SPIN_INCREMENTS {
+        let mut value: SpinMutexGuard<u32> = SPIN_COUNTER.lock();
+        if SPIN_HELD.swap(true, Ordering::SeqCst) {
+            SPIN_FAILED.store(true, Ordering::SeqCst);
+        }
+
+        // Yield while holding the mutex, so that other threads contend for it.
+        let old: u32 = *value;
+        pm::thread_yield();
+        *value = old + 1;
+
+        SPIN_HELD.store(false, Ordering::SeqCst);
+    }
+
+    core::ptr::null_mut()
+}
+
+/// Increments a shared counter from several threads under a spin mutex.
+fn spin_mutex_contention() -> bool {
+    let mut tids: [Tid; SPIN_THREADS] = [0; SPIN_THREADS];
+    for tid in tids.iter_mut() {
+        *tid = pm::thread_create(spin_increment, core::ptr::null_mut());
+        if *tid < 0 {
+            nanvix::log!("failed to create thread");
+            return false;
+        }
+    }
+
+    for tid in tids.iter() {
+        let mut retval: *mut ffi::c_void = core::ptr::null_mut();
+        if pm::thread_join(*tid, &mut retval) < 0 {
+            nanvix::log!("failed to join thread {}", tid);
+            return false;
+        }
+    }
+
+    if SPIN_FAILED.load(Ordering::SeqCst) {
+        nanvix::log!("two threads held the spin mutex at the same time");
+        return false;
+    }
+
+    *SPIN_COUNTER.lock() == SPIN_THREADS as u32 * SPIN_INCREMENTS
+}
+
+/// Acquires a ticket mutex several times and logs the served tickets.
+fn ticket_acquire() {
+    for _ in 0..TICKET_ROUNDS {
+        let mut log: TicketMutexGuard<([u32; TICKET_TOTAL], usize)> =
+            TICKET_LOG.lock();
+        let ticket: u32 = log.ticket();
+        let (tickets, len): &mut ([u32; TICKET_TOTAL], usize) = &mut log;
+        tickets[*len] = ticket;
+        *len += 1;
+
+        // Yield while holding the mutex, so that other threads queue up.
+        thread::yield_now();
+    }
+}
+
+/// Checks if threads that contend for a ticket mutex are served in order.
+fn ticket_mutex_fifo() -> bool {
+    let mut handles: [Option<JoinHandle>; TICKET_THREADS] = [None, None, None];
+    for handle in handles.iter_mut() {
+        match thread::spawn(ticket_acquire) {
+            Ok(h) => *handle = Some(h),
+            Err(_) => {
+                nanvix::log!("failed to spawn thread");
+                return false;
+            },
+        }
+    }
+    for handle in handles.iter_mut() {
+        if handle.take().map(|h| h.join().is_ok()) != Some(true) {
+            nanvix::log!("failed to join thread");
+            return false;
+        }
+    }
+
+    // Tickets should have been served in the order that they were drawn.
+    let log: TicketMutexGuard<([u32; TICKET_TOTAL], usize)> = TICKET_LOG.lock();
+    let (tickets, len): &([u32; TICKET_TOTAL], usize) = &log;
+    if *len != TICKET_TOTAL {
+        nanvix::log!("unexpected number of acquisitions {}", len);
+        return false;
+    }
+    for (i, ticket) in tickets.iter().enumerate() {
+        if *ticket != i as u32 {
+            nanvix::log!("ticket {} was served in place of {}", ticket, i);
+            return false;
+        }
+    }
+
+    // A failed attempt should not draw a ticket.
+    if TICKET_LOG.try_lock().is_some() {
+        nanvix::log!("acquired a ticket mutex that is held");
+        return false;
+    }
+    drop(log);
+
+    match TICKET_LOG.try_lock() {
+        Some(guard) => guard.ticket() == TICKET_TOTAL as u32 + 1,
+        None => {
+            nanvix::log!("failed to acquire a ticket mutex that is free");
+            false
+        },
+    }
+}
+
+/// Shares a value across threads and checks that it is released once.
+fn arc_shared_across_threads() -> bool {
+    let shared: Arc<Tracked> = match Arc::new(Tracked { magic: ARC_MAGIC }) {
+        Ok(shared) => shared,
+        Err(_) => {
+            nanvix::log!("failed to create arc");
+            return false;
+        },
+    };
+
+    let mut handles: [Option<JoinHandle>; ARC_THREADS] = [None, None, None];
+    for handle in handles.iter_mut() {
+        let other: Arc<Tracked> = Arc::clone(&shared);
+        let ret: Result<JoinHandle, KcallError> = thread::spawn(move || {
+            let code: i32 = if other.magic == ARC_MAGIC { 0 } else { 1 };
+            drop(other);
+            thread::exit(code)
+        });
+        match ret {
+            Ok(h) => *handle = Some(h),
+            Err(_) => {
+                nanvix::log!("failed to spawn thread");
+                return false;
+            },
+        }
+    }
+    for handle in handles.iter_mut() {
+        if handle.take().map(|h| h.join()) != Some(Ok(0)) {
+            nanvix::log!("thread read an unexpected value");
+            return false;
+        }
+    }
+
+    if Arc::strong_count(&shared) != 1 || ARC_DROPS.load(Ordering::Relaxed) != 0
+    {
+        nanvix::log!("value was released before the last reference");
+        return false;
+    }
+
+    let frame: FrameNumber = Arc::frame(&shared);
+    drop(shared);
+
+    if ARC_DROPS.load(Ordering::Relaxed) != 1 {
+        nanvix::log!("value was not dropped exactly once");
+        return false;
+    }
+
+    // The page frame should already be released.
+    if kcall::decode(memory::frfree(frame)).is_ok() {
+        nanvix::log!("page frame of the arc was not released");
+        return false;
+    }
+
+    true
+}
+
+/// Attempts to share a value that does not fit in a page.
+fn arc_too_large() -> bool {
+    match Arc::new([0u8; memory::PAGE_SIZE as usize]) {
+        Err(KcallError::InvalidArgument) => true,
+        _ => {
+            nanvix::log!("succeeded to share a value that does not fit a page");
+            false
+        },
+    }
+}
+
+/// Checks the compare-exchange semantics of atomic process IDs.
+fn atomic_pid_compare_exchange() -> bool {
+    let (a, b): (Pid, Pid) = (Pid::new(1), Pid::new(2));
+    let pid: AtomicPid = AtomicPid::new(a);
+
+    // A stale expected value should hand back the current value.
+    if pid.compare_exchange(b, b, Ordering::AcqRel, Ordering::Acquire) != Err(a)
+    {
+        nanvix::log!("exchanged a process ID that was not the expected one");
+        return false;
+    }
+
+    if pid.compare_exchange(a, b, Ordering::AcqRel, Ordering::Acquire) != Ok(a)
+    {
+        nanvix::log!("failed to exchange the expected process ID");
+        return false;
+    }
+
+    pid.load(Ordering::Acquire) == b && pid.swap(a, Ordering::AcqRel) == b
+}
+
+/// Takes the value of atomic process IDs.
+fn atomic_pid_take() -> bool {
+    let pid: AtomicPid = AtomicPid::new(Pid::new(3));
+
+    if pid.take(Ordering::AcqRel) != Some(Pid::new(3)) {
+        nanvix::log!("failed to take process ID");
+        return false;
+    }
+
+    // The sentinel should be left behind, and never be handed out.
+    if !pid.load(Ordering::Acquire).is_self()
+        || pid.take(Ordering::AcqRel).is_some()
+    {
+        nanvix::log!("took a process ID from an empty value");
+        return false;
+    }
+
+    AtomicPid::default().into_inner().is_self()
+}
+
+/// Checks the compare-exchange semantics of atomic thread identities.
+fn atomic_thread_id_compare_exchange() -> bool {
+    let (a, b): (ThreadId, ThreadId) =
+        (ThreadId::from_raw(1), ThreadId::from_raw(2));
+    let id: AtomicThreadId = AtomicThreadId::empty();
+
+    // An empty value should only be claimed by expecting an empty value.
+    if id.compare_exchange(Some(a), b, Ordering::AcqRel, Ordering::Acquire)
+        != Err(None)
+    {
+        nanvix::log!("claimed an empty thread identity with a stale value");
+        return false;
+    }
+    if id.compare_exchange(None, a, Ordering::AcqRel, Ordering::Acquire)
+        != Ok(None)
+    {
+        nanvix::log!("failed to claim an empty thread identity");
+        return false;
+    }
+
+    // A stale expected value should hand back the current value.
+    id.compare_exchange(Some(b), b, Ordering::AcqRel, Ordering::Acquire)
+        == Err(Some(a))
+        && id.load(Ordering::Acquire) == Some(a)
+}
+
+/// Takes the value of atomic thread identities.
+fn atomic_thread_id_take() -> bool {
+    let id: AtomicThreadId = AtomicThreadId::new(ThreadId::from_raw(0));
+
+    // Thread ID zero is a valid thread, not the sentinel.
+    if id.take(Ordering::AcqRel) != Some(ThreadId::from_raw(0)) {
+        nanvix::log!("failed to take thread identity");
+        return false;
+    }
+
+    if id.load(Ordering::Acquire).is_some()
+        || id.take(Ordering::AcqRel).is_some()
+    {
+        nanvix::log!("took a thread identity from an empty value");
+        return false;
+    }
+
+    AtomicThreadId::default().into_inner().is_none()
+}
+
+pub fn test() {
+    crate::test!(semaphore_lock_unlock());
+    crate::test!(semaphore_drop());
+    crate::test!(semaphore_get_held());
+    crate::test!(semaphore_down_timeout());
+    crate::test!(semaphore_try_down());
+    crate::test!(semaphore_value());
+    crate::test!(semaphore_create());
+    crate::test!(semaphore_open());
+    crate::test!(semaphore_get_or_create());
+    crate::test!(semaphore_try_clone());
+    crate::test!(mutex_try_lock());
+    crate::test!(mutex_shared_counter());
+    crate::test!(condvar_producer_consumer());
+    crate::test!(barrier_lockstep());
+    crate::test!(once_call_once());
+    crate::test!(lazy_race());
+    crate::test!(rwlock_readers_writer());
+    crate::test!(spin_mutex_contention());
+    crate::test!(ticket_mutex_fifo());
+    crate::test!(arc_shared_across_threads());
+    crate::test!(arc_too_large());
+    crate::test!(atomic_pid_compare_exchange());
+    crate::test!(atomic_pid_take());
+    crate::test!(atomic_thread_id_compare_exchange());
+    crate::test!(atomic_thread_id_take());
+}
@@ -0,0 +1,738 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use alloc::vec::Vec;
+use core::{
+    cell::Cell,
+    sync::atomic::{
+        AtomicBool,
+        AtomicU32,
+        Ordering,
+    },
+};
+use nanvix::{
+    excp::ExceptionKind,
+    kcall::KcallError,
+    memory::VirtualAddress,
+    pm::{
+        ExitStatus,
+        Tid,
+    },
+    sync::AtomicThreadId,
+    thread::{
+        self,
+        Builder,
+        JoinHandle,
+        Pool,
+        Registry,
+        ThreadId,
+        THREAD_NAME_MAX,
+        THREAD_STACKS_MAX,
+        THREAD_STACK_MAX,
+    },
+    time::Ticks,
+};
+
+//==============================================================================
+// Constants
+//==============================================================================
+
+/// Number of threads spawned in tests.
+const NTHREADS: usize = 4;
+
+/// Exit code used in tests.
+const EXIT_CODE: i32 = 7;
+
+/// Exit codes of threads that are joined together.
+const JOIN_CODES: [i32; 5] = [11, 12, 13, 14, 15];
+
+/// Number of ticks to sleep in tests.
+const SLEEP_TICKS: Ticks = Ticks::from_raw(5);
+
+/// Maximum number of attempts to reap finished threads in tests.
+const REAP_ATTEMPTS: usize = 1000;
+
+/// Number of times that threads yield to themselves in tests.
+const YIELD_TO_ROUNDS: usize = 16;
+
+/// Size of custom stacks in tests (in bytes).
+const STACK_SIZE: usize = 8 * 1024;
+
+/// Depth of recursion on custom stacks, which takes more than the one page
+/// that the kernel hands out to a thread.
+const RECURSION_DEPTH: u32 = 40;
+
+/// Size of the buffer that is kept in each frame of recursion (in bytes).
+const RECURSION_FRAME: usize = 128;
+
+/// Number of jobs submitted to thread pools in tests.
+const POOL_JOBS: u32 = 100;
+
+/// Address that is not mapped, so that accessing it faults.
+const FAULT_ADDRESS: VirtualAddress = 0x06000000;
+
+//==============================================================================
+// Static Variables
+//==============================================================================
+
+nanvix::thread_local! {
+    /// Counter that is private to each thread.
+    static COUNTER: Cell<u32> = Cell::new(0);
+}
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Spawns several threads that increment a shared counter and joins them.
+fn spawn_join() -> bool {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let mut handles: [Option<JoinHandle>; NTHREADS] = Default::default();
+    for handle in handles.iter_mut() {
+        let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+        });
+        match ret {
+            Ok(h) => *handle = Some(h),
+            Err(_) => {
+                nanvix::log!("failed to spawn thread");
+                return false;
+            },
+        }
+    }
+
+    for handle in handles.iter_mut() {
+        if let Some(h) = handle.take() {
+            if h.join() != Ok(0) {
+                nanvix::log!("failed to join thread");
+                return false;
+            }
+        }
+    }
+
+    COUNTER.load(Ordering::SeqCst) == NTHREADS as u32
+}
+
+/// Checks if the exit code of a thread is retrieved when it is joined.
+fn spawn_exit_code() -> bool {
+    let handle: JoinHandle = match thread::spawn(|| thread::exit(EXIT_CODE)) {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+
+    if handle.join() != Ok(EXIT_CODE) {
+        nanvix::log!("unexpected exit code");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if exit codes of several threads are collected in order.
+fn join_all_exit_codes() -> bool {
+    let handles: [JoinHandle; JOIN_CODES.len()] = match JOIN_CODES
+        .map(|code| thread::spawn(move || thread::exit(code)))
+    {
+        [Ok(a), Ok(b), Ok(c), Ok(d), Ok(e)] => [a, b, c, d, e],
+        _ => {
+            nanvix::log!("failed to spawn threads");
+            return false;
+        },
+    };
+
+    if thread::join_all(handles) != JOIN_CODES.map(Ok) {
+        nanvix::log!("unexpected exit codes");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if the exit status of a thread is decoded when it is joined.
+fn join_exit_status() -> bool {
+    match thread::spawn(|| thread::exit(EXIT_CODE)) {
+        Ok(handle) => match handle.join_status() {
+            Ok(ExitStatus::Exited(code)) if code as i32 == EXIT_CODE => true,
+            _ => {
+                nanvix::log!("unexpected exit status");
+                false
+            },
+        },
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            false
+        },
+    }
+}
+
+/// Spawns a thread and detaches it.
+fn spawn_detach() -> bool {
+    let handle: JoinHandle = match thread::spawn(|| {}) {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+
+    if handle.detach().is_err() {
+        nanvix::log!("failed to detach thread");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if a spawned thread has a different ID than its parent.
+fn current_tid() -> bool {
+    static CHILD_TID: AtomicU32 = AtomicU32::new(0);
+
+    let parent: Tid = thread::current();
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+        CHILD_TID.store(thread::current() as u32, Ordering::SeqCst);
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+    let child: Tid = handle.tid();
+
+    if handle.join().is_err() {
+        nanvix::log!("failed to join thread");
+        return false;
+    }
+
+    if CHILD_TID.load(Ordering::SeqCst) != child as u32 || child == parent {
+        nanvix::log!("unexpected thread id");
+        return false;
+    }
+
+    // Yielding should be harmless.
+    thread::yield_now();
+
+    thread::current() == parent
+}
+
+/// Checks if thread identities tell the calling thread and live threads apart.
+fn current_id() -> bool {
+    static RELEASED: AtomicBool = AtomicBool::new(false);
+    static MISMATCHES: AtomicU32 = AtomicU32::new(0);
+
+    let parent: ThreadId = thread::current_id();
+    if thread::current_id() != parent {
+        nanvix::log!("unstable thread identity");
+        return false;
+    }
+
+    // Keep both threads alive, so that their identities are not reused.
+    let mut handles: [Option<JoinHandle>; 2] = [None, None];
+    for handle in handles.iter_mut() {
+        let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+            if thread::current_id() != thread::current_id() {
+                MISMATCHES.fetch_add(1, Ordering::SeqCst);
+            }
+            while !RELEASED.load(Ordering::SeqCst) {
+                thread::yield_now();
+            }
+        });
+        match ret {
+            Ok(h) => *handle = Some(h),
+            Err(_) => {
+                nanvix::log!("failed to spawn thread");
+                RELEASED.store(true, Ordering::SeqCst);
+                return false;
+            },
+        }
+    }
+
+    let ids: [Option<ThreadId>; 2] = [
+        handles[0].as_ref().map(|h| h.id()),
+        handles[1].as_ref().map(|h| h.id()),
+    ];
+    RELEASED.store(true, Ordering::SeqCst);
+
+    for handle in handles.iter_mut() {
+        if handle.take().map(|h| h.join()) != Some(Ok(0)) {
+            nanvix::log!("failed to join thread");
+            return false;
+        }
+    }
+
+    ids[0] != ids[1]
+        && ids[0] != Some(parent)
+        && ids[1] != Some(parent)
+        && MISMATCHES.load(Ordering::SeqCst) == 0
+}
+
+/// Increments the thread-local counter several times and exits with its value.
+fn count_and_exit(increments: u32) -> ! {
+    for _ in 0..increments {
+        COUNTER.with(|counter| counter.set(counter.get() + 1));
+    }
+    thread::exit(COUNTER.with(|counter| counter.get()) as i32)
+}
+
+/// Checks if spawned threads see independent thread-local values.
+fn thread_local_counter() -> bool {
+    let first: JoinHandle = match thread::spawn(|| count_and_exit(3)) {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+    let second: JoinHandle = match thread::spawn(|| count_and_exit(5)) {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+
+    if first.join() != Ok(3) || second.join() != Ok(5) {
+        nanvix::log!("unexpected thread-local value");
+        return false;
+    }
+
+    // The value of the calling thread is untouched.
+    COUNTER.with(|counter| counter.get()) == 0
+}
+
+/// Main function run by the runtime shim in tests.
+#[cfg(feature = "rt")]
+fn rt_main(argc: i32, argv: *const *const u8) -> i32 {
+    // The main function is handed an empty argument table.
+    if argc != 0 || unsafe { !(*argv).is_null() } {
+        return -1;
+    }
+    EXIT_CODE
+}
+
+/// Checks if sleeping advances the tick counter by at least the given ticks.
+fn sleep_advances_ticks() -> bool {
+    let start: u32 = thread::ticks();
+    thread::sleep_ticks(SLEEP_TICKS);
+    let elapsed: u32 = thread::ticks().wrapping_sub(start);
+
+    if (elapsed as u64) < SLEEP_TICKS.as_raw() {
+        nanvix::log!("slept for {} ticks, expected {:?}", elapsed, SLEEP_TICKS);
+        return false;
+    }
+
+    // Sleeping for no ticks should return immediately.
+    thread::sleep_ticks(Ticks::ZERO);
+
+    true
+}
+
+/// Checks if a registry eventually reports detached threads as finished.
+fn registry_reaps_detached() -> bool {
+    let mut registry: Registry<NTHREADS> = Registry::new();
+    let mut ids: Vec<ThreadId> = Vec::new();
+
+    for _ in 0..NTHREADS {
+        let handle: JoinHandle = match thread::spawn(thread::yield_now) {
+            Ok(handle) => handle,
+            Err(_) => {
+                nanvix::log!("failed to spawn thread");
+                return false;
+            },
+        };
+        match registry.register(handle) {
+            Ok(id) => ids.push(id),
+            Err(_) => {
+                nanvix::log!("failed to register thread");
+                return false;
+            },
+        }
+    }
+
+    let mut reaped: Vec<ThreadId> = Vec::new();
+    for _ in 0..REAP_ATTEMPTS {
+        reaped.extend(registry.reap_finished());
+        if registry.is_empty() {
+            break;
+        }
+        thread::yield_now();
+    }
+
+    if !registry.is_empty() {
+        nanvix::log!("{} threads were never reaped", registry.len());
+        return false;
+    }
+
+    reaped.sort();
+    ids.sort();
+    if reaped != ids {
+        nanvix::log!("unexpected threads were reaped");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if a thread that is spawned with a custom stack recurses deeply.
+fn builder_custom_stack() -> bool {
+    static DEPTH: AtomicU32 = AtomicU32::new(0);
+    static NAMED: AtomicBool = AtomicBool::new(false);
+
+    let ret: Result<JoinHandle, KcallError> = Builder::new()
+        .stack_size(STACK_SIZE)
+        .name("worker")
+        .spawn(|| {
+            let named: bool = thread::current_name()
+                .map_or(false, |name| name.as_str() == "worker");
+            NAMED.store(named, Ordering::SeqCst);
+            DEPTH.store(recurse(RECURSION_DEPTH), Ordering::SeqCst);
+        });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread with a custom stack");
+            return false;
+        },
+    };
+
+    if handle.join() != Ok(0) {
+        nanvix::log!("failed to join thread");
+        return false;
+    }
+
+    if DEPTH.load(Ordering::SeqCst) != RECURSION_DEPTH {
+        nanvix::log!("unexpected depth {}", DEPTH.load(Ordering::SeqCst));
+        return false;
+    }
+
+    if !NAMED.load(Ordering::SeqCst) {
+        nanvix::log!("thread did not see its name");
+        return false;
+    }
+
+    // Names are forgotten once threads finish.
+    thread::current_name().is_none()
+}
+
+/// Checks if custom stacks of threads that exit are released once joined.
+fn builder_exit_releases_stack() -> bool {
+    // Spawn more threads than stacks, one at a time.
+    for _ in 0..=THREAD_STACKS_MAX {
+        let handle: JoinHandle = match Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn(|| thread::exit(EXIT_CODE))
+        {
+            Ok(handle) => handle,
+            Err(e) => {
+                nanvix::log!("failed to spawn thread ({:?})", e);
+                return false;
+            },
+        };
+
+        if handle.join() != Ok(EXIT_CODE) {
+            nanvix::log!("unexpected exit code");
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Checks if invalid configurations of threads are rejected.
+fn builder_invalid() -> bool {
+    let long_name: [u8; THREAD_NAME_MAX + 1] = [b'a'; THREAD_NAME_MAX + 1];
+    let long_name: &str = match core::str::from_utf8(&long_name) {
+        Ok(name) => name,
+        Err(_) => return false,
+    };
+
+    let builders: [Builder; 3] = [
+        Builder::new().stack_size(0),
+        Builder::new().stack_size(THREAD_STACK_MAX + 1),
+        Builder::new().name(long_name),
+    ];
+    for builder in builders {
+        match builder.spawn(|| {}) {
+            Err(KcallError::InvalidArgument) => {},
+            _ => {
+                nanvix::log!("accepted invalid configuration {:?}", builder);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Recurses with a buffer on each frame.
+fn recurse(depth: u32) -> u32 {
+    let frame: [u8; RECURSION_FRAME] =
+        core::hint::black_box([depth as u8; RECURSION_FRAME]);
+    if depth == 0 {
+        return 0;
+    }
+
+    // Keep the buffer alive across the call.
+    let below: u32 = recurse(depth - 1);
+    core::hint::black_box(&frame);
+
+    below + 1
+}
+
+/// Checks if the code returned by a main function is the exit code.
+#[cfg(feature = "rt")]
+fn rt_main_exit_code() -> bool {
+    match thread::spawn(|| nanvix::rt::run(rt_main)) {
+        Ok(handle) => handle.join() == Ok(EXIT_CODE),
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            false
+        },
+    }
+}
+
+/// Yields to the calling thread, and then to a worker until the worker runs.
+fn yield_to_self() -> bool {
+    static HANDED_OFF: AtomicBool = AtomicBool::new(false);
+
+    let id: ThreadId = thread::current_id();
+    for _ in 0..YIELD_TO_ROUNDS {
+        thread::yield_to(id);
+    }
+    if thread::current_id() != id {
+        nanvix::log!("identity changed across yields");
+        return false;
+    }
+
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+        HANDED_OFF.store(true, Ordering::Release);
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+    for _ in 0..REAP_ATTEMPTS {
+        if HANDED_OFF.load(Ordering::Acquire) {
+            break;
+        }
+        thread::yield_to(handle.id());
+    }
+
+    handle.join().is_ok() && HANDED_OFF.load(Ordering::Acquire)
+}
+
+/// Spawns an isolated worker that faults, and keeps running.
+fn spawn_isolated_fault() -> bool {
+    static RESUMED: AtomicBool = AtomicBool::new(false);
+
+    let ret: Result<JoinHandle, KcallError> = thread::spawn_isolated(|| {
+        let _ =
+            unsafe { core::ptr::read_volatile(FAULT_ADDRESS as *const u32) };
+        RESUMED.store(true, Ordering::Release);
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(e) => {
+            nanvix::log!("failed to spawn isolated worker ({:?})", e);
+            return false;
+        },
+    };
+
+    match handle.join_status() {
+        Ok(ExitStatus::Faulted(ExceptionKind::PageFault)) => {},
+        status => {
+            nanvix::log!("unexpected exit status ({:?})", status);
+            return false;
+        },
+    }
+
+    // The worker should have been terminated on the fault.
+    if RESUMED.load(Ordering::Acquire) {
+        nanvix::log!("isolated worker ran past the fault");
+        return false;
+    }
+
+    // Page faults should be handled by the kernel again.
+    match thread::spawn_isolated(|| {}) {
+        Ok(handle) => handle.join_status() == Ok(ExitStatus::Exited(0)),
+        Err(_) => {
+            nanvix::log!("failed to spawn isolated worker after fault");
+            false
+        },
+    }
+}
+
+/// Checks if isolated workers are supervised one at a time.
+fn spawn_isolated_busy() -> bool {
+    static RELEASE: AtomicBool = AtomicBool::new(false);
+
+    let handle: JoinHandle = match thread::spawn_isolated(|| {
+        while !RELEASE.load(Ordering::Acquire) {
+            thread::yield_now();
+        }
+    }) {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn isolated worker");
+            return false;
+        },
+    };
+
+    let busy: bool =
+        matches!(thread::spawn_isolated(|| {}), Err(KcallError::Busy));
+    RELEASE.store(true, Ordering::Release);
+
+    if handle.join_status() != Ok(ExitStatus::Exited(0)) {
+        nanvix::log!("failed to join isolated worker");
+        return false;
+    }
+
+    busy
+}
+
+/// Parks a thread until another thread unparks it.
+fn park_unpark() -> bool {
+    static PARKER: AtomicThreadId = AtomicThreadId::empty();
+    static READY: AtomicBool = AtomicBool::new(false);
+
+    let ret: Result<JoinHandle, KcallError> = thread::spawn(|| {
+        PARKER.store(thread::current_id(), Ordering::Release);
+        while !READY.load(Ordering::Acquire) {
+            if thread::park().is_err() {
+                thread::exit(1);
+            }
+        }
+    });
+    let handle: JoinHandle = match ret {
+        Ok(handle) => handle,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread");
+            return false;
+        },
+    };
+
+    // Wait for the worker to publish its identity, so that it may park.
+    let id: ThreadId = loop {
+        match PARKER.load(Ordering::Acquire) {
+            Some(id) => break id,
+            None => thread::yield_now(),
+        }
+    };
+    if id != handle.id() {
+        nanvix::log!("unexpected identity of parked thread");
+        return false;
+    }
+
+    READY.store(true, Ordering::Release);
+    if thread::unpark(id).is_err() {
+        nanvix::log!("failed to unpark thread");
+        return false;
+    }
+
+    match handle.join() {
+        Ok(0) => true,
+        _ => {
+            nanvix::log!("failed to join parked thread");
+            false
+        },
+    }
+}
+
+/// Checks if a token that is handed over before parking is not lost.
+fn park_early_token() -> bool {
+    let id: ThreadId = thread::current_id();
+
+    // Tokens do not accumulate, thus a single park consumes both unparks.
+    if thread::unpark(id).is_err() || thread::unpark(id).is_err() {
+        nanvix::log!("failed to unpark the calling thread");
+        return false;
+    }
+    if thread::park().is_err() {
+        nanvix::log!("failed to consume token");
+        return false;
+    }
+
+    // Threads that are out of range should be rejected.
+    thread::unpark(ThreadId::from_raw(-1)) == Err(KcallError::InvalidArgument)
+}
+
+/// Submits jobs to a thread pool and checks if all of them run.
+fn pool_execute() -> bool {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let pool: Pool = match Pool::new(NTHREADS) {
+        Ok(pool) => pool,
+        Err(_) => {
+            nanvix::log!("failed to spawn thread pool");
+            return false;
+        },
+    };
+    if pool.workers() != NTHREADS {
+        nanvix::log!("unexpected number of workers");
+        return false;
+    }
+
+    for _ in 0..POOL_JOBS {
+        let ret: Result<(), KcallError> = pool.execute(|| {
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+        });
+        if ret.is_err() {
+            nanvix::log!("failed to submit job");
+            return false;
+        }
+    }
+
+    // Dropping the pool waits for pending jobs.
+    drop(pool);
+
+    let count: u32 = COUNTER.load(Ordering::SeqCst);
+    if count != POOL_JOBS {
+        nanvix::log!("unexpected number of jobs run (count={})", count);
+        return false;
+    }
+
+    // Pools with no workers should be rejected.
+    Pool::new(0).err() == Some(KcallError::InvalidArgument)
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+pub fn test() {
+    crate::test!(spawn_join());
+    crate::test!(spawn_exit_code());
+    crate::test!(join_all_exit_codes());
+    crate::test!(join_exit_status());
+    crate::test!(spawn_detach());
+    crate::test!(current_tid());
+    crate::test!(current_id());
+    crate::test!(thread_local_counter());
+    crate::test!(sleep_advances_ticks());
+    crate::test!(yield_to_self());
+    crate::test!(registry_reaps_detached());
+    crate::test!(builder_custom_stack());
+    crate::test!(builder_exit_releases_stack());
+    crate::test!(builder_invalid());
+    crate::test!(spawn_isolated_fault());
+    crate::test!(spawn_isolated_busy());
+    crate::test!(park_unpark());
+    crate::test!(park_early_token());
+    crate::test!(pool_execute());
+    #[cfg(feature = "rt")]
+    crate::test!(rt_main_exit_code());
+}
@@ -0,0 +1,100 @@
+/*
+ * Copyright(c) 2011-2024 The Maintainers of Nanvix.
+ * Licensed under the MIT License.
+ */
+
+//==============================================================================
+// Imports
+//==============================================================================
+
+use nanvix::time::{
+    self,
+    Ticks,
+};
+
+//==============================================================================
+// Private Standalone Functions
+//==============================================================================
+
+/// Checks if arithmetic on ticks saturates at the boundaries of `u64`.
+fn ticks_saturating_arithmetic() -> bool {
+    let one: Ticks = Ticks::from_raw(1);
+
+    if Ticks::MAX.saturating_add(one) != Ticks::MAX
+        || Ticks::MAX.saturating_add(Ticks::MAX) != Ticks::MAX
+        || Ticks::from_raw(u64::MAX - 1).saturating_add(one) != Ticks::MAX
+    {
+        nanvix::log!("addition of ticks did not saturate");
+        return false;
+    }
+
+    if Ticks::ZERO.saturating_sub(one) != Ticks::ZERO
+        || Ticks::MAX.saturating_sub(Ticks::MAX) != Ticks::ZERO
+    {
+        nanvix::log!("subtraction of ticks did not saturate");
+        return false;
+    }
+
+    true
+}
+
+/// Checks if checked arithmetic on ticks detects overflows.
+fn ticks_checked_arithmetic() -> bool {
+    let one: Ticks = Ticks::from_raw(1);
+
+    if Ticks::MAX.checked_add(one).is_some()
+        || Ticks::ZERO.checked_sub(one).is_some()
+    {
+        nanvix::log!("failed to detect overflow of ticks");
+        return false;
+    }
+
+    if Ticks::MAX.checked_sub(one) != Some(Ticks::from_raw(u64::MAX - 1))
+        || Ticks::from_raw(u64::MAX - 1).checked_add(one) != Some(Ticks::MAX)
+    {
+        nanvix::log!("unexpected result of checked arithmetic on ticks");
+        return false;
+    }
+
+    Ticks::ZERO < one && one < Ticks::MAX
+}
+
+/// Checks if ticks are converted from and to milliseconds.
+fn ticks_millis_conversion() -> bool {
+    let tick: u64 = 1000 / time::TICKS_PER_SECOND;
+
+    if Ticks::from_millis(0) != Ticks::ZERO
+        || Ticks::from_millis(1000) != Ticks::from_raw(time::TICKS_PER_SECOND)
+        || Ticks::from_millis(1) != Ticks::from_raw(1)
+        || Ticks::from_millis(tick + 1) != Ticks::from_raw(2)
+    {
+        nanvix::log!("unexpected conversion from milliseconds");
+        return false;
+    }
+
+    if Ticks::from_raw(time::TICKS_PER_SECOND).as_millis() != 1000 {
+        nanvix::log!("unexpected conversion to milliseconds");
+        return false;
+    }
+
+    // Conversions should not overflow.
+    let secs: u64 = u64::MAX / 1000;
+    Ticks::from_millis(u64::MAX)
+        >= Ticks::from_raw(secs * time::TICKS_PER_SECOND)
+        && Ticks::MAX.as_millis() == u64::MAX
+}
+
+//==============================================================================
+// Public Standalone Functions
+//==============================================================================
+
+///
+/// **Description**
+///
+/// Tests the time facility.
+///
+pub fn test() {
+    crate::test!(ticks_saturating_arithmetic());
+    crate::test!(ticks_checked_arithmetic());
+    crate::test!(ticks_millis_conversion());
+}